@@ -1,5 +1,10 @@
-use crate::sections::{feature::Feature, fit::Fit, utils::State};
-use egui::{Button, Color32, CursorIcon, RichText};
+use crate::sections::{
+    ansi::AnsiFit, browser::TableBrowser, conformance, designation::Designation, feature::Feature,
+    fit::Fit, formula, general, length::Length, material, preferred, selector::Selector,
+    shrink::ShrinkFit, stack::Stacks, statistics::Statistics, stress::PressFit, sweep::Sweep,
+    transmission::Transmission, utils::State,
+};
+use egui::{Button, Color32, CursorIcon, Key, RichText, TextEdit};
 
 #[derive(serde::Deserialize, serde::Serialize)]
 #[serde(default)]
@@ -7,6 +12,22 @@ pub struct LimitsFitsApp {
     hole: Feature,
     shaft: Feature,
     fit: Fit,
+    selector: Selector,
+    ansi: AnsiFit,
+    #[serde(default)]
+    browser: TableBrowser,
+    #[serde(default)]
+    statistics: Statistics,
+    #[serde(default)]
+    stacks: Stacks,
+    #[serde(default)]
+    press: PressFit,
+    #[serde(default)]
+    transmission: Transmission,
+    #[serde(default)]
+    shrink: ShrinkFit,
+    #[serde(default)]
+    sweep: Sweep,
     state: State,
 }
 
@@ -16,6 +37,15 @@ impl Default for LimitsFitsApp {
             hole: Feature::default_hole(),
             shaft: Feature::default_shaft(),
             fit: Fit::default(),
+            selector: Selector::default(),
+            ansi: AnsiFit::default(),
+            browser: TableBrowser::default(),
+            statistics: Statistics::default(),
+            stacks: Stacks::default(),
+            press: PressFit::default(),
+            transmission: Transmission::default(),
+            shrink: ShrinkFit::default(),
+            sweep: Sweep::default(),
             state: State::default(),
         }
     }
@@ -65,18 +95,56 @@ impl eframe::App for LimitsFitsApp {
 
                 egui::widgets::global_theme_preference_switch(ui);
                 self.state.zoom.show(ui, ctx);
+                self.state.units.show(ui);
 
                 ui.separator();
 
                 ui.toggle_value(&mut self.state.thermal, "Thermal");
-
-                // ui.button("Stress").on_hover_text("Add me");
+                ui.toggle_value(&mut self.state.explain, "Explain")
+                    .on_hover_text("Show how the ISO limits were worked out");
+                ui.toggle_value(&mut self.state.statistics, "Stats")
+                    .on_hover_text("Statistical fit analysis");
+                ui.toggle_value(&mut self.state.capability, "Cpk")
+                    .on_hover_text("Process capability and predicted scrap of each feature");
+                ui.toggle_value(&mut self.state.selector, "Selector");
+                ui.toggle_value(&mut self.state.preferred, "Preferred");
+                ui.toggle_value(&mut self.state.ansi, "ANSI");
+                ui.toggle_value(&mut self.state.browser, "Browse")
+                    .on_hover_text("Browse the ISO 286 tables");
+                ui.toggle_value(&mut self.state.general, "2768")
+                    .on_hover_text("ISO 2768-1 general tolerances");
+                ui.toggle_value(&mut self.state.geometric, "Form")
+                    .on_hover_text("ISO 2768-2 general geometrical tolerances");
+                ui.toggle_value(&mut self.state.stack, "Stack")
+                    .on_hover_text("One dimensional tolerance stack-ups");
+
+                ui.toggle_value(&mut self.state.stress, "Stress")
+                    .on_hover_text("Press fit stresses");
+                ui.toggle_value(&mut self.state.slip, "Slip")
+                    .on_hover_text("Torque and axial load an interference fit carries");
+                ui.toggle_value(&mut self.state.shrink, "Shrink")
+                    .on_hover_text("Temperatures to assemble or take apart an interference fit");
+                ui.toggle_value(&mut self.state.sweep, "Sweep")
+                    .on_hover_text("Fit against temperature");
+                ui.toggle_value(&mut self.state.library, "Materials")
+                    .on_hover_text("Material library and your own materials");
 
                 if ui.add(Button::new("Reset")).clicked() {
                     self.hole = Feature::default_hole();
                     self.shaft = Feature::default_shaft();
                     self.fit = Fit::default();
-                    self.state = State::default();
+                    self.selector = Selector::default();
+                    self.ansi = AnsiFit::default();
+                    self.browser = TableBrowser::default();
+                    self.statistics = Statistics::default();
+                    self.press = PressFit::default();
+                    self.transmission = Transmission::default();
+                    self.shrink = ShrinkFit::default();
+                    self.sweep = Sweep::default();
+                    self.state = State {
+                        materials: std::mem::take(&mut self.state.materials),
+                        ..State::default()
+                    };
                 }
 
                 if self.state.debug {
@@ -86,6 +154,10 @@ impl eframe::App for LimitsFitsApp {
                         .on_hover_cursor(CursorIcon::default());
 
                     ui.toggle_value(&mut self.state.force_valid, "Force Valid");
+                    ui.toggle_value(&mut self.state.cross_check, "Tables")
+                        .on_hover_text("Check the lookup tables against the ISO 286-1 formulas");
+                    ui.toggle_value(&mut self.state.conformance, "286-2")
+                        .on_hover_text("Check every class against the ISO 286-2 reference limits");
 
                     if ui.add(Button::new("Random")).clicked() {
                         self.state.sync_size = false;
@@ -97,11 +169,45 @@ impl eframe::App for LimitsFitsApp {
             });
         });
 
+        if self.state.debug {
+            egui::Window::new("ISO 286-1 Cross-check")
+                .open(&mut self.state.cross_check)
+                .show(ctx, formula::show_cross_check);
+            egui::Window::new("ISO 286-2 Conformance")
+                .open(&mut self.state.conformance)
+                .show(ctx, conformance::show_conformance);
+        }
+
+        egui::Window::new("ISO 286 Tables")
+            .open(&mut self.state.browser)
+            .show(ctx, |ui| self.browser.show(ui, &[&self.hole, &self.shaft]));
+
+        egui::Window::new("ISO 2768-1 General Tolerances")
+            .open(&mut self.state.general)
+            .show(ctx, |ui| {
+                general::show_general(ui, &[&self.hole, &self.shaft])
+            });
+
+        egui::Window::new("Materials")
+            .open(&mut self.state.library)
+            .show(ctx, |ui| {
+                material::show_library(
+                    ui,
+                    &mut self.state.materials,
+                    [&self.hole.mat, &self.shaft.mat],
+                    &self.state.units,
+                )
+            });
+
         egui::CentralPanel::default().show(ctx, |ui| {
             ui.heading("ISO Limits and Fits Tool");
 
             ui.add_space(10.0);
 
+            designation_input(self, ui);
+
+            ui.add_space(10.0);
+
             self.hole.show(ui, &mut self.state);
 
             ui.add_space(10.0);
@@ -113,6 +219,90 @@ impl eframe::App for LimitsFitsApp {
             self.fit = Fit::new(&self.hole, &self.shaft);
             self.fit.show(ui, &self.state);
 
+            if self.state.sweep {
+                ui.add_space(10.0);
+
+                self.sweep.show(ui, &self.hole, &self.shaft, &self.state);
+            }
+
+            if self.state.statistics {
+                ui.add_space(10.0);
+
+                self.statistics
+                    .show(ui, &self.hole, &self.shaft, &self.state);
+            }
+
+            if self.state.stack {
+                ui.add_space(10.0);
+
+                self.stacks.show(ui, &self.state.units);
+            }
+
+            if self.state.stress {
+                ui.add_space(10.0);
+
+                self.press
+                    .show(ui, &mut self.hole, &mut self.shaft, &self.state);
+            }
+
+            if self.state.slip {
+                ui.add_space(10.0);
+
+                self.transmission
+                    .show(ui, &self.press, &self.hole, &self.shaft, &self.state);
+            }
+
+            if self.state.shrink {
+                ui.add_space(10.0);
+
+                self.shrink
+                    .show(ui, &mut self.hole, &mut self.shaft, &mut self.state);
+            }
+
+            if self.state.selector {
+                ui.add_space(10.0);
+
+                if let Some((hole, shaft)) = self.selector.show(ui) {
+                    let size = Length::from_mm(self.selector.size);
+                    self.hole.iso = hole;
+                    self.hole.size = size;
+                    self.hole.standard = true;
+                    self.shaft.iso = shaft;
+                    self.shaft.size = size;
+                    self.shaft.standard = true;
+                    self.state.sync_size = true;
+                    self.state.synced_size = size;
+                }
+            }
+
+            if self.state.preferred {
+                ui.add_space(10.0);
+
+                if let Some(fit) = preferred::show_catalogue(ui) {
+                    self.hole.iso = fit.hole_iso();
+                    self.hole.standard = true;
+                    self.shaft.iso = fit.shaft_iso();
+                    self.shaft.standard = true;
+                }
+            }
+
+            if self.state.ansi {
+                ui.add_space(10.0);
+
+                if let Some((hole, shaft)) = self.ansi.show(ui) {
+                    self.hole = Feature {
+                        mat: self.hole.mat.clone(),
+                        ..hole
+                    };
+                    self.shaft = Feature {
+                        mat: self.shaft.mat.clone(),
+                        ..shaft
+                    };
+                    self.state.sync_size = true;
+                    self.state.synced_size = self.hole.size;
+                }
+            }
+
             ui.with_layout(egui::Layout::bottom_up(egui::Align::LEFT), |ui| {
                 signature(self, ui);
                 egui::warn_if_debug_build(ui);
@@ -198,6 +388,46 @@ fn format_changelog(entries: &[ChangelogEntry]) -> String {
     changelog
 }
 
+fn designation_input(app: &mut LimitsFitsApp, ui: &mut egui::Ui) {
+    ui.horizontal(|ui| {
+        let input = ui
+            .add(
+                TextEdit::singleline(&mut app.state.designation)
+                    .hint_text("Ø25 H7/g6")
+                    .desired_width(120.0),
+            )
+            .on_hover_text("Paste a fit or feature callout from a drawing");
+
+        let submitted = input.lost_focus() && ui.input(|i| i.key_pressed(Key::Enter));
+
+        if ui.add(Button::new("Apply")).clicked() || submitted {
+            match app.state.designation.parse::<Designation>() {
+                Ok(designation) => {
+                    if let Some(mut hole) = designation.hole {
+                        hole.mat = app.hole.mat.clone();
+                        app.hole = hole;
+                        app.state.synced_size = app.hole.size;
+                    }
+
+                    if let Some(mut shaft) = designation.shaft {
+                        shaft.mat = app.shaft.mat.clone();
+                        app.shaft = shaft;
+                        app.state.synced_size = app.shaft.size;
+                    }
+
+                    app.state.sync_size = app.hole.size == app.shaft.size;
+                    app.state.designation_error = None;
+                }
+                Err(error) => app.state.designation_error = Some(error.to_string()),
+            }
+        }
+
+        if let Some(error) = &app.state.designation_error {
+            ui.colored_label(Color32::RED, error);
+        }
+    });
+}
+
 fn signature(app: &mut LimitsFitsApp, ui: &mut egui::Ui) {
     ui.horizontal(|ui| {
         let release_colour = Color32::from_rgb(0, 169, 0);
@@ -1,5 +1,5 @@
 #![warn(clippy::all, rust_2018_idioms)]
 
 mod app;
-mod sections;
+pub mod sections;
 pub use app::LimitsFitsApp;
@@ -0,0 +1,568 @@
+use std::fmt;
+
+use egui::{ComboBox, DragValue, Grid, RichText, Ui};
+
+use super::{
+    feature::Feature, fit::Fit, length::Length, tolerance::Tolerance, units::MM_PER_INCH,
+    utils::decimals,
+};
+
+// ANSI B4.1 preferred limits and fits for cylindrical parts, inch sizes
+// All fits are hole basis, the hole lower deviation is always zero
+// Values are in 1/100th of a thousandth of an inch (0.00001 in)
+// -1 marks sizes the class isn't defined for
+
+/// Upper bound of each nominal size range in inches.
+pub const ANSI_SIZES: &[f64; 13] = &[
+    0.12, 0.24, 0.40, 0.71, 1.19, 1.97, 3.15, 4.73, 7.09, 9.85, 12.41, 15.75, 19.69,
+];
+
+/// Standard tolerances for grades 4 to 13 across `ANSI_SIZES`.
+pub const ANSI_GRADES: &[[i32; 13]; 10] = &[
+    [15, 15, 15, 20, 25, 30, 30, 40, 50, 60, 60, 70, 80],
+    [20, 20, 25, 30, 40, 40, 50, 60, 70, 80, 90, 100, 100],
+    [25, 30, 40, 40, 50, 60, 70, 90, 100, 120, 120, 140, 160],
+    [40, 50, 60, 70, 80, 100, 120, 140, 160, 180, 200, 220, 250],
+    [60, 70, 90, 100, 120, 160, 180, 220, 250, 280, 300, 350, 400],
+    [
+        100, 120, 140, 160, 200, 250, 300, 350, 400, 450, 500, 600, 600,
+    ],
+    [
+        160, 180, 220, 280, 350, 400, 450, 500, 600, 700, 800, 900, 1_000,
+    ],
+    [
+        250, 300, 350, 400, 500, 600, 700, 900, 1_000, 1_200, 1_200, 1_400, 1_600,
+    ],
+    [
+        400, 500, 600, 700, 800, 1_000, 1_200, 1_400, 1_600, 1_800, 2_000, 2_200, 2_500,
+    ],
+    [
+        600, 700, 900, 1_000, 1_200, 1_600, 1_800, 2_200, 2_500, 2_800, 3_000, 3_500, 4_000,
+    ],
+];
+
+const RC1_2: [i32; 13] = [10, 15, 20, 25, 30, 40, 40, 50, 60, 60, 80, 100, 120];
+const RC3_4: [i32; 13] = [30, 40, 50, 60, 80, 100, 120, 140, 160, 200, 250, 300, 400];
+const RC5_6: [i32; 13] = [
+    60, 80, 100, 120, 160, 200, 250, 300, 350, 400, 500, 600, 800,
+];
+const RC7: [i32; 13] = [
+    100, 120, 160, 200, 250, 300, 400, 500, 600, 700, 800, 1_000, 1_200,
+];
+const RC8_LC9: [i32; 13] = [
+    250, 280, 300, 350, 450, 500, 600, 700, 800, 1_000, 1_200, 1_400, 1_600,
+];
+const RC9: [i32; 13] = [
+    400, 450, 500, 600, 700, 800, 900, 1_000, 1_200, 1_500, 1_800, 2_200, 2_500,
+];
+const LC5: [i32; 13] = [10, 15, 20, 25, 30, 40, 40, 50, 60, 60, 70, 70, 80];
+const LC6: [i32; 13] = [30, 40, 50, 60, 80, 100, 120, 140, 160, 200, 220, 250, 280];
+const LC7: [i32; 13] = [
+    60, 80, 100, 120, 160, 200, 250, 300, 350, 400, 450, 500, 500,
+];
+const LC8: [i32; 13] = [
+    100, 120, 160, 200, 250, 300, 400, 500, 600, 700, 700, 800, 900,
+];
+const LC10: [i32; 13] = [
+    400, 450, 500, 600, 700, 800, 1_000, 1_100, 1_200, 1_600, 2_000, 2_200, 2_500,
+];
+const LC11: [i32; 13] = [
+    500, 600, 700, 800, 1_000, 1_200, 1_400, 1_600, 1_800, 2_200, 2_800, 3_000, 3_500,
+];
+const LT3_4: [i32; 13] = [-1, -1, 10, 10, 10, 10, 10, 10, 10, 20, 20, 20, 20];
+const LT5_6: [i32; 13] = [25, 30, 40, 50, 60, 70, 80, 100, 120, 140, 140, 160, 180];
+const LN3: [i32; 13] = [10, 10, 20, 30, 40, 40, 40, 60, 90, 120, 150, 230, 250];
+
+/// Upper bound of each force and shrink fit size range in inches, FN classes
+/// are tabulated in finer steps than the running and locational fits.
+pub const FN_SIZES: &[f64; 25] = &[
+    0.12, 0.24, 0.40, 0.56, 0.71, 0.95, 1.19, 1.58, 1.97, 2.56, 3.15, 3.94, 4.73, 5.52, 6.30, 7.09,
+    7.88, 8.86, 9.85, 11.03, 12.41, 13.98, 15.75, 17.72, 19.69,
+];
+
+// Minimum interference across `FN_SIZES`
+const FN1: [i32; 25] = [
+    5, 10, 10, 10, 20, 20, 30, 30, 40, 60, 70, 90, 110, 120, 150, 180, 180, 230, 230, 280, 280,
+    310, 360, 440, 440,
+];
+const FN2: [i32; 25] = [
+    20, 20, 40, 50, 50, 60, 60, 80, 80, 80, 100, 140, 160, 190, 240, 290, 320, 420, 420, 400, 500,
+    580, 580, 650, 750,
+];
+const FN3: [i32; 25] = [
+    -1, -1, -1, -1, -1, -1, 80, 100, 120, 130, 180, 210, 260, 340, 340, 440, 520, 520, 620, 700,
+    700, 780, 980, 950, 1_150,
+];
+const FN4: [i32; 25] = [
+    30, 40, 60, 70, 70, 80, 100, 150, 180, 230, 280, 360, 460, 540, 540, 640, 720, 820, 1_020,
+    1_000, 1_200, 1_380, 1_580, 1_750, 1_950,
+];
+const FN5: [i32; 25] = [
+    30, 50, 50, 60, 80, 100, 130, 140, 240, 320, 420, 480, 580, 750, 950, 950, 1_120, 1_320, 1_320,
+    1_500, 1_700, 1_850, 2_150, 2_400, 2_600,
+];
+
+/// How the shaft is placed relative to the hole for each size range.
+#[derive(Clone, Copy)]
+pub enum AnsiShaft {
+    /// Upper deviation below zero by the allowance (minimum clearance).
+    Clearance(&'static [i32; 13]),
+    /// Split evenly either side of zero, the published table drops the odd
+    /// 1/100 thou so both deviations round toward zero.
+    Symmetric,
+    /// Lower deviation above zero by the given amount.
+    Transition(&'static [i32; 13]),
+    /// Lower deviation above the hole upper limit by the minimum interference.
+    Interference(&'static [i32; 13]),
+    /// As `Interference` with the minimum across `FN_SIZES`.
+    Force(&'static [i32; 25]),
+}
+
+pub struct AnsiClass {
+    pub name: &'static str,
+    pub description: &'static str,
+    pub hole_grade: usize,
+    pub shaft_grade: usize,
+    pub shaft: AnsiShaft,
+}
+
+const fn class(
+    name: &'static str,
+    description: &'static str,
+    hole_grade: usize,
+    shaft_grade: usize,
+    shaft: AnsiShaft,
+) -> AnsiClass {
+    AnsiClass {
+        name,
+        description,
+        hole_grade,
+        shaft_grade,
+        shaft,
+    }
+}
+
+pub const ANSI_CLASSES: &[AnsiClass] = &[
+    class("RC1", "Close sliding", 5, 4, AnsiShaft::Clearance(&RC1_2)),
+    class("RC2", "Sliding", 6, 5, AnsiShaft::Clearance(&RC1_2)),
+    class(
+        "RC3",
+        "Precision running",
+        7,
+        6,
+        AnsiShaft::Clearance(&RC3_4),
+    ),
+    class("RC4", "Close running", 8, 7, AnsiShaft::Clearance(&RC3_4)),
+    class("RC5", "Medium running", 8, 7, AnsiShaft::Clearance(&RC5_6)),
+    class("RC6", "Medium running", 9, 8, AnsiShaft::Clearance(&RC5_6)),
+    class("RC7", "Free running", 9, 8, AnsiShaft::Clearance(&RC7)),
+    class(
+        "RC8",
+        "Loose running",
+        10,
+        9,
+        AnsiShaft::Clearance(&RC8_LC9),
+    ),
+    class("RC9", "Loose running", 11, 10, AnsiShaft::Clearance(&RC9)),
+    class(
+        "LC1",
+        "Locational clearance",
+        6,
+        5,
+        AnsiShaft::Clearance(&[0; 13]),
+    ),
+    class(
+        "LC2",
+        "Locational clearance",
+        7,
+        6,
+        AnsiShaft::Clearance(&[0; 13]),
+    ),
+    class(
+        "LC3",
+        "Locational clearance",
+        8,
+        7,
+        AnsiShaft::Clearance(&[0; 13]),
+    ),
+    class(
+        "LC4",
+        "Locational clearance",
+        10,
+        9,
+        AnsiShaft::Clearance(&[0; 13]),
+    ),
+    class(
+        "LC5",
+        "Locational clearance",
+        7,
+        6,
+        AnsiShaft::Clearance(&LC5),
+    ),
+    class(
+        "LC6",
+        "Locational clearance",
+        9,
+        8,
+        AnsiShaft::Clearance(&LC6),
+    ),
+    class(
+        "LC7",
+        "Locational clearance",
+        10,
+        9,
+        AnsiShaft::Clearance(&LC7),
+    ),
+    class(
+        "LC8",
+        "Locational clearance",
+        10,
+        9,
+        AnsiShaft::Clearance(&LC8),
+    ),
+    class(
+        "LC9",
+        "Locational clearance",
+        11,
+        10,
+        AnsiShaft::Clearance(&RC8_LC9),
+    ),
+    class(
+        "LC10",
+        "Locational clearance",
+        12,
+        12,
+        AnsiShaft::Clearance(&LC10),
+    ),
+    class(
+        "LC11",
+        "Locational clearance",
+        13,
+        13,
+        AnsiShaft::Clearance(&LC11),
+    ),
+    class("LT1", "Locational transition", 7, 6, AnsiShaft::Symmetric),
+    class("LT2", "Locational transition", 8, 7, AnsiShaft::Symmetric),
+    class(
+        "LT3",
+        "Locational transition",
+        7,
+        6,
+        AnsiShaft::Transition(&LT3_4),
+    ),
+    class(
+        "LT4",
+        "Locational transition",
+        8,
+        7,
+        AnsiShaft::Transition(&LT3_4),
+    ),
+    class(
+        "LT5",
+        "Locational transition",
+        7,
+        6,
+        AnsiShaft::Transition(&LT5_6),
+    ),
+    class(
+        "LT6",
+        "Locational transition",
+        7,
+        7,
+        AnsiShaft::Transition(&LT5_6),
+    ),
+    class(
+        "LN1",
+        "Locational interference",
+        6,
+        5,
+        AnsiShaft::Interference(&[0; 13]),
+    ),
+    class(
+        "LN2",
+        "Locational interference",
+        7,
+        6,
+        AnsiShaft::Interference(&[0; 13]),
+    ),
+    class(
+        "LN3",
+        "Locational interference",
+        7,
+        6,
+        AnsiShaft::Interference(&LN3),
+    ),
+    class("FN1", "Light drive", 6, 5, AnsiShaft::Force(&FN1)),
+    class("FN2", "Medium drive", 7, 6, AnsiShaft::Force(&FN2)),
+    class("FN3", "Heavy drive", 7, 6, AnsiShaft::Force(&FN3)),
+    class("FN4", "Force", 7, 6, AnsiShaft::Force(&FN4)),
+    class("FN5", "Force", 8, 7, AnsiShaft::Force(&FN5)),
+];
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum AnsiError {
+    UnknownClass(String),
+    SizeOutOfRange(f64),
+    NotDefined(&'static str, f64),
+}
+
+impl fmt::Display for AnsiError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnknownClass(name) => write!(f, "'{name}' is not an ANSI B4.1 fit class"),
+            Self::SizeOutOfRange(size) => {
+                write!(
+                    f,
+                    "Size {size} in is outside the ANSI range of 0 to 19.69 in"
+                )
+            }
+            Self::NotDefined(name, size) => write!(f, "{name} is not defined at {size} in"),
+        }
+    }
+}
+
+impl std::error::Error for AnsiError {}
+
+/// An ANSI B4.1 fit, size in inches.
+#[derive(Clone, serde::Deserialize, serde::Serialize)]
+pub struct AnsiFit {
+    pub class: String,
+    pub size: f64,
+}
+
+impl Default for AnsiFit {
+    fn default() -> Self {
+        Self {
+            class: "RC4".to_owned(),
+            size: 1.0,
+        }
+    }
+}
+
+impl AnsiFit {
+    pub fn new(class: &str, size: f64) -> Self {
+        Self {
+            class: class.to_owned(),
+            size,
+        }
+    }
+
+    /// Hole and shaft deviations, exact as 1/100th thou is 254 nm.
+    pub fn limits(&self) -> Result<(Tolerance, Tolerance), AnsiError> {
+        let class = ANSI_CLASSES
+            .iter()
+            .find(|c| c.name.eq_ignore_ascii_case(&self.class))
+            .ok_or_else(|| AnsiError::UnknownClass(self.class.clone()))?;
+
+        if self.size <= 0.0 {
+            return Err(AnsiError::SizeOutOfRange(self.size));
+        }
+
+        let idx_size = ANSI_SIZES
+            .iter()
+            .position(|&s| s >= self.size)
+            .ok_or(AnsiError::SizeOutOfRange(self.size))?;
+
+        // Helper function to convert 1/100th thou integers to lengths
+        let flt = |d: i32| Length::from_nm(254 * d as i64);
+
+        let hole = ANSI_GRADES[class.hole_grade - 4][idx_size];
+        let shaft = ANSI_GRADES[class.shaft_grade - 4][idx_size];
+
+        let idx_force = FN_SIZES
+            .iter()
+            .position(|&s| s >= self.size)
+            .ok_or(AnsiError::SizeOutOfRange(self.size))?;
+
+        let (upper, lower) = match class.shaft {
+            AnsiShaft::Clearance(allowance) => (-allowance[idx_size], -allowance[idx_size] - shaft),
+            AnsiShaft::Symmetric => (shaft / 2, -(shaft / 2)),
+            AnsiShaft::Transition(lower) => (lower[idx_size] + shaft, lower[idx_size]),
+            AnsiShaft::Interference(interference) => {
+                let lower = hole + interference[idx_size];
+                (lower + shaft, lower)
+            }
+            AnsiShaft::Force(interference) => {
+                let lower = hole + interference[idx_force];
+                (lower + shaft, lower)
+            }
+        };
+
+        let undefined = match class.shaft {
+            AnsiShaft::Clearance(table)
+            | AnsiShaft::Transition(table)
+            | AnsiShaft::Interference(table) => table[idx_size] == -1,
+            AnsiShaft::Force(table) => table[idx_force] == -1,
+            AnsiShaft::Symmetric => false,
+        };
+
+        if undefined {
+            return Err(AnsiError::NotDefined(class.name, self.size));
+        }
+
+        Ok((
+            Tolerance::new(flt(hole), Length::ZERO),
+            Tolerance::new(flt(upper), flt(lower)),
+        ))
+    }
+
+    /// Hole and shaft features in millimetres with manual limits.
+    pub fn features(&self) -> Result<(Feature, Feature), AnsiError> {
+        let (hole_tol, shaft_tol) = self.limits()?;
+        let size = Length::from_mm(self.size * MM_PER_INCH);
+
+        let feature = |mut feature: Feature, tolerance: Tolerance| {
+            feature.standard = false;
+            feature.size = size;
+            feature.tolerance = tolerance;
+            feature
+        };
+
+        Ok((
+            feature(Feature::default_hole(), hole_tol),
+            feature(Feature::default_shaft(), shaft_tol),
+        ))
+    }
+
+    pub fn fit(&self) -> Result<Fit, AnsiError> {
+        let (hole, shaft) = self.features()?;
+        Ok(Fit::new(&hole, &shaft))
+    }
+
+    /// Returns the hole and shaft features when the user applies the fit.
+    pub fn show(&mut self, ui: &mut Ui) -> Option<(Feature, Feature)> {
+        let mut selected = None;
+
+        ui.label(RichText::new("ANSI B4.1 Fits").strong().size(15.0));
+        ui.add_space(5.0);
+
+        egui::Frame::group(ui.style())
+            .inner_margin(10.0)
+            .rounding(10.0)
+            .show(ui, |ui| {
+                ui.horizontal(|ui| {
+                    ui.add_sized(
+                        [55.0, 18.0],
+                        DragValue::new(&mut self.size)
+                            .speed(0.01)
+                            .range(0.0..=19.69)
+                            .min_decimals(3)
+                            .suffix(" in"),
+                    )
+                    .on_hover_text("Size");
+
+                    ComboBox::from_id_salt("ansi_class")
+                        .width(60.0)
+                        .selected_text(&self.class)
+                        .show_ui(ui, |ui| {
+                            for class in ANSI_CLASSES {
+                                ui.selectable_value(
+                                    &mut self.class,
+                                    class.name.to_owned(),
+                                    class.name,
+                                )
+                                .on_hover_text(class.description);
+                            }
+                        })
+                        .response
+                        .on_hover_text("Class");
+
+                    if let Ok(features) = self.features() {
+                        if ui.button("Use").clicked() {
+                            selected = Some(features);
+                        }
+                    }
+                });
+
+                ui.add_space(5.0);
+
+                match self.limits() {
+                    Ok((hole, shaft)) => {
+                        // Thousandths of an inch, as printed in the standard
+                        let thou = |d: Length| decimals(1_000.0 * d.mm() / MM_PER_INCH, 2);
+
+                        Grid::new("ansi_limits")
+                            .striped(false)
+                            .min_col_width(10.0)
+                            .show(ui, |ui| {
+                                ui.label("Hole");
+                                ui.label(format!("{:+}", thou(hole.upper)));
+                                ui.label(format!("{:+}", thou(hole.lower)));
+                                ui.label("thou");
+                                ui.end_row();
+
+                                ui.label("Shaft");
+                                ui.label(format!("{:+}", thou(shaft.upper)));
+                                ui.label(format!("{:+}", thou(shaft.lower)));
+                                ui.label("thou");
+                                ui.end_row();
+
+                                ui.label("Clearance");
+                                ui.label(format!("{:+}", thou(hole.lower - shaft.upper)));
+                                ui.label(format!("{:+}", thou(hole.upper - shaft.lower)));
+                                ui.label("thou");
+                                ui.end_row();
+                            });
+                    }
+                    Err(error) => {
+                        ui.colored_label(egui::Color32::RED, error.to_string());
+                    }
+                }
+            });
+
+        selected
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ansi_limits() {
+        let thou = |t: &Tolerance| {
+            (
+                t.upper.nm() as f64 / 25_400.0,
+                t.lower.nm() as f64 / 25_400.0,
+            )
+        };
+
+        let (hole, shaft) = AnsiFit::new("RC4", 0.1).limits().unwrap();
+        assert_eq!(thou(&hole), (0.6, 0.0));
+        assert_eq!(thou(&shaft), (-0.3, -0.7));
+
+        let (hole, shaft) = AnsiFit::new("FN2", 0.1).limits().unwrap();
+        assert_eq!(thou(&hole), (0.4, 0.0));
+        assert_eq!(thou(&shaft), (0.85, 0.6));
+
+        let (_, shaft) = AnsiFit::new("LT1", 0.1).limits().unwrap();
+        assert_eq!(thou(&shaft), (0.12, -0.12));
+
+        assert_eq!(
+            AnsiFit::new("FN3", 0.5).limits().err(),
+            Some(AnsiError::NotDefined("FN3", 0.5))
+        );
+        assert_eq!(
+            AnsiFit::new("FN3", 0.8).limits().err(),
+            Some(AnsiError::NotDefined("FN3", 0.8))
+        );
+        let (_, shaft) = AnsiFit::new("FN3", 1.0).limits().unwrap();
+        assert_eq!(thou(&shaft), (2.1, 1.6));
+
+        // FN classes step at 1.58 in inside the 1.19 to 1.97 in range
+        let (hole, shaft) = AnsiFit::new("FN4", 1.3).limits().unwrap();
+        assert_eq!(thou(&hole), (1.0, 0.0));
+        assert_eq!(thou(&shaft), (3.1, 2.5));
+        let (_, shaft) = AnsiFit::new("FN4", 1.7).limits().unwrap();
+        assert_eq!(thou(&shaft), (3.4, 2.8));
+        assert_eq!(
+            AnsiFit::new("RC4", 20.0).limits().err(),
+            Some(AnsiError::SizeOutOfRange(20.0))
+        );
+        assert!(AnsiFit::new("XX1", 1.0).limits().is_err());
+
+        let fit = AnsiFit::new("RC4", 1.0).fit().unwrap();
+        assert_eq!(fit.kind, "Clearance");
+        assert_eq!(fit.hole.size, Length::from_mm(25.4));
+    }
+}
@@ -0,0 +1,293 @@
+use egui::{Color32, ComboBox, Grid, RichText, ScrollArea, Ui};
+
+use super::{
+    feature::Feature,
+    lookup::{DEVIATIONS_K_ZC, STANDARD_TOLERANCE_GRADES},
+    tolerance::{FundamentalDeviation, Iso, Letter, ToleranceGrade},
+};
+
+// Readable views of the lookup tables for printing, empty (-1) cells and
+// classes that don't exist are left blank in the CSV and greyed out on screen
+
+#[derive(Clone, Copy, PartialEq, serde::Deserialize, serde::Serialize)]
+pub enum View {
+    Tolerances,
+    Deviations,
+}
+
+#[derive(Clone, serde::Deserialize, serde::Serialize)]
+pub struct TableBrowser {
+    pub view: View,
+    pub deviation: FundamentalDeviation,
+    #[serde(skip)]
+    pub status: Option<String>,
+}
+
+impl Default for TableBrowser {
+    fn default() -> Self {
+        Self {
+            view: View::Tolerances,
+            deviation: FundamentalDeviation::Hole(Letter::H),
+            status: None,
+        }
+    }
+}
+
+/// Size ranges (over, up to) in mm bounded by the size column of a table.
+fn ranges(bounds: impl Iterator<Item = i32>) -> Vec<(i32, i32)> {
+    let mut over = 0;
+    bounds
+        .map(|up_to| {
+            let range = (over, up_to);
+            over = up_to;
+            range
+        })
+        .collect()
+}
+
+fn tolerance_ranges() -> Vec<(i32, i32)> {
+    ranges(STANDARD_TOLERANCE_GRADES.iter().map(|row| row[0]))
+}
+
+// The k to zc table has the finest size ranges, which cover every other table
+fn deviation_ranges() -> Vec<(i32, i32)> {
+    ranges(DEVIATIONS_K_ZC.iter().map(|row| row[0]))
+}
+
+/// Standard tolerance in µm, None where the table is empty.
+pub fn tolerance_cell(row: usize, grade: ToleranceGrade) -> Option<f64> {
+    let cell = STANDARD_TOLERANCE_GRADES[row][grade.index() + 1];
+    (cell != -1).then(|| cell as f64 / 10.0)
+}
+
+/// Limit deviations in µm as "upper/lower", None where the class doesn't exist.
+pub fn deviation_cell(
+    deviation: FundamentalDeviation,
+    grade: ToleranceGrade,
+    up_to: i32,
+) -> Option<String> {
+    let tolerance = Iso::new(deviation, grade).convert(up_to as f64).ok()?;
+    Some(format!(
+        "{:+}/{:+}",
+        tolerance.upper.um(),
+        tolerance.lower.um()
+    ))
+}
+
+fn header() -> String {
+    let grades = ToleranceGrade::ALL.map(|grade| format!("IT{grade}"));
+    format!("over,up_to,{}\n", grades.join(","))
+}
+
+/// Standard tolerances in µm for every size range and grade.
+pub fn tolerances_csv() -> String {
+    let mut csv = header();
+    for (row, (over, up_to)) in tolerance_ranges().into_iter().enumerate() {
+        let cells = ToleranceGrade::ALL.map(|grade| {
+            tolerance_cell(row, grade).map_or(String::new(), |value| value.to_string())
+        });
+        csv.push_str(&format!("{over},{up_to},{}\n", cells.join(",")));
+    }
+    csv
+}
+
+/// Limit deviations in µm of one deviation for every size range and grade.
+pub fn deviations_csv(deviation: FundamentalDeviation) -> String {
+    let mut csv = header();
+    for (over, up_to) in deviation_ranges() {
+        let cells = ToleranceGrade::ALL
+            .map(|grade| deviation_cell(deviation, grade, up_to).unwrap_or_default());
+        csv.push_str(&format!("{over},{up_to},{}\n", cells.join(",")));
+    }
+    csv
+}
+
+impl TableBrowser {
+    fn csv(&self) -> (String, String) {
+        match self.view {
+            View::Tolerances => ("iso_286_tolerances.csv".to_owned(), tolerances_csv()),
+            View::Deviations => (
+                format!("iso_286_{}.csv", self.deviation.to_string().to_lowercase()),
+                deviations_csv(self.deviation),
+            ),
+        }
+    }
+
+    /// Table browser, with the cells of `features` that use ISO classes highlighted.
+    pub fn show(&mut self, ui: &mut Ui, features: &[&Feature]) {
+        ui.horizontal(|ui| {
+            ui.selectable_value(&mut self.view, View::Tolerances, "Tolerances");
+            ui.selectable_value(&mut self.view, View::Deviations, "Deviations");
+
+            if self.view == View::Deviations {
+                ComboBox::from_id_salt("browser_deviation")
+                    .width(45.0)
+                    .selected_text(self.deviation.to_string())
+                    .show_ui(ui, |ui| {
+                        for deviation in
+                            FundamentalDeviation::all(true).chain(FundamentalDeviation::all(false))
+                        {
+                            ui.selectable_value(
+                                &mut self.deviation,
+                                deviation,
+                                deviation.to_string(),
+                            );
+                        }
+                    })
+                    .response
+                    .on_hover_text("Deviation");
+            }
+
+            ui.separator();
+
+            if ui
+                .button("Copy CSV")
+                .on_hover_text("Copy the table to paste into a spreadsheet")
+                .clicked()
+            {
+                ui.ctx().copy_text(self.csv().1);
+                self.status = Some("Copied".to_owned());
+            }
+
+            #[cfg(not(target_arch = "wasm32"))]
+            if ui
+                .button("Save CSV")
+                .on_hover_text("Save the table to the working directory")
+                .clicked()
+            {
+                let (name, csv) = self.csv();
+                self.status = Some(match std::fs::write(&name, csv) {
+                    Ok(()) => format!("Saved {name}"),
+                    Err(error) => format!("Couldn't save {name}: {error}"),
+                });
+            }
+
+            if let Some(status) = &self.status {
+                ui.label(status);
+            }
+        });
+
+        ui.add_space(5.0);
+
+        // Only ISO features have a class to find in the tables
+        let current = features
+            .iter()
+            .filter(|feature| feature.standard)
+            .map(|feature| (feature.size.mm(), &feature.iso))
+            .collect::<Vec<_>>();
+
+        ScrollArea::both().max_height(400.0).show(ui, |ui| {
+            Grid::new("browser_table")
+                .striped(true)
+                .min_col_width(10.0)
+                .show(ui, |ui| match self.view {
+                    View::Tolerances => tolerance_grid(ui, &current),
+                    View::Deviations => deviation_grid(ui, self.deviation, &current),
+                });
+        });
+    }
+}
+
+fn grid_header(ui: &mut Ui) {
+    ui.label(RichText::new("mm").strong());
+    for grade in ToleranceGrade::ALL {
+        ui.label(RichText::new(format!("IT{grade}")).strong());
+    }
+    ui.end_row();
+}
+
+fn cell(ui: &mut Ui, text: Option<String>, highlight: bool) {
+    match text {
+        Some(text) if highlight => {
+            ui.label(
+                RichText::new(text)
+                    .strong()
+                    .background_color(ui.visuals().selection.bg_fill),
+            );
+        }
+        Some(text) => {
+            ui.label(text);
+        }
+        None => {
+            ui.colored_label(ui.visuals().weak_text_color(), "–");
+        }
+    }
+}
+
+fn range_label(ui: &mut Ui, (over, up_to): (i32, i32), highlight: bool) {
+    let text = RichText::new(format!("{over} – {up_to}"));
+    ui.label(if highlight {
+        text.strong().color(Color32::from_rgb(0, 160, 0))
+    } else {
+        text
+    });
+}
+
+fn tolerance_grid(ui: &mut Ui, current: &[(f64, &Iso)]) {
+    grid_header(ui);
+
+    for (row, range) in tolerance_ranges().into_iter().enumerate() {
+        let inside = |size: f64| size > range.0 as f64 && size <= range.1 as f64;
+        range_label(ui, range, current.iter().any(|(size, _)| inside(*size)));
+
+        for grade in ToleranceGrade::ALL {
+            let highlight = current
+                .iter()
+                .any(|(size, iso)| inside(*size) && iso.grade == grade);
+            cell(
+                ui,
+                tolerance_cell(row, grade).map(|value| value.to_string()),
+                highlight,
+            );
+        }
+        ui.end_row();
+    }
+}
+
+fn deviation_grid(ui: &mut Ui, deviation: FundamentalDeviation, current: &[(f64, &Iso)]) {
+    grid_header(ui);
+
+    // Only the features using this deviation are highlighted
+    let current = current
+        .iter()
+        .filter(|(_, iso)| iso.deviation == deviation)
+        .collect::<Vec<_>>();
+
+    for range in deviation_ranges() {
+        let inside = |size: f64| size > range.0 as f64 && size <= range.1 as f64;
+        range_label(ui, range, current.iter().any(|(size, _)| inside(*size)));
+
+        for grade in ToleranceGrade::ALL {
+            let highlight = current
+                .iter()
+                .any(|(size, iso)| inside(*size) && iso.grade == grade);
+            cell(ui, deviation_cell(deviation, grade, range.1), highlight);
+        }
+        ui.end_row();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_csv() {
+        let csv = tolerances_csv();
+        let lines = csv.lines().collect::<Vec<_>>();
+        assert_eq!(lines.len(), STANDARD_TOLERANCE_GRADES.len() + 1);
+        assert!(lines[0].starts_with("over,up_to,IT01,IT0,IT1"));
+
+        // 18 to 30 mm, IT7 is the tenth grade after the two range columns
+        let row = lines[5].split(',').collect::<Vec<_>>();
+        assert_eq!((row[0], row[1], row[10]), ("18", "30", "21"));
+
+        // IT01 doesn't exist above 500 mm
+        assert!(lines[14].starts_with("500,630,,"));
+
+        let csv = deviations_csv(FundamentalDeviation::Shaft(Letter::G));
+        let row = csv.lines().find(|l| l.starts_with("24,30,")).unwrap();
+        assert_eq!(row.split(',').nth(9), Some("-7/-20"));
+        assert_eq!(csv.lines().count(), DEVIATIONS_K_ZC.len() + 1);
+    }
+}
@@ -0,0 +1,345 @@
+use egui::{ComboBox, DragValue, Grid, RichText, Ui};
+
+use super::{
+    feature::Feature,
+    statistics::{normal_cdf, probability},
+    tolerance::{FundamentalDeviation, Iso, IsoError, Letter, ToleranceGrade},
+    units::Units,
+};
+
+// Process capability of a feature against its limits, the process is taken
+// to be normal. Sizes are in mm as floats like the statistics module
+
+/// Typical machining processes and the grade each holds within ±3σ.
+/// These are rules of thumb, measured data for the machine is better.
+#[derive(Clone, Copy, Debug, PartialEq, serde::Deserialize, serde::Serialize)]
+pub enum Machining {
+    Lapping,
+    Honing,
+    Grinding,
+    FineBoring,
+    Reaming,
+    Turning,
+    Milling,
+    Drilling,
+}
+
+impl Machining {
+    pub const ALL: [Self; 8] = [
+        Self::Lapping,
+        Self::Honing,
+        Self::Grinding,
+        Self::FineBoring,
+        Self::Reaming,
+        Self::Turning,
+        Self::Milling,
+        Self::Drilling,
+    ];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Lapping => "Lapping",
+            Self::Honing => "Honing",
+            Self::Grinding => "Grinding",
+            Self::FineBoring => "Fine boring/turning",
+            Self::Reaming => "Reaming",
+            Self::Turning => "Turning",
+            Self::Milling => "Milling",
+            Self::Drilling => "Drilling",
+        }
+    }
+
+    pub fn grade(self) -> ToleranceGrade {
+        use ToleranceGrade::*;
+
+        match self {
+            Self::Lapping => IT4,
+            Self::Honing => IT5,
+            Self::Grinding => IT6,
+            Self::FineBoring => IT7,
+            Self::Reaming => IT8,
+            Self::Turning => IT9,
+            Self::Milling => IT10,
+            Self::Drilling => IT12,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, serde::Deserialize, serde::Serialize)]
+pub enum Process {
+    /// Measured mean size and standard deviation in mm.
+    Measured { mean: f64, sigma: f64 },
+    /// Target indices, a Cpk below Cp moves the mean toward MMC.
+    Target { cp: f64, cpk: f64 },
+    /// Centred on the limits with 6σ equal to the grade the process holds.
+    Machining(Machining),
+}
+
+impl Default for Process {
+    fn default() -> Self {
+        Self::Target {
+            cp: 1.33,
+            cpk: 1.33,
+        }
+    }
+}
+
+/// Predicted spread of a feature and the fraction outside each limit.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Capability {
+    pub mean: f64,
+    pub sigma: f64,
+    pub cp: f64,
+    pub cpk: f64,
+    pub above: f64,
+    pub below: f64,
+}
+
+impl Capability {
+    /// Parts per million outside either limit.
+    pub fn ppm(&self) -> f64 {
+        (self.above + self.below) * 1_000_000.0
+    }
+}
+
+/// Standard tolerance of `grade` at `size`, the width of H at that grade.
+fn standard_tolerance(grade: ToleranceGrade, size: f64) -> Result<f64, IsoError> {
+    let tolerance = Iso::new(FundamentalDeviation::Hole(Letter::H), grade).convert(size)?;
+    Ok((tolerance.upper - tolerance.lower).mm())
+}
+
+/// Capability of `process` making `feature` within its limits.
+pub fn predict(process: &Process, feature: &Feature) -> Result<Capability, IsoError> {
+    let (lower, upper) = (feature.lower_limit().mm(), feature.upper_limit().mm());
+    let (mid, width) = ((lower + upper) / 2.0, upper - lower);
+
+    let (mean, sigma) = match *process {
+        Process::Measured { mean, sigma } => (mean, sigma),
+        Process::Target { cp, cpk } => {
+            let sigma = width / (6.0 * cp.max(f64::EPSILON));
+            // Machinists stop short of the MMC limit
+            let shift = 3.0 * sigma * (cp - cpk).max(0.0);
+            if feature.hole {
+                (mid - shift, sigma)
+            } else {
+                (mid + shift, sigma)
+            }
+        }
+        Process::Machining(machining) => (
+            mid,
+            standard_tolerance(machining.grade(), feature.size.mm())? / 6.0,
+        ),
+    };
+
+    let (above, below) = if sigma > 0.0 {
+        (
+            normal_cdf((mean - upper) / sigma),
+            normal_cdf((lower - mean) / sigma),
+        )
+    } else {
+        (f64::from(mean > upper), f64::from(mean < lower))
+    };
+
+    let cp = width / (6.0 * sigma);
+    let cpk = (upper - mean).min(mean - lower) / (3.0 * sigma);
+
+    Ok(Capability {
+        mean,
+        sigma,
+        cp,
+        cpk,
+        above,
+        below,
+    })
+}
+
+fn process_ui(ui: &mut Ui, id: &str, process: &mut Process, feature: &Feature, units: &Units) {
+    ui.horizontal(|ui| {
+        let measured = Process::Measured {
+            mean: feature.middle_limit().mm(),
+            sigma: (feature.upper_limit() - feature.lower_limit()).mm() / 8.0,
+        };
+
+        if ui
+            .selectable_label(matches!(process, Process::Measured { .. }), "Measured")
+            .on_hover_text("Process mean and standard deviation")
+            .clicked()
+        {
+            *process = measured;
+        }
+        if ui
+            .selectable_label(matches!(process, Process::Target { .. }), "Cp/Cpk")
+            .on_hover_text("Target capability indices")
+            .clicked()
+        {
+            *process = Process::default();
+        }
+        if ui
+            .selectable_label(matches!(process, Process::Machining(_)), "Process")
+            .on_hover_text("Typical capability of a machining process")
+            .clicked()
+        {
+            *process = Process::Machining(Machining::Turning);
+        }
+    });
+
+    ui.horizontal(|ui| match process {
+        Process::Measured { mean, sigma } => {
+            ui.add_sized(
+                [70.0, 18.0],
+                DragValue::new(mean)
+                    .custom_formatter(|m, _| units.number(m))
+                    .custom_parser(|m| units.parse_length(m))
+                    .speed(0.001)
+                    .range(0.0..=10_000.0),
+            )
+            .on_hover_text(format!("Mean ({})", units.length_unit()));
+            ui.add_sized(
+                [70.0, 18.0],
+                DragValue::new(sigma)
+                    .custom_formatter(|s, _| units.number(s))
+                    .custom_parser(|s| units.parse_length(s))
+                    .speed(0.0001)
+                    .range(0.0..=f64::MAX),
+            )
+            .on_hover_text(format!("Standard deviation ({})", units.length_unit()));
+        }
+        Process::Target { cp, cpk } => {
+            ui.add_sized(
+                [45.0, 18.0],
+                DragValue::new(cp)
+                    .speed(0.01)
+                    .range(0.1..=10.0)
+                    .min_decimals(2),
+            )
+            .on_hover_text("Cp");
+            let max = *cp;
+            ui.add_sized(
+                [45.0, 18.0],
+                DragValue::new(cpk)
+                    .speed(0.01)
+                    .range(-10.0..=max)
+                    .min_decimals(2),
+            )
+            .on_hover_text("Cpk, the mean moves toward MMC as it drops below Cp");
+        }
+        Process::Machining(machining) => {
+            ComboBox::from_id_salt(format!("{id}_machining"))
+                .width(130.0)
+                .selected_text(machining.label())
+                .show_ui(ui, |ui| {
+                    for option in Machining::ALL {
+                        ui.selectable_value(
+                            machining,
+                            option,
+                            format!("{} (IT{})", option.label(), option.grade()),
+                        );
+                    }
+                })
+                .response
+                .on_hover_text(format!(
+                    "Holds IT{} within ±3σ, a rule of thumb",
+                    machining.grade()
+                ));
+        }
+    });
+}
+
+/// Panel with the process inputs and the predicted scrap for `feature`.
+pub fn show(ui: &mut Ui, id: &str, process: &mut Process, feature: &Feature, units: &Units) {
+    ui.label(RichText::new("Capability").strong());
+    ui.add_space(5.0);
+
+    process_ui(ui, id, process, feature, units);
+    ui.add_space(5.0);
+
+    let capability = match predict(process, feature) {
+        Ok(capability) => capability,
+        Err(error) => {
+            ui.colored_label(egui::Color32::RED, error.to_string());
+            return;
+        }
+    };
+
+    let colour = if capability.cpk >= 1.33 {
+        egui::Color32::from_rgb(0, 160, 0)
+    } else if capability.cpk >= 1.0 {
+        egui::Color32::YELLOW
+    } else {
+        egui::Color32::RED
+    };
+
+    Grid::new(format!("{id}_capability"))
+        .striped(false)
+        .min_col_width(10.0)
+        .show(ui, |ui| {
+            ui.label("Cp");
+            ui.label(format!("{:.2}", capability.cp));
+            ui.end_row();
+
+            ui.label("Cpk");
+            ui.colored_label(colour, format!("{:.2}", capability.cpk))
+                .on_hover_text("1.33 or more is usually asked for");
+            ui.end_row();
+
+            ui.label("⬆")
+                .on_hover_cursor(egui::CursorIcon::Default)
+                .on_hover_text("Above the upper limit");
+            ui.label(probability(capability.above));
+            ui.end_row();
+
+            ui.label("⬇")
+                .on_hover_cursor(egui::CursorIcon::Default)
+                .on_hover_text("Below the lower limit");
+            ui.label(probability(capability.below));
+            ui.end_row();
+
+            ui.label("Reject");
+            ui.label(format!("{:.0} ppm", capability.ppm()));
+            ui.end_row();
+        });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn close(a: f64, b: f64, tol: f64) -> bool {
+        (a - b).abs() < tol
+    }
+
+    #[test]
+    fn test_capability() {
+        let bore = Feature::from_iso(true, 10.0, "H6".parse().unwrap()).unwrap();
+
+        // A centred Cp of 1 scraps 0.27 %, split evenly
+        let target = Process::Target { cp: 1.0, cpk: 1.0 };
+        let capability = predict(&target, &bore).unwrap();
+        assert!(close(capability.ppm(), 2_699.8, 1.0));
+        assert!(close(capability.above, capability.below, 1e-12));
+
+        let capability = predict(&Process::default(), &bore).unwrap();
+        assert!(close(capability.ppm(), 66.1, 1.0));
+
+        // A lower Cpk moves a hole toward its lower limit
+        let target = Process::Target { cp: 1.33, cpk: 1.0 };
+        let capability = predict(&target, &bore).unwrap();
+        assert!(close(capability.cpk, 1.0, 1e-9));
+        assert!(capability.below > 100.0 * capability.above);
+
+        // Grinding holds IT6, reaming an IT6 bore at 10 mm (IT8 = 22 µm) scraps a lot
+        let capability = predict(&Process::Machining(Machining::Grinding), &bore).unwrap();
+        assert!(close(capability.cp, 1.0, 1e-9));
+        let capability = predict(&Process::Machining(Machining::Reaming), &bore).unwrap();
+        assert!(close(capability.cp, 9.0 / 22.0, 1e-9));
+        assert!(capability.ppm() > 200_000.0);
+
+        // A process centred on the upper limit puts half the parts above it
+        let measured = Process::Measured {
+            mean: bore.upper_limit().mm(),
+            sigma: 0.002,
+        };
+        let capability = predict(&measured, &bore).unwrap();
+        assert!(close(capability.above, 0.5, 1e-7));
+    }
+}
@@ -0,0 +1,192 @@
+use egui::{Color32, Grid, RichText, ScrollArea, Ui};
+
+use super::{
+    length::Length,
+    reference::ISO_286_2,
+    tolerance::{Iso, IsoError, Tolerance},
+};
+
+// Checks Iso::convert against the reference limits in reference.rs, every
+// row is converted at the top of its size range and part way through it
+
+/// Limits of one class over one size range of the reference tables.
+#[derive(Clone, Debug)]
+pub struct Reference {
+    pub iso: Iso,
+    pub over: i32,
+    pub up_to: i32,
+    pub tolerance: Tolerance,
+}
+
+impl Reference {
+    /// Sizes in mm the row is checked at.
+    fn sizes(&self) -> [f64; 2] {
+        let (over, up_to) = (self.over as f64, self.up_to as f64);
+        [over + 0.5 * (up_to - over), up_to]
+    }
+}
+
+/// A reference row that `Iso::convert` disagrees with at `size`.
+#[derive(Clone, Debug)]
+pub struct Mismatch {
+    pub reference: Reference,
+    pub size: f64,
+    pub found: Result<Tolerance, IsoError>,
+}
+
+impl std::fmt::Display for Mismatch {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let Reference {
+            iso,
+            over,
+            up_to,
+            tolerance,
+        } = &self.reference;
+        let limits = |t: &Tolerance| format!("{:+} / {:+} µm", t.upper.um(), t.lower.um());
+
+        write!(
+            f,
+            "{iso} over {over} to {up_to} mm at {} mm: expected {}, ",
+            self.size,
+            limits(tolerance)
+        )?;
+        match &self.found {
+            Ok(found) => write!(f, "found {}", limits(found)),
+            Err(error) => write!(f, "found error \"{error}\""),
+        }
+    }
+}
+
+/// Every row of the reference tables.
+pub fn references() -> Vec<Reference> {
+    ISO_286_2
+        .lines()
+        .skip(1)
+        .map(|line| {
+            let fields = line.split(',').collect::<Vec<_>>();
+            let number = |i: usize| {
+                fields[i]
+                    .parse::<f64>()
+                    .expect("reference rows are numbers")
+            };
+
+            Reference {
+                iso: fields[0].parse().expect("reference classes are valid"),
+                over: number(1) as i32,
+                up_to: number(2) as i32,
+                tolerance: Tolerance::new(Length::from_um(number(3)), Length::from_um(number(4))),
+            }
+        })
+        .collect()
+}
+
+/// Every reference row that `Iso::convert` doesn't reproduce exactly.
+pub fn check(references: &[Reference]) -> Vec<Mismatch> {
+    references
+        .iter()
+        .flat_map(|reference| {
+            reference.sizes().into_iter().filter_map(|size| {
+                let found = reference.iso.convert(size);
+                (found.as_ref() != Ok(&reference.tolerance)).then(|| Mismatch {
+                    reference: reference.clone(),
+                    size,
+                    found,
+                })
+            })
+        })
+        .collect()
+}
+
+/// Plain text list of mismatches for test output and sign-off records.
+pub fn report(references: &[Reference], mismatches: &[Mismatch]) -> String {
+    let mut report = format!(
+        "ISO 286-2 conformance: {} rows checked at {} sizes, {} mismatches\n",
+        references.len(),
+        2 * references.len(),
+        mismatches.len()
+    );
+
+    for mismatch in mismatches {
+        report.push_str(&format!("{mismatch}\n"));
+    }
+
+    report
+}
+
+/// Debug view of the conformance check with every mismatch listed.
+pub fn show_conformance(ui: &mut Ui) {
+    let references = references();
+    let mismatches = check(&references);
+
+    ui.label(format!(
+        "{} rows checked at {} sizes",
+        references.len(),
+        2 * references.len()
+    ));
+
+    if mismatches.is_empty() {
+        ui.colored_label(Color32::from_rgb(0, 160, 0), "Every row matches");
+    } else {
+        ui.colored_label(Color32::RED, format!("{} mismatches", mismatches.len()));
+    }
+
+    if ui
+        .button("Copy report")
+        .on_hover_text("Copy the full report to the clipboard")
+        .clicked()
+    {
+        ui.ctx().copy_text(report(&references, &mismatches));
+    }
+
+    ScrollArea::vertical().max_height(400.0).show(ui, |ui| {
+        Grid::new("conformance_mismatches")
+            .striped(true)
+            .min_col_width(10.0)
+            .show(ui, |ui| {
+                for heading in ["Class", "Range mm", "Size mm", "Expected µm", "Found"] {
+                    ui.label(RichText::new(heading).strong());
+                }
+                ui.end_row();
+
+                for mismatch in &mismatches {
+                    let reference = &mismatch.reference;
+                    let tolerance = &reference.tolerance;
+
+                    ui.label(reference.iso.to_string());
+                    ui.label(format!("{} – {}", reference.over, reference.up_to));
+                    ui.label(mismatch.size.to_string());
+                    ui.label(format!(
+                        "{:+} / {:+}",
+                        tolerance.upper.um(),
+                        tolerance.lower.um()
+                    ));
+                    match &mismatch.found {
+                        Ok(found) => ui.colored_label(
+                            Color32::RED,
+                            format!("{:+} / {:+}", found.upper.um(), found.lower.um()),
+                        ),
+                        Err(error) => ui.colored_label(Color32::RED, error.to_string()),
+                    };
+                    ui.end_row();
+                }
+            });
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_conformance() {
+        let references = references();
+        assert!(references.len() > 8_000);
+
+        let mismatches = check(&references);
+        assert!(
+            mismatches.is_empty(),
+            "{}",
+            report(&references, &mismatches)
+        );
+    }
+}
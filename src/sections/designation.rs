@@ -0,0 +1,218 @@
+use std::{fmt, str::FromStr};
+
+use super::{
+    feature::Feature,
+    fit::Fit,
+    tolerance::{FundamentalDeviation, Iso, IsoError, ToleranceGrade},
+};
+
+// Accepts callouts as they appear on drawings, for example:
+// "25H7/g6", "Ø25 H7-g6", "25 H7 / 24.98 g6", "40 js6"
+// The first size is carried over to the shaft if it doesn't have its own.
+
+const DIAMETER_SYMBOLS: &[char] = &['Ø', 'ø', '⌀', '∅'];
+const SEPARATORS: &[char] = &['/', '-'];
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum ParseError {
+    Empty,
+    MissingSize,
+    InvalidSize(String),
+    SizeOutOfRange(f64),
+    MissingClass(String),
+    MixedCase(String),
+    UnknownDeviation(String),
+    UnknownGrade(String),
+    ExpectedHole(String),
+    ExpectedShaft(String),
+    TooManyParts(usize),
+    InvalidClass(IsoError),
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Empty => write!(f, "Nothing to parse"),
+            Self::MissingSize => write!(f, "No nominal size given, e.g. 25 H7/g6"),
+            Self::InvalidSize(s) => write!(f, "'{s}' is not a valid size"),
+            Self::SizeOutOfRange(s) => write!(f, "Size {s} mm is outside 0 to 3150 mm"),
+            Self::MissingClass(s) => write!(f, "No tolerance class found in '{s}'"),
+            Self::MixedCase(s) => write!(f, "'{s}' mixes upper and lower case letters"),
+            Self::UnknownDeviation(s) => write!(f, "'{s}' is not a fundamental deviation"),
+            Self::UnknownGrade(s) => write!(f, "'{s}' is not a tolerance grade"),
+            Self::ExpectedHole(s) => write!(f, "'{s}' should be a hole (upper case) class"),
+            Self::ExpectedShaft(s) => write!(f, "'{s}' should be a shaft (lower case) class"),
+            Self::TooManyParts(n) => write!(f, "Expected a hole and a shaft, found {n} classes"),
+            Self::InvalidClass(error) => write!(f, "{error}"),
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// A hole, a shaft, or a full fit parsed from a drawing callout.
+#[derive(Clone)]
+pub struct Designation {
+    pub hole: Option<Feature>,
+    pub shaft: Option<Feature>,
+}
+
+impl Designation {
+    /// Returns the fit if both a hole and a shaft were given.
+    pub fn fit(&self) -> Option<Fit> {
+        Some(Fit::new(self.hole.as_ref()?, self.shaft.as_ref()?))
+    }
+}
+
+impl FromStr for Designation {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim().trim_start_matches(DIAMETER_SYMBOLS).trim();
+        if s.is_empty() {
+            return Err(ParseError::Empty);
+        }
+
+        let parts = s.split(SEPARATORS).map(str::trim).collect::<Vec<_>>();
+
+        match parts.as_slice() {
+            [single] => {
+                let feature = parse_feature(single, None)?;
+                if feature.hole {
+                    Ok(Self {
+                        hole: Some(feature),
+                        shaft: None,
+                    })
+                } else {
+                    Ok(Self {
+                        hole: None,
+                        shaft: Some(feature),
+                    })
+                }
+            }
+            [first, second] => {
+                let hole = parse_feature(first, None)?;
+                if !hole.hole {
+                    return Err(ParseError::ExpectedHole(first.to_string()));
+                }
+                let shaft = parse_feature(second, Some(hole.size.mm()))?;
+                if shaft.hole {
+                    return Err(ParseError::ExpectedShaft(second.to_string()));
+                }
+                Ok(Self {
+                    hole: Some(hole),
+                    shaft: Some(shaft),
+                })
+            }
+            _ => Err(ParseError::TooManyParts(parts.len())),
+        }
+    }
+}
+
+/// Parses a single feature such as "25 H7" or "g6", falling back on `size` if none is given.
+pub fn parse_feature(s: &str, size: Option<f64>) -> Result<Feature, ParseError> {
+    let s = s.trim().trim_start_matches(DIAMETER_SYMBOLS).trim();
+    if s.is_empty() {
+        return Err(ParseError::Empty);
+    }
+
+    let split = s
+        .find(|c: char| !(c.is_ascii_digit() || c == '.' || c == ','))
+        .unwrap_or(s.len());
+    let (size_text, class) = (&s[..split], s[split..].trim());
+
+    let size = if size_text.is_empty() {
+        size.ok_or(ParseError::MissingSize)?
+    } else {
+        size_text
+            .replace(',', ".")
+            .parse::<f64>()
+            .map_err(|_| ParseError::InvalidSize(size_text.to_owned()))?
+    };
+
+    if size <= 0.0 || size > 3_150.0 {
+        return Err(ParseError::SizeOutOfRange(size));
+    }
+
+    let split = class
+        .find(|c: char| !c.is_alphabetic())
+        .unwrap_or(class.len());
+    let (letters, grade) = (&class[..split], class[split..].trim());
+
+    if letters.is_empty() {
+        return Err(ParseError::MissingClass(s.to_owned()));
+    }
+
+    let deviation = letters.parse::<FundamentalDeviation>()?;
+    let grade = grade.parse::<ToleranceGrade>()?;
+
+    Feature::from_iso(deviation.is_hole(), size, Iso::new(deviation, grade))
+        .map_err(ParseError::InvalidClass)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sections::tolerance::Letter;
+
+    #[test]
+    fn test_parse_designation() {
+        for text in ["25H7/g6", "Ø25 H7-g6", "ø 25 H7 / g6", "25 H7 / 25 g6"] {
+            let designation = text.parse::<Designation>().unwrap();
+            let (hole, shaft) = (designation.hole.unwrap(), designation.shaft.unwrap());
+            assert_eq!(
+                (hole.size.mm(), hole.iso.to_string()),
+                (25.0, "H7".to_owned())
+            );
+            assert_eq!(
+                (shaft.size.mm(), shaft.iso.to_string()),
+                (25.0, "g6".to_owned())
+            );
+        }
+
+        let designation = "25 H7 / 24,98 g6".parse::<Designation>().unwrap();
+        assert_eq!(designation.shaft.unwrap().size.mm(), 24.98);
+
+        let designation = "40 js6".parse::<Designation>().unwrap();
+        assert!(designation.hole.is_none());
+        assert_eq!(
+            designation.shaft.unwrap().iso.deviation,
+            FundamentalDeviation::Shaft(Letter::JS)
+        );
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let error = |s: &str| s.parse::<Designation>().err();
+
+        assert_eq!(error(" "), Some(ParseError::Empty));
+        assert_eq!(error("H7/g6"), Some(ParseError::MissingSize));
+        assert_eq!(
+            error("25 Hg7"),
+            Some(ParseError::MixedCase("Hg".to_owned()))
+        );
+        assert_eq!(
+            error("25 Q7"),
+            Some(ParseError::UnknownDeviation("Q".to_owned()))
+        );
+        assert_eq!(
+            error("25 H19"),
+            Some(ParseError::UnknownGrade("19".to_owned()))
+        );
+        assert_eq!(
+            error("25 g6/H7"),
+            Some(ParseError::ExpectedHole("25 g6".to_owned()))
+        );
+        assert_eq!(
+            error("25 H7/G6"),
+            Some(ParseError::ExpectedShaft("G6".to_owned()))
+        );
+        assert_eq!(error("4000 H7"), Some(ParseError::SizeOutOfRange(4_000.0)));
+        assert_eq!(
+            error("1 a9"),
+            Some(ParseError::InvalidClass(IsoError::NotDefinedUpToOne(
+                FundamentalDeviation::Shaft(Letter::A)
+            )))
+        );
+    }
+}
@@ -0,0 +1,270 @@
+use egui::{Color32, Grid, RichText, Ui};
+
+use super::{
+    length::Length,
+    rules::Rule,
+    tolerance::{Derivation, FundamentalDeviation, Iso, Letter, ToleranceGrade},
+};
+
+// Step by step working of a tolerance class in micrometres, the unit of the
+// printed tables, so that it can be followed against ISO 286 line by line
+
+/// One line of working, a label and the value or sum next to it.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Step {
+    pub label: String,
+    pub working: String,
+    /// Where in the standard the step comes from, if anywhere in particular.
+    pub clause: Option<&'static str>,
+}
+
+impl Step {
+    fn new(label: impl Into<String>, working: impl Into<String>) -> Self {
+        Self {
+            label: label.into(),
+            working: working.into(),
+            clause: None,
+        }
+    }
+
+    fn clause(mut self, clause: &'static str) -> Self {
+        self.clause = Some(clause);
+        self
+    }
+}
+
+fn um(length: Length) -> String {
+    format!("{}", length.um())
+}
+
+fn signed(length: Length) -> String {
+    format!("{:+}", length.um())
+}
+
+/// Every step from the size to the limits of `iso` as `Iso::convert` takes them.
+pub fn explain(iso: &Iso, size: f64, derivation: &Derivation) -> Vec<Step> {
+    let Derivation {
+        over,
+        up_to,
+        it,
+        source,
+        rules,
+        tolerance,
+    } = derivation;
+    let grade = iso.grade;
+    let letter = iso.deviation.letter();
+    let hole = iso.deviation.is_hole();
+    let (upper, lower) = if hole { ("ES", "EI") } else { ("es", "ei") };
+
+    let mut steps = vec![
+        Step::new("Size", format!("{size} mm is over {over} up to {up_to} mm")),
+        Step::new(format!("IT{grade}"), format!("{} µm", um(*it)))
+            .clause("ISO 286-1 Table 1, standard tolerances"),
+    ];
+
+    if let Some(source) = source {
+        let value = source.value.map_or("–".to_owned(), um);
+        steps.push(Step::new(
+            "Table",
+            format!(
+                "{}, over {} up to {} mm, column {} = {value} µm",
+                source.table, source.over, source.up_to, source.column
+            ),
+        ));
+    }
+
+    // Δ only ever applies to the upper deviation of K to ZC holes
+    let k_to_zc = letter.index() >= Letter::K.index();
+    let delta = rules.iter().find_map(|rule| match rule {
+        Rule::Delta(delta) => Some(*delta),
+        _ => None,
+    });
+
+    if hole && k_to_zc {
+        let limit = match letter {
+            Letter::K | Letter::M | Letter::N => ToleranceGrade::IT8,
+            _ => ToleranceGrade::IT7,
+        };
+        let working = if let Some(delta) = delta {
+            format!(
+                "{} µm added, IT{grade} is up to IT{limit} from 3 to 500 mm",
+                delta as f64 / 1_000.0
+            )
+        } else if grade > limit {
+            format!("Not added, {letter} only takes Δ up to IT{limit}")
+        } else if grade < ToleranceGrade::IT3 {
+            "Not added, Δ starts at IT3".to_owned()
+        } else if size <= 3.0 {
+            "Not added, Δ is zero up to 3 mm".to_owned()
+        } else {
+            "Not added, Δ stops at 500 mm".to_owned()
+        };
+        steps.push(Step::new("Δ", working).clause(Rule::Delta(0).clause()));
+    }
+
+    for rule in rules.iter().filter(|rule| !matches!(rule, Rule::Delta(_))) {
+        steps.push(Step::new("Rule", rule.to_string()).clause(rule.clause()));
+    }
+
+    let cell = source
+        .as_ref()
+        .and_then(|s| s.value)
+        .unwrap_or(Length::ZERO);
+
+    match iso.deviation {
+        FundamentalDeviation::Hole(Letter::JS) | FundamentalDeviation::Shaft(Letter::JS) => {
+            let half = if rules.contains(&Rule::JsEven) {
+                format!("(IT - 1) / 2 = ({} - 1) / 2", um(*it))
+            } else {
+                format!("IT / 2 = {} / 2", um(*it))
+            };
+            steps.push(Step::new(
+                upper,
+                format!("+{half} = {}", signed(tolerance.upper)),
+            ));
+            steps.push(Step::new(
+                lower,
+                format!("-{half} = {}", signed(tolerance.lower)),
+            ));
+        }
+        // The fundamental deviation is the lower one, the upper is IT above it
+        FundamentalDeviation::Hole(_) if !k_to_zc && letter != Letter::J => {
+            steps.push(Step::new(lower, signed(tolerance.lower)));
+            steps.push(Step::new(
+                upper,
+                format!(
+                    "{lower} + IT = {} + {} = {}",
+                    signed(tolerance.lower),
+                    um(*it),
+                    signed(tolerance.upper)
+                ),
+            ));
+        }
+        FundamentalDeviation::Shaft(_) if k_to_zc => {
+            let working = if rules.contains(&Rule::KOutsideIt4ToIt7) {
+                "0".to_owned()
+            } else {
+                signed(cell)
+            };
+            steps.push(Step::new(lower, working));
+            steps.push(Step::new(
+                upper,
+                format!(
+                    "{lower} + IT = {} + {} = {}",
+                    signed(tolerance.lower),
+                    um(*it),
+                    signed(tolerance.upper)
+                ),
+            ));
+        }
+        FundamentalDeviation::Shaft(Letter::J) => {
+            steps.push(Step::new(lower, format!("-{}", um(cell))));
+            steps.push(Step::new(
+                upper,
+                format!(
+                    "{lower} + IT = {} + {} = {}",
+                    signed(tolerance.lower),
+                    um(*it),
+                    signed(tolerance.upper)
+                ),
+            ));
+        }
+        // The fundamental deviation is the upper one, the lower is IT below it
+        _ => {
+            let working = if rules.contains(&Rule::NAboveIt8) {
+                "0".to_owned()
+            } else if hole && k_to_zc {
+                let mut sum = format!("-{}", um(cell));
+                if let Some(delta) = delta {
+                    sum.push_str(&format!(" + {}", delta as f64 / 1_000.0));
+                }
+                if rules.contains(&Rule::M6) {
+                    sum.push_str(" + 2");
+                }
+                format!("{sum} = {}", signed(tolerance.upper))
+            } else {
+                signed(tolerance.upper)
+            };
+            steps.push(Step::new(upper, working));
+            steps.push(Step::new(
+                lower,
+                format!(
+                    "{upper} - IT = {} - {} = {}",
+                    signed(tolerance.upper),
+                    um(*it),
+                    signed(tolerance.lower)
+                ),
+            ));
+        }
+    }
+
+    steps
+}
+
+/// Panel with the working for `iso` at `size` in mm.
+pub fn show(ui: &mut Ui, id: &str, iso: &Iso, size: f64) {
+    ui.label(RichText::new(format!("Explain {iso}")).strong());
+    ui.add_space(5.0);
+
+    let derivation = match iso.derive(size) {
+        Ok(derivation) => derivation,
+        Err(error) => {
+            ui.colored_label(Color32::RED, error.to_string());
+            return;
+        }
+    };
+
+    Grid::new(format!("{id}_explain"))
+        .striped(true)
+        .min_col_width(10.0)
+        .show(ui, |ui| {
+            for step in explain(iso, size, &derivation) {
+                ui.label(RichText::new(&step.label).strong());
+                let label = ui.label(&step.working);
+                if let Some(clause) = step.clause {
+                    label
+                        .on_hover_cursor(egui::CursorIcon::Help)
+                        .on_hover_text(clause);
+                }
+                ui.end_row();
+            }
+        });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn working(iso: &str, size: f64) -> Vec<String> {
+        let iso = iso.parse::<Iso>().unwrap();
+        let derivation = iso.derive(size).unwrap();
+        explain(&iso, size, &derivation)
+            .into_iter()
+            .map(|step| format!("{}: {}", step.label, step.working))
+            .collect()
+    }
+
+    #[test]
+    fn test_explain() {
+        let steps = working("M6", 300.0);
+        assert_eq!(steps[0], "Size: 300 mm is over 250 up to 315 mm");
+        assert_eq!(steps[1], "IT6: 32 µm");
+        assert_eq!(
+            steps[2],
+            "Table: K to ZC, over 280 up to 315 mm, column m = 20 µm"
+        );
+        assert!(steps[3].starts_with("Δ: 9 µm added"));
+        assert!(steps.contains(&"ES: -20 + 9 + 2 = -9".to_owned()));
+        assert!(steps.contains(&"EI: ES - IT = -9 - 32 = -41".to_owned()));
+
+        let steps = working("P9", 10.0);
+        assert!(steps.contains(&"Δ: Not added, P only takes Δ up to IT7".to_owned()));
+
+        let steps = working("g6", 25.0);
+        assert!(steps.contains(&"es: -7".to_owned()));
+        assert!(steps.contains(&"ei: es - IT = -7 - 13 = -20".to_owned()));
+
+        let steps = working("js7", 24.0);
+        assert!(steps.contains(&"es: +(IT - 1) / 2 = (21 - 1) / 2 = +10".to_owned()));
+    }
+}
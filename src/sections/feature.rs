@@ -2,18 +2,32 @@ use egui::{ComboBox, DragValue, Grid, RichText, Ui};
 use rand::Rng;
 
 use super::{
-    material::Material,
-    tolerance::{GradesDeviations, Iso, Tolerance},
-    utils::{decimals, State},
+    capability::{self, Process},
+    explain, formula,
+    general::{self, GeneralClass},
+    geometric::{self, GeometricClass},
+    length::Length,
+    material::{self, Material},
+    tolerance::{FundamentalDeviation, Iso, IsoError, Letter, Tolerance, ToleranceGrade},
+    units::Units,
+    utils::State,
 };
 
 #[derive(Clone, serde::Deserialize, serde::Serialize)]
 pub struct Feature {
     pub hole: bool,
     pub standard: bool,
-    pub size: f64,
+    pub size: Length,
     pub iso: Iso,
     pub tolerance: Tolerance,
+    /// ISO 2768-1 class used in place of manual limits.
+    #[serde(default)]
+    pub general: Option<GeneralClass>,
+    /// ISO 2768-2 class for form and position.
+    #[serde(default)]
+    pub geometric: Option<GeometricClass>,
+    #[serde(default)]
+    pub process: Process,
     pub mat: Material,
 }
 
@@ -36,9 +50,12 @@ impl Feature {
         Feature {
             hole: true,
             standard: true,
-            size: 10.0,
-            iso: Iso::new("H", "7"),
-            tolerance: Tolerance::new(0.015, 0.0),
+            size: Length::from_mm(10.0),
+            iso: Iso::new(FundamentalDeviation::Hole(Letter::H), ToleranceGrade::IT7),
+            tolerance: Tolerance::from_mm(0.015, 0.0),
+            general: None,
+            geometric: None,
+            process: Process::default(),
             mat: Material::default(),
         }
     }
@@ -47,9 +64,12 @@ impl Feature {
         Feature {
             hole: false,
             standard: true,
-            size: 10.0,
-            iso: Iso::new("h", "6"),
-            tolerance: Tolerance::new(0.0, -0.009),
+            size: Length::from_mm(10.0),
+            iso: Iso::new(FundamentalDeviation::Shaft(Letter::H), ToleranceGrade::IT6),
+            tolerance: Tolerance::from_mm(0.0, -0.009),
+            general: None,
+            geometric: None,
+            process: Process::default(),
             mat: Material::default(),
         }
     }
@@ -59,37 +79,48 @@ impl Feature {
 
         loop {
             let size = rng.gen_range(0..3_150) as f64;
-            let grades = GradesDeviations::default().it_numbers;
-            let grade = &grades[rng.gen_range(0..grades.len())];
-            let deviations = if hole {
-                GradesDeviations::default().hole_letters
-            } else {
-                GradesDeviations::default().shaft_letters
-            };
-            let deviation = &deviations[rng.gen_range(0..deviations.len())];
-            let iso = Iso::new(deviation, grade);
+            let grade = ToleranceGrade::ALL[rng.gen_range(0..ToleranceGrade::ALL.len())];
+            let letter = Letter::ALL[rng.gen_range(0..Letter::ALL.len())];
+            let iso = Iso::new(FundamentalDeviation::new(letter, hole), grade);
 
-            if valid && iso.convert(size).is_none() {
+            if valid && iso.convert(size).is_err() {
                 continue;
             }
 
             let tolerance = match iso.convert(size) {
-                Some(tolerance) => tolerance,
-                None => Tolerance::new(0.0, 0.0),
+                Ok(tolerance) => tolerance,
+                Err(_) => Tolerance::new(Length::ZERO, Length::ZERO),
             };
 
             return Feature {
                 hole,
                 standard: true,
-                size,
+                size: Length::from_mm(size),
                 iso,
                 tolerance,
+                general: None,
+                geometric: None,
+                process: Process::default(),
                 mat: Material::default(),
             };
         }
     }
 
-    // pub fn from_iso() -> Self {}
+    pub fn from_iso(hole: bool, size: f64, iso: Iso) -> Result<Self, IsoError> {
+        let tolerance = iso.convert(size)?;
+
+        Ok(Feature {
+            hole,
+            standard: true,
+            size: Length::from_mm(size),
+            iso,
+            tolerance,
+            general: None,
+            geometric: None,
+            process: Process::default(),
+            mat: Material::default(),
+        })
+    }
 
     // pub fn from_tol(hole: bool, size: f64, upper: f64, lower: f64) -> Self {
     //     Feature {
@@ -102,31 +133,49 @@ impl Feature {
     //     }
     // }
 
-    pub fn upper_limit(&self, temp: bool) -> f64 {
-        if temp {
-            self.temp(self.size + self.tolerance.upper)
-        } else {
-            self.size + self.tolerance.upper
-        }
+    pub fn upper_limit(&self) -> Length {
+        self.size + self.tolerance.upper
+    }
+
+    pub fn middle_limit(&self) -> Length {
+        (self.upper_limit() + self.lower_limit()) / 2
+    }
+
+    pub fn lower_limit(&self) -> Length {
+        self.size + self.tolerance.lower
     }
 
-    pub fn middle_limit(&self, temp: bool) -> f64 {
-        (self.upper_limit(temp) + self.lower_limit(temp)) / 2.0
+    /// Circular run-out allowed by the ISO 2768-2 class, zero without one.
+    pub fn run_out(&self) -> Length {
+        self.geometric.map_or(Length::ZERO, geometric::run_out)
     }
 
-    pub fn lower_limit(&self, temp: bool) -> f64 {
+    /// Limits in mm, expanded to the material temperature if `temp` is set.
+    pub fn upper_limit_mm(&self, temp: bool) -> f64 {
+        self.expand(self.upper_limit(), temp)
+    }
+
+    pub fn middle_limit_mm(&self, temp: bool) -> f64 {
+        (self.upper_limit_mm(temp) + self.lower_limit_mm(temp)) / 2.0
+    }
+
+    pub fn lower_limit_mm(&self, temp: bool) -> f64 {
+        self.expand(self.lower_limit(), temp)
+    }
+
+    /// Growth of a size from 20 ºC to the material temperature.
+    pub fn thermal_scale(&self) -> f64 {
+        self.mat.scale(self.mat.temp)
+    }
+
+    fn expand(&self, size: Length, temp: bool) -> f64 {
         if temp {
-            self.temp(self.size + self.tolerance.lower)
+            size.mm() * self.thermal_scale()
         } else {
-            self.size + self.tolerance.lower
+            size.mm()
         }
     }
 
-    fn temp(&self, size: f64) -> f64 {
-        let delta_temp = self.mat.temp - 20.0;
-        size * (1.0 + self.mat.cte * 0.000_001 * delta_temp)
-    }
-
     pub fn show(&mut self, ui: &mut Ui, state: &mut State) {
         let id = if self.hole { "hole" } else { "shaft" };
 
@@ -151,7 +200,7 @@ impl Feature {
                 .show(ui, |ui| {
                     ui.vertical(|ui| {
                         self.feature_input_ui(ui, id, state);
-                        self.feature_output_ui(ui, id, false);
+                        self.feature_output_ui(ui, id, false, &state.units);
                     });
                 });
 
@@ -162,17 +211,63 @@ impl Feature {
                     .show(ui, |ui| {
                         ui.vertical(|ui| {
                             self.thermal_input_ui(ui, state);
-                            self.feature_output_ui(ui, &(id.to_owned() + "_thermal"), true);
+                            self.feature_output_ui(
+                                ui,
+                                &(id.to_owned() + "_thermal"),
+                                true,
+                                &state.units,
+                            );
                             // self.thermal_output_ui(ui, id);
                         });
                     });
             }
+
+            if state.explain && self.standard {
+                egui::Frame::group(ui.style())
+                    .inner_margin(10.0)
+                    .rounding(10.0)
+                    .show(ui, |ui| {
+                        ui.vertical(|ui| {
+                            explain::show(ui, id, &self.iso, self.size.mm());
+                        });
+                    });
+            }
+
+            if state.geometric {
+                egui::Frame::group(ui.style())
+                    .inner_margin(10.0)
+                    .rounding(10.0)
+                    .show(ui, |ui| {
+                        ui.vertical(|ui| {
+                            geometric::show(
+                                ui,
+                                id,
+                                &mut self.geometric,
+                                self.size.mm(),
+                                &state.units,
+                            );
+                        });
+                    });
+            }
+
+            if state.capability {
+                egui::Frame::group(ui.style())
+                    .inner_margin(10.0)
+                    .rounding(10.0)
+                    .show(ui, |ui| {
+                        ui.vertical(|ui| {
+                            let mut process = self.process;
+                            capability::show(ui, id, &mut process, self, &state.units);
+                            self.process = process;
+                        });
+                    });
+            }
         });
         // });
     }
 
     fn feature_input_ui(&mut self, ui: &mut Ui, id: &str, state: &mut State) {
-        let dropdowns = GradesDeviations::default();
+        let units = state.units;
 
         ui.horizontal(|ui| {
             ui.toggle_value(&mut self.standard, "ISO")
@@ -188,19 +283,14 @@ impl Feature {
             let size_drag = ui
                 .add_sized(
                     [45.0, 18.0],
-                    DragValue::new(&mut self.size)
-                        // .custom_formatter(|s, _| format!("{s} mm"))
-                        // .custom_parser(|s| {
-                        //     let to_parse = s
-                        //         .chars()
-                        //         .filter(|c| c.is_ascii_digit() || c == &'.')
-                        //         .collect::<String>();
-                        //     to_parse.parse::<f64>().ok()
-                        // })
+                    self.size
+                        .drag()
+                        .custom_formatter(|s, _| units.number(s))
+                        .custom_parser(|s| units.parse_length(s))
                         .speed(0.1)
-                        .range(0.0..=3_150.0),
+                        .range(0.0..=10_000.0),
                 )
-                .on_hover_text("Size");
+                .on_hover_text(format!("Size ({})", units.length_unit()));
 
             if size_drag.changed() {
                 state.synced_size = self.size;
@@ -209,14 +299,14 @@ impl Feature {
             if self.standard {
                 ComboBox::from_id_salt(format!("{}_deviation", id))
                     .width(45.0)
-                    .selected_text(&self.iso.deviation)
+                    .selected_text(self.iso.deviation.to_string())
                     .show_ui(ui, |ui| {
-                        for letter in if self.hole {
-                            &dropdowns.hole_letters
-                        } else {
-                            &dropdowns.shaft_letters
-                        } {
-                            ui.selectable_value(&mut self.iso.deviation, letter.clone(), letter);
+                        for deviation in FundamentalDeviation::all(self.hole) {
+                            ui.selectable_value(
+                                &mut self.iso.deviation,
+                                deviation,
+                                deviation.to_string(),
+                            );
                         }
                     })
                     .response
@@ -224,57 +314,118 @@ impl Feature {
 
                 ComboBox::from_id_salt(format!("{}_grade", id))
                     .width(45.0)
-                    .selected_text(&self.iso.grade)
+                    .selected_text(self.iso.grade.to_string())
                     .show_ui(ui, |ui| {
-                        for grade in &dropdowns.it_numbers {
-                            ui.selectable_value(&mut self.iso.grade, grade.clone(), grade);
+                        for grade in ToleranceGrade::ALL {
+                            ui.selectable_value(&mut self.iso.grade, grade, grade.to_string());
                         }
                     })
                     .response
                     .on_hover_text("Grade");
                 ui.end_row();
             } else {
+                ComboBox::from_id_salt(format!("{}_general", id))
+                    .width(45.0)
+                    .selected_text(self.general.map_or("±".to_owned(), |c| c.to_string()))
+                    .show_ui(ui, |ui| {
+                        ui.selectable_value(&mut self.general, None, "±")
+                            .on_hover_text("Manual limits");
+                        for class in GeneralClass::ALL {
+                            ui.selectable_value(&mut self.general, Some(class), class.to_string())
+                                .on_hover_text(format!("ISO 2768-{class}, {}", class.name()));
+                        }
+                    })
+                    .response
+                    .on_hover_text("ISO 2768-1 general tolerance class");
+
+                if self.general.is_some() {
+                    return;
+                }
+
+                let (size, upper, lower) = (
+                    self.size.mm(),
+                    self.tolerance.upper.mm(),
+                    self.tolerance.lower.mm(),
+                );
+
                 ui.add_sized(
                     [45.0, 18.0],
-                    DragValue::new(&mut self.tolerance.lower)
+                    self.tolerance
+                        .lower
+                        .drag()
+                        .custom_formatter(|l, _| units.number(l))
+                        .custom_parser(|l| units.parse_length(l))
                         .speed(0.001)
-                        .range(-self.size..=self.tolerance.upper)
-                        .min_decimals(3),
+                        .range(-size..=upper),
                 )
-                .on_hover_text("Lower limit");
+                .on_hover_text(format!("Lower limit ({})", units.length_unit()));
                 ui.add_sized(
                     [45.0, 18.0],
-                    DragValue::new(&mut self.tolerance.upper)
+                    self.tolerance
+                        .upper
+                        .drag()
+                        .custom_formatter(|u, _| units.number(u))
+                        .custom_parser(|u| units.parse_length(u))
                         .speed(0.001)
-                        .range(self.tolerance.lower..=f64::MAX)
-                        .min_decimals(3),
+                        .range(lower..=f64::MAX),
                 )
-                .on_hover_text("Upper limit");
+                .on_hover_text(format!("Upper limit ({})", units.length_unit()));
             }
         });
     }
 
-    fn feature_output_ui(&mut self, ui: &mut Ui, id: &str, thermal: bool) {
-        if !self.standard {
-        } else if let Some(mut tolerance) = self.iso.convert(self.size) {
-            tolerance.round(-1);
-            self.tolerance = tolerance;
-        } else {
-            ui.colored_label(
-                egui::Color32::RED,
-                "Invalid fundamental deviation",
-            )
-            .on_hover_cursor(egui::CursorIcon::Help)
-            .on_hover_text("This combination of size, deviation and tolerance grade does not exist within the ISO limits and fits system. Please refer to the ISO preferred fits.");
-            return;
+    fn feature_output_ui(&mut self, ui: &mut Ui, id: &str, thermal: bool, units: &Units) {
+        if self.standard {
+            // Beyond the tables the formulas take over
+            let converted = self
+                .iso
+                .convert(self.size.mm())
+                .map(|t| (t, false))
+                .or_else(|error| match error {
+                    IsoError::SizeOutOfRange(size) if size > formula::TABLE_LIMIT => {
+                        formula::compute(&self.iso, size)
+                            .map(|computed| (computed.tolerance, computed.extrapolated))
+                            .ok_or(error)
+                    }
+                    _ => Err(error),
+                });
+
+            match converted {
+                Ok((tolerance, extrapolated)) => {
+                    self.tolerance = tolerance;
+
+                    if extrapolated && !thermal {
+                        ui.colored_label(egui::Color32::YELLOW, "⚠ Extrapolated")
+                            .on_hover_cursor(egui::CursorIcon::Help)
+                            .on_hover_text("This size is beyond the ISO 286 tables, the limits are worked out from the ISO 286-1 formulas.");
+                    }
+                }
+                Err(error) => {
+                    ui.colored_label(egui::Color32::RED, error.to_string())
+                        .on_hover_cursor(egui::CursorIcon::Help)
+                        .on_hover_text("This combination of size, deviation and tolerance grade does not exist within the ISO limits and fits system. Please refer to the ISO preferred fits.");
+                    return;
+                }
+            }
+        } else if let Some(class) = self.general {
+            match general::tolerance(class, self.size.mm()) {
+                Ok(tolerance) => self.tolerance = tolerance,
+                Err(error) => {
+                    ui.colored_label(egui::Color32::RED, error.to_string());
+                    return;
+                }
+            }
+        } else if !thermal {
+            self.iso_match_ui(ui, units);
         }
 
-        let (units, scale) = if self.tolerance.upper.abs() < 1.0 && self.tolerance.lower.abs() < 1.0
-        {
-            ("µm", 1_000.0)
-        } else {
-            ("mm", 1.0)
-        };
+        // Deviations share a unit, picked from the largest
+        let range = self
+            .tolerance
+            .upper
+            .abs()
+            .max(self.tolerance.lower.abs())
+            .mm();
 
         ui.add_space(5.0);
 
@@ -285,19 +436,9 @@ impl Feature {
                 ui.label("⬆")
                     .on_hover_cursor(egui::CursorIcon::Default)
                     .on_hover_text("Upper limit");
-                ui.label(format!("{:.}", decimals(self.upper_limit(thermal), -1)));
-                ui.label("mm");
+                ui.label(units.length(self.upper_limit_mm(thermal)));
                 if !thermal {
-                    ui.label(format!(
-                        "{}{}",
-                        if self.tolerance.upper.is_sign_positive() {
-                            "+"
-                        } else {
-                            ""
-                        },
-                        decimals(scale * self.tolerance.upper, -1)
-                    ));
-                    ui.label(format!("{units}"));
+                    ui.label(units.deviation(self.tolerance.upper.mm(), range, true));
                 }
                 ui.end_row();
 
@@ -305,36 +446,72 @@ impl Feature {
                 ui.label("⬍")
                     .on_hover_cursor(egui::CursorIcon::Default)
                     .on_hover_text("Mid-limits");
-                ui.label(format!("{:.}", decimals(self.middle_limit(thermal), -1)));
-                ui.label("mm");
+                ui.label(units.length(self.middle_limit_mm(thermal)));
                 if !thermal {
-                    ui.label(format!("±{:.}", decimals(scale * self.tolerance.mid(), -1)));
-                    ui.label(format!("{units}"));
+                    ui.label(format!(
+                        "±{}",
+                        units.deviation(self.tolerance.mid().mm(), range, false)
+                    ));
                 }
                 ui.end_row();
 
                 ui.label("⬇")
                     .on_hover_cursor(egui::CursorIcon::Default)
                     .on_hover_text("Lower limit");
-                ui.label(format!("{:.}", decimals(self.lower_limit(thermal), -1)));
-                ui.label("mm");
+                ui.label(units.length(self.lower_limit_mm(thermal)));
                 if !thermal {
-                    ui.label(format!(
-                        "{}{}",
-                        if self.tolerance.lower.is_sign_positive() {
-                            "+"
-                        } else {
-                            ""
-                        },
-                        decimals(scale * self.tolerance.lower, -1)
-                    ));
-                    ui.label(format!("{units}"));
+                    ui.label(units.deviation(self.tolerance.lower.mm(), range, true));
                 }
                 ui.end_row();
             });
     }
 
+    fn iso_match_ui(&mut self, ui: &mut Ui, units: &Units) {
+        let matches = Iso::find(self.hole, self.size.mm(), &self.tolerance);
+
+        ui.horizontal(|ui| {
+            ui.label("≈")
+                .on_hover_cursor(egui::CursorIcon::Help)
+                .on_hover_text("Closest ISO tolerance classes, click to use one");
+
+            for found in matches.iter().take(3) {
+                let text = if found.is_exact() {
+                    format!("{} ✔", found.iso)
+                } else {
+                    format!(
+                        "{} Δ{}",
+                        found.iso,
+                        units.deviation(found.error.mm(), found.error.mm(), false)
+                    )
+                };
+
+                if ui
+                    .button(text)
+                    .on_hover_text(format!(
+                        "{} / {}",
+                        units.deviation(
+                            found.tolerance.upper.mm(),
+                            found.tolerance.upper.mm(),
+                            true
+                        ),
+                        units.deviation(
+                            found.tolerance.lower.mm(),
+                            found.tolerance.lower.mm(),
+                            true
+                        )
+                    ))
+                    .clicked()
+                {
+                    self.iso = found.iso.clone();
+                    self.standard = true;
+                }
+            }
+        });
+    }
+
     fn thermal_input_ui(&mut self, ui: &mut Ui, state: &mut State) {
+        let units = state.units;
+
         ui.horizontal(|ui| {
             if state.sync_temp {
                 self.mat.temp = state.synced_temp;
@@ -347,14 +524,8 @@ impl Feature {
                 .add_sized(
                     [45.0, 18.0],
                     egui::DragValue::new(&mut self.mat.temp)
-                        .custom_formatter(|t, _| format!("{t} ºC"))
-                        .custom_parser(|t| {
-                            let to_parse = t
-                                .chars()
-                                .filter(|c| c.is_ascii_digit() || c == &'.' || c == &'-')
-                                .collect::<String>();
-                            to_parse.parse::<f64>().ok()
-                        })
+                        .custom_formatter(|t, _| units.temp(t))
+                        .custom_parser(|t| units.parse_temp(t))
                         .speed(1.0)
                         .range(-273.15..=10_000.0)
                         .min_decimals(1),
@@ -365,41 +536,42 @@ impl Feature {
                 state.synced_temp = self.mat.temp;
             }
 
+            let id = if self.hole { "hole" } else { "shaft" };
+            material::select_ui(ui, id, &mut self.mat, &state.materials, &units);
+
             ui.add_sized(
-                [60.0, 18.0],
+                [90.0, 18.0],
                 DragValue::new(&mut self.mat.cte)
-                    .custom_formatter(|e, _| format!("{e:.1} ¹/k")) // /ºC")) ¹/k
-                    .custom_parser(|t| {
-                        let parsed = t
-                            .chars()
-                            .filter(|c| c.is_ascii_digit() || *c == '.' || *c == '-')
-                            .collect::<String>();
-                        parsed.parse::<f64>().ok()
-                    })
+                    .custom_formatter(|e, _| units.cte(e))
+                    .custom_parser(|e| units.parse_cte(e))
                     .speed(0.1)
                     .range(0.0..=f64::MAX)
                     .min_decimals(1),
             )
-            .on_hover_text("Thermal expansion coefficient");
+            .on_hover_text(
+                "Thermal expansion coefficient at 20 ºC, the material curve scales with it",
+            );
 
             if self.hole {
                 if ui
                     .add_sized([40.0, 18.0], egui::Button::new("Oven"))
-                    .on_hover_text("Set to 170 ºC")
+                    .on_hover_text(format!("Set to {}", units.temp(170.0)))
                     .clicked()
                 {
                     self.mat.temp = 170.0;
                 }
-            } else {
-                if ui
-                    .add_sized([40.0, 18.0], egui::Button::new("LN"))
-                    .on_hover_text("Set to -196 ºC")
-                    .clicked()
-                {
-                    self.mat.temp = -196.0;
-                }
+            } else if ui
+                .add_sized([40.0, 18.0], egui::Button::new("LN"))
+                .on_hover_text(format!("Set to {}", units.temp(-196.0)))
+                .clicked()
+            {
+                self.mat.temp = -196.0;
             }
         });
+
+        if let Some(warning) = self.mat.range_warning(self.mat.temp, &units) {
+            ui.colored_label(egui::Color32::YELLOW, warning);
+        }
     }
 
     // fn thermal_output_ui(&mut self, ui: &mut Ui, id: &str) {
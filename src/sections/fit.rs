@@ -1,36 +1,48 @@
 use egui::{Grid, RichText, Ui};
 
 use super::{
-    feature::Feature,
-    utils::{decimals, State},
+    feature::Feature, length::Length, preferred::PreferredFit, units::Units, utils::State,
 };
 
 #[derive(serde::Deserialize, serde::Serialize)]
 pub struct Fit {
     pub kind: String,
     pub class: String,
-    pub mmc: f64,
-    pub lmc: f64,
-    pub mid: f64,
+    pub mmc: Length,
+    pub lmc: Length,
+    pub mid: Length,
+    /// Max material condition less the run-out of both features, if either has an ISO 2768-2 class.
+    #[serde(default)]
+    pub run_out_mmc: Option<Length>,
     pub hole: Feature,
     pub shaft: Feature,
 }
 
+impl Default for Fit {
+    fn default() -> Self {
+        Fit::new(&Feature::default_hole(), &Feature::default_shaft())
+    }
+}
+
 impl Fit {
     pub fn new(hole: &Feature, shaft: &Feature) -> Self {
-        let mmc = hole.lower_limit(false) - shaft.upper_limit(false);
-        let lmc = hole.upper_limit(false) - shaft.lower_limit(false);
-        let mid = (mmc + lmc) / 2.0;
+        let mmc = hole.lower_limit() - shaft.upper_limit();
+        let lmc = hole.upper_limit() - shaft.lower_limit();
+        let mid = (mmc + lmc) / 2;
 
-        let kind = if mmc >= 0.0 {
+        // A shaft running out sweeps a larger diameter and a hole a smaller one
+        let run_out_mmc = (hole.geometric.is_some() || shaft.geometric.is_some())
+            .then(|| mmc - hole.run_out() - shaft.run_out());
+
+        let kind = if mmc >= Length::ZERO {
             "Clearance".to_owned()
-        } else if lmc <= 0.0 {
+        } else if lmc <= Length::ZERO {
             "Interference".to_owned()
         } else {
             "Transition".to_owned()
         };
 
-        let class = if mid >= 0.0 {
+        let class = if mid >= Length::ZERO {
             "Clearance".to_owned()
         } else {
             "Interference".to_owned()
@@ -42,33 +54,19 @@ impl Fit {
             mmc,
             lmc,
             mid,
+            run_out_mmc,
             hole: hole.clone(),
             shaft: shaft.clone(),
         }
     }
 
-    pub fn default() -> Self {
-        Self {
-            kind: "Clearance".to_owned(),
-            class: "clearance".to_owned(),
-            mmc: 24.0,
-            lmc: 0.0,
-            mid: 12.0,
-            hole: Feature::default_hole(),
-            shaft: Feature::default_shaft(),
-        }
-    }
-
     pub fn show(&self, ui: &mut egui::Ui, state: &State) {
-        let (units, scale) = if self.mmc.abs() < 1.0 && self.lmc.abs() < 1.0 {
-            ("µm", 1_000.0)
-        } else {
-            ("mm", 1.0)
-        };
+        // Conditions share a unit, picked from the largest
+        let range = self.mmc.abs().max(self.lmc.abs()).mm();
 
         ui.horizontal(|ui| {
             ui.vertical(|ui| {
-                self.fit_title_ui(ui);
+                self.fit_title_ui(ui, &state.units);
 
                 ui.add_space(5.0);
 
@@ -77,7 +75,7 @@ impl Fit {
                     .rounding(10.0)
                     .show(ui, |ui| {
                         ui.vertical(|ui| {
-                            self.fit_output_ui(ui, units, scale, false);
+                            self.fit_output_ui(ui, &state.units, range, false);
                         });
                     });
             });
@@ -95,7 +93,7 @@ impl Fit {
                         .rounding(10.0)
                         .show(ui, |ui| {
                             ui.vertical(|ui| {
-                                self.fit_output_ui(ui, units, scale, true);
+                                self.fit_output_ui(ui, &state.units, range, true);
                             });
                         });
                 });
@@ -103,7 +101,7 @@ impl Fit {
         });
     }
 
-    fn fit_title_ui(&self, ui: &mut Ui) {
+    fn fit_title_ui(&self, ui: &mut Ui, units: &Units) {
         ui.horizontal(|ui| {
             ui.label(
                 RichText::new(format!("{} Fit", self.kind,))
@@ -118,7 +116,7 @@ impl Fit {
                     let fit_text = if self.hole.size == self.shaft.size {
                         format!(
                             "{} {}{} / {}{}",
-                            self.hole.size,
+                            units.length(self.hole.size.mm()),
                             self.hole.iso.deviation,
                             self.hole.iso.grade,
                             self.shaft.iso.deviation,
@@ -127,24 +125,36 @@ impl Fit {
                     } else {
                         format!(
                             "{} {}{} / {} {}{}",
-                            self.hole.size,
+                            units.length(self.hole.size.mm()),
                             self.hole.iso.deviation,
                             self.hole.iso.grade,
-                            self.shaft.size,
+                            units.length(self.shaft.size.mm()),
                             self.shaft.iso.deviation,
                             self.shaft.iso.grade,
                         )
                     };
 
-                    if ui.button(fit_text.clone()).on_hover_text("Copy").clicked() {
-                        ui.output_mut(|o| o.copied_text = fit_text);
-                    }
+                    ui.horizontal(|ui| {
+                        if ui.button(fit_text.clone()).on_hover_text("Copy").clicked() {
+                            ui.output_mut(|o| o.copied_text = fit_text);
+                        }
+
+                        if let Some(preferred) = PreferredFit::find(&self.hole.iso, &self.shaft.iso)
+                        {
+                            ui.label(format!("★ {}", preferred.name))
+                                .on_hover_cursor(egui::CursorIcon::Help)
+                                .on_hover_text(format!(
+                                    "ISO preferred fit. {}\n\nTypical uses: {}",
+                                    preferred.description, preferred.uses
+                                ));
+                        }
+                    });
                 }
             });
         });
     }
 
-    fn fit_output_ui(&self, ui: &mut Ui, units: &str, scale: f64, thermal: bool) {
+    fn fit_output_ui(&self, ui: &mut Ui, units: &Units, range: f64, thermal: bool) {
         let id = if thermal { "thermal_fit" } else { "fit" };
 
         let condition = |mc: f64| {
@@ -155,8 +165,14 @@ impl Fit {
             }
         };
 
-        let mmc = self.hole.lower_limit(thermal) - self.shaft.upper_limit(thermal);
-        let lmc = self.hole.upper_limit(thermal) - self.shaft.lower_limit(thermal);
+        let (mmc, lmc) = if thermal {
+            (
+                self.hole.lower_limit_mm(true) - self.shaft.upper_limit_mm(true),
+                self.hole.upper_limit_mm(true) - self.shaft.lower_limit_mm(true),
+            )
+        } else {
+            (self.mmc.mm(), self.lmc.mm())
+        };
         let mid = (mmc + lmc) / 2.0;
 
         let mmc_type = condition(mmc);
@@ -170,23 +186,33 @@ impl Fit {
                 ui.label("🌑")
                     .on_hover_cursor(egui::CursorIcon::Default)
                     .on_hover_text("Max material condition");
-                ui.label(format!("{:.} {units}", decimals(scale * mmc.abs(), 3)));
+                ui.label(units.deviation(mmc.abs(), range, false));
                 ui.label(mmc_type);
                 ui.end_row();
 
                 ui.label("🌓")
                     .on_hover_cursor(egui::CursorIcon::Default)
                     .on_hover_text("Mid limits");
-                ui.label(format!("{:.} {units}", decimals(scale * mid.abs(), 3)));
+                ui.label(units.deviation(mid.abs(), range, false));
                 ui.label(mid_type);
                 ui.end_row();
 
                 ui.label("🌕")
                     .on_hover_cursor(egui::CursorIcon::Default)
                     .on_hover_text("Min material condition");
-                ui.label(format!("{:.} {units}", decimals(scale * lmc.abs(), 3)));
+                ui.label(units.deviation(lmc.abs(), range, false));
                 ui.label(lmc_type);
                 ui.end_row();
+
+                if let Some(run_out_mmc) = self.run_out_mmc.filter(|_| !thermal) {
+                    let run_out_mmc = run_out_mmc.mm();
+                    ui.label("↗")
+                        .on_hover_cursor(egui::CursorIcon::Default)
+                        .on_hover_text("Max material condition with the ISO 2768-2 circular run-out of both features");
+                    ui.label(units.deviation(run_out_mmc.abs(), range, false));
+                    ui.label(condition(run_out_mmc));
+                    ui.end_row();
+                }
             });
     }
 
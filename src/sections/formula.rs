@@ -0,0 +1,634 @@
+use egui::{CollapsingHeader, Color32, Grid, RichText, ScrollArea, Ui};
+
+use super::{
+    length::Length,
+    lookup::{DELTA, DEVIATIONS_A_G, DEVIATIONS_K_ZC, DEVIATION_MAP, STANDARD_TOLERANCE_GRADES},
+    tolerance::{FundamentalDeviation, Iso, Letter, Tolerance, ToleranceGrade},
+    utils::decimals,
+};
+
+// ISO 286-1 formulas for the standard tolerances and fundamental deviations
+// D is the geometric mean of the size range in mm, results are in micrometres
+// These are independent of lookup.rs so that the two can be checked against each other
+
+/// Largest size covered by the ISO 286 tables in mm.
+pub const TABLE_LIMIT: f64 = 3_150.0;
+
+// (calculated value up to, rounded to a multiple of) in micrometres
+const ROUND_IT: &[(f64, f64)] = &[
+    (3.0, 0.1),
+    (10.0, 0.5),
+    (60.0, 1.0),
+    (100.0, 2.0),
+    (200.0, 5.0),
+    (500.0, 10.0),
+    (1_000.0, 20.0),
+    (2_000.0, 50.0),
+    (5_000.0, 100.0),
+    (10_000.0, 200.0),
+    (20_000.0, 500.0),
+    (f64::MAX, 1_000.0),
+];
+
+const ROUND_A_G: &[(f64, f64)] = &[
+    (45.0, 1.0),
+    (60.0, 2.0),
+    (200.0, 5.0),
+    (500.0, 10.0),
+    (1_000.0, 20.0),
+    (2_000.0, 50.0),
+    (f64::MAX, 100.0),
+];
+
+const ROUND_K_ZC: &[(f64, f64)] = &[
+    (100.0, 1.0),
+    (200.0, 2.0),
+    (500.0, 5.0),
+    (1_000.0, 10.0),
+    (2_000.0, 20.0),
+    (5_000.0, 50.0),
+    (f64::MAX, 100.0),
+];
+
+// Δ is taken from the tolerance table, anything but an exact match is a typo
+const ROUND_DELTA: &[(f64, f64)] = &[(f64::MAX, 0.0)];
+
+/// Rounding step the standard uses at `value`.
+fn step(value: f64, steps: &[(f64, f64)]) -> f64 {
+    let (_, step) = steps
+        .iter()
+        .find(|(limit, _)| value.abs() <= *limit)
+        .unwrap_or(&steps[steps.len() - 1]);
+    *step
+}
+
+fn round_to(value: f64, steps: &[(f64, f64)]) -> f64 {
+    let step = step(value, steps);
+    (value / step).round() * step
+}
+
+/// Geometric mean of the range `size` falls in and whether it lies beyond the last range.
+/// Ranges are bounded by the size column of a lookup table, the first runs from 1 mm.
+pub fn mean_diameter(bounds: impl Iterator<Item = i32>, size: f64) -> (f64, bool) {
+    let mut lower = 1.0;
+
+    for bound in bounds {
+        let upper = bound as f64;
+        if size <= upper {
+            return ((lower * upper).sqrt(), false);
+        }
+        lower = upper;
+    }
+
+    (size, true)
+}
+
+/// Standard tolerance factor i (up to 500 mm) or I (above 500 mm) in micrometres.
+pub fn tolerance_factor(d: f64) -> f64 {
+    if d <= 500.0 {
+        0.45 * d.cbrt() + 0.001 * d
+    } else {
+        0.004 * d + 2.1
+    }
+}
+
+/// Standard tolerance in micrometres, IT01 and IT0 only exist up to 500 mm.
+pub fn standard_tolerance(grade: ToleranceGrade, d: f64) -> Option<f64> {
+    use ToleranceGrade::*;
+
+    let i = tolerance_factor(d);
+    let small = d <= 500.0;
+    let it1 = if small { 0.8 + 0.020 * d } else { 2.0 * i };
+    // IT2 to IT4 sit geometrically between IT1 and IT5
+    let between = |n: f64| it1 * (7.0 * i / it1).powf(n / 4.0);
+
+    let raw = match grade {
+        IT01 if small => 0.3 + 0.008 * d,
+        IT0 if small => 0.5 + 0.012 * d,
+        IT01 | IT0 => return None,
+        IT1 => it1,
+        IT2 if small => between(1.0),
+        IT3 if small => between(2.0),
+        IT4 if small => between(3.0),
+        IT2 => 2.7 * i,
+        IT3 => 3.7 * i,
+        IT4 => 5.0 * i,
+        IT5 => 7.0 * i,
+        IT6 => 10.0 * i,
+        IT7 => 16.0 * i,
+        IT8 => 25.0 * i,
+        IT9 => 40.0 * i,
+        IT10 => 64.0 * i,
+        IT11 => 100.0 * i,
+        IT12 => 160.0 * i,
+        IT13 => 250.0 * i,
+        IT14 => 400.0 * i,
+        IT15 => 640.0 * i,
+        IT16 => 1_000.0 * i,
+        IT17 => 1_600.0 * i,
+        IT18 => 2_500.0 * i,
+    };
+
+    Some(round_to(raw, ROUND_IT))
+}
+
+/// Unrounded magnitude of the shaft fundamental deviation in micrometres, as the
+/// range the standard allows, None where it gives no formula or the letter isn't
+/// used at this size. a to c and r to zc use `d` from the intermediate size
+/// ranges, the other letters and the standard tolerances use `d_main`.
+fn raw_shaft_deviation(
+    letter: Letter,
+    grade: ToleranceGrade,
+    d: f64,
+    d_main: f64,
+) -> Option<(f64, f64)> {
+    use Letter::*;
+
+    let small = d <= 500.0;
+    let it = |grade: ToleranceGrade| standard_tolerance(grade, d_main);
+    let raw = |letter: Letter| raw_shaft_deviation(letter, grade, d, d_main);
+    // Geometric mean of two deviations, end by end
+    let between = |a: Letter, b: Letter| {
+        let (a, b) = (raw(a)?, raw(b)?);
+        Some(((a.0 * b.0).sqrt(), (a.1 * b.1).sqrt()))
+    };
+
+    let deviation = match letter {
+        A if d <= 120.0 => 265.0 + 1.3 * d,
+        A if small => 3.5 * d,
+        B if d <= 160.0 => 140.0 + 0.85 * d,
+        B if small => 1.8 * d,
+        C if d <= 40.0 => 52.0 * d.powf(0.2),
+        C if small => 95.0 + 0.8 * d,
+        CD if d_main <= 50.0 => return between(C, D),
+        D => 16.0 * d_main.powf(0.44),
+        E => 11.0 * d_main.powf(0.41),
+        EF if d_main <= 50.0 => return between(E, F),
+        F => 5.5 * d_main.powf(0.41),
+        FG if d_main <= 50.0 => return between(F, G),
+        G => 2.5 * d_main.powf(0.34),
+        H => 0.0,
+        // k is zero up to 3 mm, where D is below 3
+        K if small
+            && d_main > 3.0
+            && (ToleranceGrade::IT4..=ToleranceGrade::IT7).contains(&grade) =>
+        {
+            0.6 * d_main.cbrt()
+        }
+        K => 0.0,
+        M if small => it(ToleranceGrade::IT7)? - it(ToleranceGrade::IT6)?,
+        M => 0.024 * d_main + 12.6,
+        N if small => 5.0 * d_main.powf(0.34),
+        N => 0.04 * d_main + 21.0,
+        P if small => {
+            let it7 = it(ToleranceGrade::IT7)?;
+            return Some((it7, it7 + 5.0));
+        }
+        P => 0.072 * d_main + 37.8,
+        R => return between(P, S),
+        S if d <= 50.0 => {
+            let it8 = it(ToleranceGrade::IT8)?;
+            return Some((it8 + 1.0, it8 + 4.0));
+        }
+        S => it(ToleranceGrade::IT7)? + 0.4 * d,
+        T => it(ToleranceGrade::IT7)? + 0.63 * d,
+        U => it(ToleranceGrade::IT7)? + d,
+        V if small => it(ToleranceGrade::IT7)? + 1.25 * d,
+        X if small => it(ToleranceGrade::IT7)? + 1.6 * d,
+        Y if small => it(ToleranceGrade::IT7)? + 2.0 * d,
+        Z if small => it(ToleranceGrade::IT7)? + 2.5 * d,
+        ZA if small => it(ToleranceGrade::IT8)? + 3.15 * d,
+        ZB if small => it(ToleranceGrade::IT9)? + 4.0 * d,
+        ZC if small => it(ToleranceGrade::IT10)? + 5.0 * d,
+        _ => return None,
+    };
+
+    Some((deviation, deviation))
+}
+
+/// Rounded range of the shaft fundamental deviation magnitude in micrometres.
+pub fn shaft_deviation_range(
+    letter: Letter,
+    grade: ToleranceGrade,
+    d: f64,
+    d_main: f64,
+) -> Option<(f64, f64)> {
+    let (low, high) = raw_shaft_deviation(letter, grade, d, d_main)?;
+    let rounding = if letter.index() <= Letter::H.index() {
+        ROUND_A_G
+    } else {
+        ROUND_K_ZC
+    };
+
+    Some((round_to(low, rounding), round_to(high, rounding)))
+}
+
+/// Shaft fundamental deviation in micrometres, the upper deviation (negative)
+/// for a to h and the lower deviation (positive) for k to zc. Where the standard
+/// gives a range the middle of it is taken.
+pub fn shaft_deviation(letter: Letter, grade: ToleranceGrade, d: f64, d_main: f64) -> Option<f64> {
+    let (low, high) = raw_shaft_deviation(letter, grade, d, d_main)?;
+    let raw = (low + high) / 2.0;
+
+    if letter.index() <= Letter::H.index() {
+        Some(-round_to(raw, ROUND_A_G))
+    } else {
+        Some(round_to(raw, ROUND_K_ZC))
+    }
+}
+
+/// Δ for hole deviations K to ZC in micrometres, ITn - ITn-1 up to IT8 and 500 mm.
+pub fn delta(grade: ToleranceGrade, d: f64) -> Option<f64> {
+    // Δ is zero up to 3 mm, where D is below 3
+    if !(3.0..=500.0).contains(&d) || !(ToleranceGrade::IT3..=ToleranceGrade::IT8).contains(&grade)
+    {
+        return Some(0.0);
+    }
+
+    let lower = ToleranceGrade::ALL[grade.index() - 1];
+    Some(standard_tolerance(grade, d)? - standard_tolerance(lower, d)?)
+}
+
+/// A tolerance class worked out from the formulas rather than the tables.
+#[derive(Clone, Debug)]
+pub struct Computed {
+    pub tolerance: Tolerance,
+    pub extrapolated: bool,
+}
+
+/// Works out `iso` at `size` in mm, sizes beyond the tables are extrapolated.
+pub fn compute(iso: &Iso, size: f64) -> Option<Computed> {
+    use Letter::*;
+
+    if size <= 0.0 {
+        return None;
+    }
+
+    let grade = iso.grade;
+    let letter = iso.deviation.letter();
+    let dev_bounds = if letter.index() <= H.index() {
+        DEVIATIONS_A_G.iter().map(|r| r[0]).collect::<Vec<_>>()
+    } else {
+        DEVIATIONS_K_ZC.iter().map(|r| r[0]).collect::<Vec<_>>()
+    };
+
+    let (d_it, extrapolated) = mean_diameter(STANDARD_TOLERANCE_GRADES.iter().map(|r| r[0]), size);
+    let (d_dev, _) = mean_diameter(dev_bounds.into_iter(), size);
+
+    let it = standard_tolerance(grade, d_it)?;
+
+    let (upper, lower) = match iso.deviation {
+        FundamentalDeviation::Hole(JS) | FundamentalDeviation::Shaft(JS) => (it / 2.0, -it / 2.0),
+        FundamentalDeviation::Hole(J) | FundamentalDeviation::Shaft(J) => return None,
+        FundamentalDeviation::Shaft(letter) => {
+            let dev = shaft_deviation(letter, grade, d_dev, d_it)?;
+            if letter.index() <= H.index() {
+                (dev, dev - it)
+            } else {
+                (dev + it, dev)
+            }
+        }
+        FundamentalDeviation::Hole(letter) if letter.index() <= H.index() => {
+            let dev = -shaft_deviation(letter, grade, d_dev, d_it)?;
+            (dev + it, dev)
+        }
+        FundamentalDeviation::Hole(letter) => {
+            let fine = match letter {
+                K | M | N => grade <= ToleranceGrade::IT8,
+                _ => grade <= ToleranceGrade::IT7,
+            };
+            // K uses the k value for IT4 to IT7 whatever the hole grade
+            let shaft_grade = if letter == K {
+                ToleranceGrade::IT6
+            } else {
+                grade
+            };
+            let dev = -shaft_deviation(letter, shaft_grade, d_dev, d_it)?;
+            let dev = match letter {
+                K | N if !fine => 0.0,
+                _ if fine => dev + delta(grade, d_it)?,
+                _ => dev,
+            };
+            (dev, dev - it)
+        }
+    };
+
+    Some(Computed {
+        tolerance: Tolerance::new(Length::from_um(upper), Length::from_um(lower)),
+        extrapolated,
+    })
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Agreement {
+    Exact,
+    Rounding,
+    Disagrees,
+}
+
+/// One lookup table cell next to the formula value, both in micrometres.
+#[derive(Clone, Debug)]
+pub struct CheckedCell {
+    pub table: &'static str,
+    pub size: i32,
+    pub column: String,
+    pub table_value: Option<f64>,
+    /// Lowest and highest value the formula allows, the same unless the
+    /// standard gives a range.
+    pub formula: Option<(f64, f64)>,
+    /// Rounding rule the table was published to, the tables were rounded
+    /// towards preferred numbers so one step either way still agrees.
+    pub rounding: &'static [(f64, f64)],
+}
+
+impl CheckedCell {
+    /// None where either side has no value to compare.
+    pub fn agreement(&self) -> Option<Agreement> {
+        let (table, (low, high)) = (self.table_value?, self.formula?);
+        let formula = table.clamp(low, high);
+        let difference = (table - formula).abs();
+
+        Some(if difference < 1e-6 {
+            Agreement::Exact
+        } else if difference <= step(formula, self.rounding) + 1e-6 {
+            Agreement::Rounding
+        } else {
+            Agreement::Disagrees
+        })
+    }
+}
+
+/// Compares every standard tolerance, fundamental deviation and Δ table cell with the formulas.
+/// The J tables have no formula in the standard and are left out.
+pub fn cross_check() -> Vec<CheckedCell> {
+    // Table cells are -1 where empty
+    let value = |cell: i32, scale: f64| (cell != -1).then(|| cell as f64 / scale);
+    let mut cells = Vec::new();
+
+    let mut lower = 1.0;
+    for row in STANDARD_TOLERANCE_GRADES {
+        let d = (lower * row[0] as f64).sqrt();
+        for grade in ToleranceGrade::ALL {
+            cells.push(CheckedCell {
+                table: "Standard tolerances",
+                size: row[0],
+                column: format!("IT{grade}"),
+                table_value: value(row[grade.index() + 1], 10.0),
+                formula: standard_tolerance(grade, d).map(|t| (t, t)),
+                rounding: ROUND_IT,
+            });
+        }
+        lower = row[0] as f64;
+    }
+
+    // Mean of the main size range a table row falls in
+    let main =
+        |size: i32| mean_diameter(STANDARD_TOLERANCE_GRADES.iter().map(|r| r[0]), size as f64).0;
+
+    let mut lower = 1.0;
+    for row in DEVIATIONS_A_G {
+        let d = (lower * row[0] as f64).sqrt();
+        for letter in &Letter::ALL[..Letter::H.index()] {
+            cells.push(CheckedCell {
+                table: "Deviations a to g",
+                size: row[0],
+                column: DEVIATION_MAP[letter.index()].to_owned(),
+                table_value: value(row[letter.index() + 1], 1.0),
+                formula: shaft_deviation_range(*letter, ToleranceGrade::IT7, d, main(row[0])),
+                rounding: ROUND_A_G,
+            });
+        }
+        lower = row[0] as f64;
+    }
+
+    let mut lower = 1.0;
+    for row in DEVIATIONS_K_ZC {
+        let d = (lower * row[0] as f64).sqrt();
+        for letter in &Letter::ALL[Letter::K.index()..] {
+            // The k column holds the value for IT4 to IT7
+            cells.push(CheckedCell {
+                table: "Deviations k to zc",
+                size: row[0],
+                column: DEVIATION_MAP[letter.index()].to_owned(),
+                table_value: value(row[letter.index() - Letter::K.index() + 1], 1.0),
+                formula: shaft_deviation_range(*letter, ToleranceGrade::IT6, d, main(row[0])),
+                rounding: ROUND_K_ZC,
+            });
+        }
+        lower = row[0] as f64;
+    }
+
+    // Δ is checked against the tolerance table it should be derived from
+    for row in DELTA {
+        let it = STANDARD_TOLERANCE_GRADES
+            .iter()
+            .find(|r| r[0] == row[0])
+            .expect("Δ sizes match the tolerance table");
+        // Columns run from IT3 to IT8
+        for n in 0..6 {
+            let idx = ToleranceGrade::IT3.index() + n + 1;
+            let derived = if row[0] <= 3 {
+                0.0
+            } else {
+                (it[idx] - it[idx - 1]) as f64 / 10.0
+            };
+            cells.push(CheckedCell {
+                table: "Δ",
+                size: row[0],
+                column: format!("IT{}", ToleranceGrade::ALL[idx - 1]),
+                table_value: value(row[n + 1], 10.0),
+                formula: Some((derived, derived)),
+                rounding: ROUND_DELTA,
+            });
+        }
+    }
+
+    cells
+}
+
+/// Debug view of the lookup tables with every cell coloured by how well it matches the formulas.
+pub fn show_cross_check(ui: &mut Ui) {
+    let cells = cross_check();
+    let count = |agreement: Agreement| {
+        cells
+            .iter()
+            .filter(|c| c.agreement() == Some(agreement))
+            .count()
+    };
+
+    ui.label(format!(
+        "{} exact, {} within rounding, {} disagree",
+        count(Agreement::Exact),
+        count(Agreement::Rounding),
+        count(Agreement::Disagrees)
+    ));
+
+    let tables = [
+        "Standard tolerances",
+        "Deviations a to g",
+        "Deviations k to zc",
+        "Δ",
+    ];
+
+    ScrollArea::both().max_height(400.0).show(ui, |ui| {
+        for table in tables {
+            let rows = cells
+                .iter()
+                .filter(|c| c.table == table)
+                .collect::<Vec<_>>();
+            let disagree = rows
+                .iter()
+                .filter(|c| c.agreement() == Some(Agreement::Disagrees))
+                .count();
+
+            CollapsingHeader::new(format!("{table} ({disagree} disagree)"))
+                .default_open(disagree > 0)
+                .show(ui, |ui| {
+                    Grid::new(format!("cross_check_{table}"))
+                        .striped(true)
+                        .min_col_width(10.0)
+                        .show(ui, |ui| {
+                            let first_size = rows[0].size;
+                            ui.label("mm");
+                            for cell in rows.iter().take_while(|c| c.size == first_size) {
+                                ui.label(RichText::new(&cell.column).strong());
+                            }
+
+                            let mut size = None;
+                            for cell in &rows {
+                                if size != Some(cell.size) {
+                                    ui.end_row();
+                                    ui.label(cell.size.to_string());
+                                    size = Some(cell.size);
+                                }
+
+                                let text = cell
+                                    .table_value
+                                    .map_or("–".to_owned(), |v| format!("{}", decimals(v, 1)));
+                                let colour = match cell.agreement() {
+                                    Some(Agreement::Exact) => Color32::from_rgb(0, 160, 0),
+                                    Some(Agreement::Disagrees) => Color32::RED,
+                                    _ => ui.visuals().text_color(),
+                                };
+
+                                let label = ui.colored_label(colour, text);
+                                if let Some((low, high)) = cell.formula {
+                                    let formula = if low == high {
+                                        decimals(low, 1).to_string()
+                                    } else {
+                                        format!("{} to {}", decimals(low, 1), decimals(high, 1))
+                                    };
+                                    label.on_hover_text(format!("Formula {formula} µm"));
+                                }
+                            }
+                            ui.end_row();
+                        });
+                });
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_formulas() {
+        // 18 to 30 mm
+        let d = (18.0f64 * 30.0).sqrt();
+        assert_eq!(standard_tolerance(ToleranceGrade::IT7, d), Some(21.0));
+        assert_eq!(standard_tolerance(ToleranceGrade::IT12, d), Some(210.0));
+        assert_eq!(
+            shaft_deviation(Letter::G, ToleranceGrade::IT6, d, d),
+            Some(-7.0)
+        );
+        // p is IT7 + 0 to 5 µm
+        assert_eq!(
+            shaft_deviation_range(Letter::P, ToleranceGrade::IT6, d, d),
+            Some((21.0, 26.0))
+        );
+
+        let computed = compute(&"H7".parse().unwrap(), 25.0).unwrap();
+        assert_eq!(computed.tolerance, Tolerance::from_mm(0.021, 0.0));
+        assert!(!computed.extrapolated);
+
+        let computed = compute(&"h6".parse().unwrap(), 4_000.0).unwrap();
+        assert!(computed.extrapolated);
+        assert!(computed.tolerance.lower < Length::from_mm(-0.135));
+
+        assert!(compute(&"a9".parse().unwrap(), 1_000.0).is_none());
+    }
+
+    #[test]
+    fn test_cross_check() {
+        let disagree = cross_check()
+            .into_iter()
+            .filter(|c| c.agreement() == Some(Agreement::Disagrees))
+            .collect::<Vec<_>>();
+
+        // The 630 mm IT12 cell once had a typo that this caught
+        assert!(!disagree.iter().any(|c| c.size == 630 && c.column == "IT12"));
+
+        // Cells the standard itself sets away from its formulas
+        let it = "Standard tolerances";
+        let (a_g, k_zc) = ("Deviations a to g", "Deviations k to zc");
+        let known: &[(&str, i32, &[&str])] = &[
+            // Up to 3 mm the tolerances sit on preferred numbers, i is nearer 0.6 than 0.54
+            (
+                it,
+                3,
+                &[
+                    "IT3", "IT4", "IT7", "IT9", "IT10", "IT11", "IT12", "IT14", "IT15", "IT16",
+                    "IT17",
+                ],
+            ),
+            // IT01 to IT1 are fixed values and IT2 to IT4 only roughly geometric
+            (it, 6, &["IT3"]),
+            (it, 18, &["IT2", "IT3"]),
+            (it, 30, &["IT0", "IT1", "IT2"]),
+            (it, 120, &["IT0", "IT1"]),
+            (it, 180, &["IT01", "IT0"]),
+            (it, 315, &["IT2"]),
+            (it, 400, &["IT1", "IT2"]),
+            (it, 500, &["IT1", "IT2"]),
+            // 7I is 30.4 µm, published as 32
+            (it, 630, &["IT5"]),
+            // c from 10 to 40 mm is published 10 to 15 µm above 52D^0.2, cd follows it
+            (a_g, 18, &["c"]),
+            (a_g, 30, &["c"]),
+            (a_g, 40, &["c", "cd"]),
+            // 11D^0.41 is 179 µm, published as 170
+            (a_g, 1000, &["e"]),
+            // The smallest sizes of n, p and u to zc are not from the formulas
+            (k_zc, 3, &["n", "p", "u", "x", "z", "za", "zb", "zc"]),
+            (k_zc, 6, &["u", "x", "z", "za", "zb", "zc"]),
+            (k_zc, 10, &["u", "x", "z", "za"]),
+            (k_zc, 14, &["u", "x", "z"]),
+            (k_zc, 18, &["v", "x", "z"]),
+            // IT7 + 0.63D is 38 µm, published as 41
+            (k_zc, 30, &["t"]),
+            // IT10 + 5D is 1380 µm, published as 1350
+            (k_zc, 250, &["zc"]),
+            // Above 500 mm these are published several rounding steps off the formulas
+            (k_zc, 710, &["s"]),
+            (k_zc, 1600, &["r"]),
+            (k_zc, 1800, &["m", "p"]),
+            (k_zc, 2000, &["m", "p", "r"]),
+            (k_zc, 2240, &["m", "p", "r"]),
+            (k_zc, 2500, &["m", "p", "r"]),
+            (k_zc, 2800, &["m"]),
+            (k_zc, 3150, &["m"]),
+        ];
+        let mut expected = known
+            .iter()
+            .flat_map(|&(table, size, columns)| columns.iter().map(move |&c| (table, size, c)))
+            .collect::<Vec<_>>();
+        let mut found = disagree
+            .iter()
+            .map(|c| (c.table, c.size, c.column.as_str()))
+            .collect::<Vec<_>>();
+        expected.sort_unstable();
+        found.sort_unstable();
+        assert_eq!(found, expected);
+    }
+}
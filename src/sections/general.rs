@@ -0,0 +1,308 @@
+use std::fmt;
+
+use egui::{Color32, Grid, RichText, Ui};
+
+use super::{feature::Feature, length::Length, tolerance::Tolerance};
+
+// ISO 2768-1:1989 general tolerances for linear and angular dimensions
+// without individual tolerance indications, as called up by "ISO 2768-m"
+// in a title block. Linear deviations are ± in µm, angular deviations
+// are ± in minutes of arc, -1 marks ranges a class doesn't cover
+
+/// Tolerance class f (fine), m (medium), c (coarse) or v (very coarse).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, serde::Deserialize, serde::Serialize)]
+pub enum GeneralClass {
+    F,
+    M,
+    C,
+    V,
+}
+
+impl GeneralClass {
+    pub const ALL: [Self; 4] = [Self::F, Self::M, Self::C, Self::V];
+
+    pub fn index(self) -> usize {
+        self as usize
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::F => "fine",
+            Self::M => "medium",
+            Self::C => "coarse",
+            Self::V => "very coarse",
+        }
+    }
+}
+
+impl fmt::Display for GeneralClass {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let letter = match self {
+            Self::F => "f",
+            Self::M => "m",
+            Self::C => "c",
+            Self::V => "v",
+        };
+        write!(f, "{letter}")
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum GeneralError {
+    TooSmall(f64),
+    SizeOutOfRange(f64),
+    NotDefined(GeneralClass, f64),
+}
+
+impl fmt::Display for GeneralError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::TooSmall(size) => write!(
+                f,
+                "ISO 2768-1 starts at 0.5 mm, {size} mm needs its own tolerance"
+            ),
+            Self::SizeOutOfRange(size) => {
+                write!(f, "Size {size} mm is beyond the ISO 2768-1 tables")
+            }
+            Self::NotDefined(class, size) => {
+                write!(f, "ISO 2768-{class} is not defined at {size} mm")
+            }
+        }
+    }
+}
+
+impl std::error::Error for GeneralError {}
+
+/// One table of ISO 2768-1, deviations for each class by length range.
+pub struct GeneralTable {
+    pub name: &'static str,
+    /// Smallest length covered in mm.
+    pub from: f64,
+    /// Upper bound of each range in mm, None where the last range is open ended.
+    pub up_to: &'static [Option<i32>],
+    /// Deviations for f, m, c and v in each range.
+    pub values: [&'static [i32]; 4],
+}
+
+/// Table 1, linear dimensions except broken edges.
+pub const LINEAR: GeneralTable = GeneralTable {
+    name: "Linear dimensions",
+    from: 0.5,
+    up_to: &[
+        Some(3),
+        Some(6),
+        Some(30),
+        Some(120),
+        Some(400),
+        Some(1_000),
+        Some(2_000),
+        Some(4_000),
+    ],
+    values: [
+        &[50, 50, 100, 150, 200, 300, 500, -1],
+        &[100, 100, 200, 300, 500, 800, 1_200, 2_000],
+        &[200, 300, 500, 800, 1_200, 2_000, 3_000, 4_000],
+        &[-1, 500, 1_000, 1_500, 2_500, 4_000, 6_000, 8_000],
+    ],
+};
+
+/// Table 2, external radii and chamfer heights.
+pub const RADIUS: GeneralTable = GeneralTable {
+    name: "Radii and chamfers",
+    from: 0.5,
+    up_to: &[Some(3), Some(6), None],
+    values: [
+        &[200, 500, 1_000],
+        &[200, 500, 1_000],
+        &[400, 1_000, 2_000],
+        &[400, 1_000, 2_000],
+    ],
+};
+
+/// Table 3, angular dimensions by the length of the shorter side.
+pub const ANGULAR: GeneralTable = GeneralTable {
+    name: "Angular dimensions",
+    from: 0.0,
+    up_to: &[Some(10), Some(50), Some(120), Some(400), None],
+    values: [
+        &[60, 30, 20, 10, 5],
+        &[60, 30, 20, 10, 5],
+        &[90, 60, 30, 15, 10],
+        &[180, 120, 60, 30, 20],
+    ],
+};
+
+impl GeneralTable {
+    /// Index of the range that `size` in mm falls into.
+    pub fn range(&self, size: f64) -> Result<usize, GeneralError> {
+        if size < self.from {
+            return Err(GeneralError::TooSmall(size));
+        }
+
+        self.up_to
+            .iter()
+            .position(|up_to| up_to.map_or(true, |up_to| size <= up_to as f64))
+            .ok_or(GeneralError::SizeOutOfRange(size))
+    }
+
+    pub fn value(&self, class: GeneralClass, size: f64) -> Result<i32, GeneralError> {
+        let value = self.values[class.index()][self.range(size)?];
+        if value == -1 {
+            return Err(GeneralError::NotDefined(class, size));
+        }
+        Ok(value)
+    }
+
+    /// Range `idx` as printed, e.g. "≤ 10", "6 – 30" or "> 400".
+    pub fn label(&self, idx: usize) -> String {
+        let over = match idx {
+            0 => self.from,
+            _ => self.up_to[idx - 1].unwrap_or_default() as f64,
+        };
+
+        match self.up_to[idx] {
+            Some(up_to) if over == 0.0 => format!("≤ {up_to}"),
+            Some(up_to) => format!("{over} – {up_to}"),
+            None => format!("> {over}"),
+        }
+    }
+}
+
+/// ± deviation of a linear size.
+pub fn linear(class: GeneralClass, size: f64) -> Result<Length, GeneralError> {
+    LINEAR.value(class, size).map(|v| Length::from_um(v as f64))
+}
+
+/// ± deviation of an external radius or chamfer height.
+pub fn radius(class: GeneralClass, size: f64) -> Result<Length, GeneralError> {
+    RADIUS.value(class, size).map(|v| Length::from_um(v as f64))
+}
+
+/// ± deviation in minutes of arc of an angle with a shorter side of `length` mm.
+pub fn angular(class: GeneralClass, length: f64) -> Result<i32, GeneralError> {
+    ANGULAR.value(class, length)
+}
+
+/// Symmetric limits of a linear size.
+pub fn tolerance(class: GeneralClass, size: f64) -> Result<Tolerance, GeneralError> {
+    let deviation = linear(class, size)?;
+    Ok(Tolerance::new(deviation, -deviation))
+}
+
+/// Minutes of arc as degrees and minutes, e.g. "1°30'".
+pub fn angle(minutes: i32) -> String {
+    match (minutes / 60, minutes % 60) {
+        (degrees, 0) => format!("{degrees}°"),
+        (degrees, minutes) => format!("{degrees}°{minutes}'"),
+    }
+}
+
+fn cell(table: &GeneralTable, value: i32) -> String {
+    if table.name == ANGULAR.name {
+        format!("±{}", angle(value))
+    } else {
+        format!("±{}", value as f64 / 1_000.0)
+    }
+}
+
+/// Every ISO 2768-1 table, with the class and range of `features` on general tolerances highlighted.
+pub fn show_general(ui: &mut Ui, features: &[&Feature]) {
+    let current = features
+        .iter()
+        .filter(|feature| !feature.standard)
+        .filter_map(|feature| Some((feature.general?, feature.size.mm())))
+        .collect::<Vec<_>>();
+
+    for table in [&LINEAR, &RADIUS, &ANGULAR] {
+        ui.label(RichText::new(table.name).strong());
+
+        // Only linear sizes come from the features, the other tables are for reference
+        let current = if table.name == LINEAR.name {
+            current.as_slice()
+        } else {
+            &[]
+        };
+
+        Grid::new(table.name)
+            .striped(true)
+            .min_col_width(10.0)
+            .show(ui, |ui| {
+                ui.label(RichText::new("mm").strong());
+                for class in GeneralClass::ALL {
+                    ui.label(RichText::new(class.to_string()).strong())
+                        .on_hover_text(class.name());
+                }
+                ui.end_row();
+
+                for idx in 0..table.up_to.len() {
+                    let inside = |size: f64| table.range(size) == Ok(idx);
+                    let label = RichText::new(table.label(idx));
+                    ui.label(if current.iter().any(|(_, size)| inside(*size)) {
+                        label.strong().color(Color32::from_rgb(0, 160, 0))
+                    } else {
+                        label
+                    });
+
+                    for class in GeneralClass::ALL {
+                        let value = table.values[class.index()][idx];
+                        if value == -1 {
+                            ui.colored_label(ui.visuals().weak_text_color(), "–");
+                            continue;
+                        }
+
+                        let text = RichText::new(cell(table, value));
+                        let highlight =
+                            current.iter().any(|(c, size)| *c == class && inside(*size));
+                        ui.label(if highlight {
+                            text.strong()
+                                .background_color(ui.visuals().selection.bg_fill)
+                        } else {
+                            text
+                        });
+                    }
+                    ui.end_row();
+                }
+            });
+
+        ui.add_space(5.0);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_general() {
+        use GeneralClass::*;
+
+        assert_eq!(linear(M, 25.0), Ok(Length::from_mm(0.2)));
+        assert_eq!(linear(F, 0.5), Ok(Length::from_mm(0.05)));
+        assert_eq!(linear(C, 3.0), Ok(Length::from_mm(0.2)));
+        assert_eq!(linear(C, 3.5), Ok(Length::from_mm(0.3)));
+        assert_eq!(linear(V, 4_000.0), Ok(Length::from_mm(8.0)));
+        assert_eq!(tolerance(M, 150.0), Ok(Tolerance::from_mm(0.5, -0.5)));
+
+        assert_eq!(linear(V, 2.0), Err(GeneralError::NotDefined(V, 2.0)));
+        assert_eq!(
+            linear(F, 2_500.0),
+            Err(GeneralError::NotDefined(F, 2_500.0))
+        );
+        assert_eq!(linear(M, 0.4), Err(GeneralError::TooSmall(0.4)));
+        assert_eq!(
+            linear(M, 4_001.0),
+            Err(GeneralError::SizeOutOfRange(4_001.0))
+        );
+
+        // The last radius and angle ranges are open ended
+        assert_eq!(radius(C, 50.0), Ok(Length::from_mm(2.0)));
+        assert_eq!(angular(M, 1_000.0), Ok(5));
+        assert_eq!(angle(angular(C, 8.0).unwrap()), "1°30'");
+        assert_eq!(angle(angular(V, 30.0).unwrap()), "2°");
+        assert_eq!(angle(angular(F, 100.0).unwrap()), "0°20'");
+
+        assert_eq!(LINEAR.label(0), "0.5 – 3");
+        assert_eq!(RADIUS.label(2), "> 6");
+        assert_eq!(ANGULAR.label(0), "≤ 10");
+    }
+}
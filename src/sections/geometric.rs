@@ -0,0 +1,221 @@
+use std::fmt;
+
+use egui::{ComboBox, Grid, RichText, Ui};
+
+use super::{length::Length, units::Units};
+
+// ISO 2768-2:1989 general geometrical tolerances, classes H, K and L
+// Nominal length in millimetres, the upper bound of each range
+// Tolerances in micrometres, columns are H, K and L
+
+/// Table 1, by the length of the line or the longer side of the surface.
+pub const STRAIGHTNESS_FLATNESS: &[[i32; 4]; 6] = &[
+    [10, 20, 50, 100],
+    [30, 50, 100, 200],
+    [100, 100, 200, 400],
+    [300, 200, 400, 800],
+    [1_000, 300, 600, 1_200],
+    [3_000, 400, 800, 1_600],
+];
+
+/// Table 2, by the length of the shorter side.
+pub const PERPENDICULARITY: &[[i32; 4]; 4] = &[
+    [100, 200, 400, 600],
+    [300, 300, 600, 1_000],
+    [1_000, 400, 800, 1_500],
+    [3_000, 500, 1_000, 2_000],
+];
+
+/// Table 3, by the length of the toleranced feature.
+pub const SYMMETRY: &[[i32; 4]; 4] = &[
+    [100, 500, 600, 600],
+    [300, 500, 600, 1_000],
+    [1_000, 500, 800, 1_500],
+    [3_000, 500, 1_000, 2_000],
+];
+
+/// Table 4, the same for every length.
+pub const CIRCULAR_RUN_OUT: &[i32; 3] = &[100, 200, 500];
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, serde::Deserialize, serde::Serialize)]
+pub enum GeometricClass {
+    H,
+    K,
+    L,
+}
+
+impl GeometricClass {
+    pub const ALL: [Self; 3] = [Self::H, Self::K, Self::L];
+
+    pub fn index(self) -> usize {
+        self as usize
+    }
+}
+
+impl fmt::Display for GeometricClass {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{self:?}")
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Characteristic {
+    StraightnessFlatness,
+    Perpendicularity,
+    Symmetry,
+    CircularRunOut,
+}
+
+impl Characteristic {
+    pub const ALL: [Self; 4] = [
+        Self::StraightnessFlatness,
+        Self::Perpendicularity,
+        Self::Symmetry,
+        Self::CircularRunOut,
+    ];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::StraightnessFlatness => "Straightness, flatness",
+            Self::Perpendicularity => "Perpendicularity",
+            Self::Symmetry => "Symmetry",
+            Self::CircularRunOut => "Circular run-out",
+        }
+    }
+
+    pub fn symbol(self) -> &'static str {
+        match self {
+            Self::StraightnessFlatness => "⏤ ⏥",
+            Self::Perpendicularity => "⊥",
+            Self::Symmetry => "⌯",
+            Self::CircularRunOut => "↗",
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum GeometricError {
+    SizeOutOfRange(f64),
+}
+
+impl fmt::Display for GeometricError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::SizeOutOfRange(length) => {
+                write!(f, "Length {length} mm is beyond the ISO 2768-2 tables")
+            }
+        }
+    }
+}
+
+impl std::error::Error for GeometricError {}
+
+fn lookup<const N: usize>(
+    table: &[[i32; 4]; N],
+    class: GeometricClass,
+    length: f64,
+) -> Result<i32, GeometricError> {
+    table
+        .iter()
+        .find(|row| length <= row[0] as f64)
+        .map(|row| row[class.index() + 1])
+        .ok_or(GeometricError::SizeOutOfRange(length))
+}
+
+/// General tolerance of `characteristic` for a nominal `length` in mm.
+pub fn tolerance(
+    characteristic: Characteristic,
+    class: GeometricClass,
+    length: f64,
+) -> Result<Length, GeometricError> {
+    let value = match characteristic {
+        Characteristic::StraightnessFlatness => lookup(STRAIGHTNESS_FLATNESS, class, length)?,
+        Characteristic::Perpendicularity => lookup(PERPENDICULARITY, class, length)?,
+        Characteristic::Symmetry => lookup(SYMMETRY, class, length)?,
+        Characteristic::CircularRunOut => CIRCULAR_RUN_OUT[class.index()],
+    };
+    Ok(Length::from_um(value as f64))
+}
+
+/// Circular run-out, which doesn't depend on length.
+pub fn run_out(class: GeometricClass) -> Length {
+    Length::from_um(CIRCULAR_RUN_OUT[class.index()] as f64)
+}
+
+/// Panel with the class picker and every tolerance at `length` in mm.
+pub fn show(ui: &mut Ui, id: &str, class: &mut Option<GeometricClass>, length: f64, units: &Units) {
+    ui.horizontal(|ui| {
+        ui.label(RichText::new("ISO 2768-2").strong());
+
+        ComboBox::from_id_salt(format!("{id}_geometric"))
+            .width(45.0)
+            .selected_text(class.map_or("–".to_owned(), |c| c.to_string()))
+            .show_ui(ui, |ui| {
+                ui.selectable_value(class, None, "–");
+                for option in GeometricClass::ALL {
+                    ui.selectable_value(class, Some(option), option.to_string());
+                }
+            })
+            .response
+            .on_hover_text("General geometrical tolerance class, the run-out is taken off the fit");
+    });
+    ui.add_space(5.0);
+
+    let Some(class) = *class else {
+        return;
+    };
+
+    Grid::new(format!("{id}_geometric_grid"))
+        .striped(true)
+        .min_col_width(10.0)
+        .show(ui, |ui| {
+            for characteristic in Characteristic::ALL {
+                ui.label(characteristic.symbol())
+                    .on_hover_cursor(egui::CursorIcon::Default)
+                    .on_hover_text(characteristic.name());
+                match tolerance(characteristic, class, length) {
+                    Ok(value) => ui.label(units.deviation(value.mm(), value.mm(), false)),
+                    Err(error) => ui.colored_label(egui::Color32::RED, error.to_string()),
+                };
+                ui.end_row();
+            }
+        });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sections::{feature::Feature, fit::Fit};
+
+    #[test]
+    fn test_geometric() {
+        use Characteristic::*;
+        use GeometricClass::*;
+
+        let mm = |characteristic, class, length| {
+            tolerance(characteristic, class, length).map(|t| t.mm())
+        };
+
+        assert_eq!(mm(StraightnessFlatness, H, 10.0), Ok(0.02));
+        assert_eq!(mm(StraightnessFlatness, K, 10.5), Ok(0.1));
+        assert_eq!(mm(StraightnessFlatness, L, 3_000.0), Ok(1.6));
+        assert_eq!(mm(Perpendicularity, K, 250.0), Ok(0.6));
+        assert_eq!(mm(Symmetry, H, 2_000.0), Ok(0.5));
+        assert_eq!(mm(Symmetry, L, 50.0), Ok(0.6));
+        assert_eq!(mm(CircularRunOut, K, 5_000.0), Ok(0.2));
+        assert_eq!(
+            mm(Perpendicularity, H, 3_500.0),
+            Err(GeometricError::SizeOutOfRange(3_500.0))
+        );
+
+        // H7/h6 at 10 mm is a clearance fit until the shaft run-out is counted
+        let hole = Feature::default_hole();
+        let mut shaft = Feature::default_shaft();
+        assert_eq!(Fit::new(&hole, &shaft).run_out_mmc, None);
+
+        shaft.geometric = Some(K);
+        let fit = Fit::new(&hole, &shaft);
+        assert_eq!(fit.mmc, Length::ZERO);
+        assert_eq!(fit.run_out_mmc, Some(Length::from_mm(-0.2)));
+    }
+}
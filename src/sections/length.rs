@@ -0,0 +1,188 @@
+use std::{
+    fmt,
+    iter::Sum,
+    ops::{Add, AddAssign, Div, Mul, Neg, Sub, SubAssign},
+};
+
+use egui::DragValue;
+
+// Sizes, limits and clearances are whole nanometres so that sums and
+// differences match the tables exactly, floats are only for thermal and
+// stress maths and the final display
+
+/// An exact length in nanometres, persisted as a float in mm.
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    serde::Deserialize,
+    serde::Serialize,
+)]
+#[serde(from = "f64", into = "f64")]
+pub struct Length(i64);
+
+impl Length {
+    pub const ZERO: Length = Length(0);
+
+    pub const fn from_nm(nm: i64) -> Self {
+        Length(nm)
+    }
+
+    pub fn from_um(um: f64) -> Self {
+        Length((um * 1_000.0).round() as i64)
+    }
+
+    pub fn from_mm(mm: f64) -> Self {
+        Length((mm * 1_000_000.0).round() as i64)
+    }
+
+    pub const fn nm(self) -> i64 {
+        self.0
+    }
+
+    pub fn um(self) -> f64 {
+        self.0 as f64 / 1_000.0
+    }
+
+    pub fn mm(self) -> f64 {
+        self.0 as f64 / 1_000_000.0
+    }
+
+    pub const fn abs(self) -> Self {
+        Length(self.0.abs())
+    }
+
+    pub const fn is_negative(self) -> bool {
+        self.0 < 0
+    }
+
+    /// Drag value that edits the length in mm.
+    pub fn drag(&mut self) -> DragValue<'_> {
+        DragValue::from_get_set(move |value| {
+            if let Some(mm) = value {
+                *self = Length::from_mm(mm);
+            }
+            self.mm()
+        })
+    }
+}
+
+impl From<f64> for Length {
+    fn from(mm: f64) -> Self {
+        Length::from_mm(mm)
+    }
+}
+
+impl From<Length> for f64 {
+    fn from(length: Length) -> Self {
+        length.mm()
+    }
+}
+
+/// Exact decimal mm without trailing zeros.
+impl fmt::Display for Length {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let sign = if self.is_negative() { "-" } else { "" };
+        let nm = self.0.unsigned_abs();
+        let (whole, fraction) = (nm / 1_000_000, nm % 1_000_000);
+
+        if fraction == 0 {
+            write!(f, "{sign}{whole}")
+        } else {
+            let fraction = format!("{fraction:06}");
+            write!(f, "{sign}{whole}.{}", fraction.trim_end_matches('0'))
+        }
+    }
+}
+
+impl Add for Length {
+    type Output = Length;
+
+    fn add(self, other: Length) -> Length {
+        Length(self.0 + other.0)
+    }
+}
+
+impl Sub for Length {
+    type Output = Length;
+
+    fn sub(self, other: Length) -> Length {
+        Length(self.0 - other.0)
+    }
+}
+
+impl Neg for Length {
+    type Output = Length;
+
+    fn neg(self) -> Length {
+        Length(-self.0)
+    }
+}
+
+impl AddAssign for Length {
+    fn add_assign(&mut self, other: Length) {
+        self.0 += other.0;
+    }
+}
+
+impl SubAssign for Length {
+    fn sub_assign(&mut self, other: Length) {
+        self.0 -= other.0;
+    }
+}
+
+impl Mul<i64> for Length {
+    type Output = Length;
+
+    fn mul(self, n: i64) -> Length {
+        Length(self.0 * n)
+    }
+}
+
+/// Rounds to the nearest nanometre, halves away from zero.
+impl Div<i64> for Length {
+    type Output = Length;
+
+    fn div(self, n: i64) -> Length {
+        let (quotient, remainder) = (self.0 / n, self.0 % n);
+        if 2 * remainder.abs() >= n.abs() {
+            Length(quotient + self.0.signum() * n.signum())
+        } else {
+            Length(quotient)
+        }
+    }
+}
+
+impl Sum for Length {
+    fn sum<I: Iterator<Item = Length>>(iter: I) -> Length {
+        iter.fold(Length::ZERO, Add::add)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_length() {
+        let (upper, lower) = (Length::from_mm(0.009), Length::from_mm(-0.0035));
+        assert_eq!(upper.nm(), 9_000);
+        assert_eq!((upper - lower).to_string(), "0.0125");
+        assert_eq!((Length::from_mm(10.0) + lower).to_string(), "9.9965");
+        assert_eq!(lower.to_string(), "-0.0035");
+        assert_eq!(Length::from_mm(25.0).to_string(), "25");
+
+        // Mid-limits round half away from zero like a hand calculation
+        assert_eq!((Length::from_nm(25) / 2).nm(), 13);
+        assert_eq!((Length::from_nm(-25) / 2).nm(), -13);
+
+        let total: Length = [upper, lower, upper].into_iter().sum();
+        assert_eq!(total, Length::from_um(14.5));
+    }
+}
@@ -10,12 +10,7 @@
 // K_ZC is negative for holes, positive for shafts
 
 // J/j have fully separate tables
-// K needs deltas up to 500 and missing values
-// M needs deltas up to 500
-// N needs deltas up to 500
-// J/j, K/k, M/m and N/n need IT modifiers
-// P & ZC need deltas depend on IT modifiers
-// JS/js needs auto tolerance splitting
+// Deltas, IT modifiers, JS/js splitting and the M6 case are applied in rules.rs
 
 pub const GRADE_MAP: &[&str; 20] = &[
     "01", "0", "1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "14", "15",
@@ -81,7 +76,7 @@ pub const STANDARD_TOLERANCE_GRADES: &[[i32; 21]; 21] = &[
         15_500, 25_000, 40_000, 63_000, 97_000,
     ],
     [
-        630, -1, -1, 90, 110, 160, 220, 320, 440, 700, 1_100, 1_750, 2_800, 4_400, 7_0000, 11_000,
+        630, -1, -1, 90, 110, 160, 220, 320, 440, 700, 1_100, 1_750, 2_800, 4_400, 7_000, 11_000,
         17_500, 28_000, 44_000, 70_000, 110_000,
     ],
     [
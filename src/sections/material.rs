@@ -1,18 +1,734 @@
-#[derive(Clone, serde::Deserialize, serde::Serialize)]
+use std::borrow::Cow;
+
+use egui::{Button, ComboBox, DragValue, Grid, RichText, TextEdit, Ui};
+
+use super::units::Units;
+
+#[derive(Clone, Debug, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(from = "Saved")]
 pub struct Material {
+    pub name: Cow<'static, str>,
     pub cte: f64,
     pub temp: f64,
     pub youngs: f64,
+    pub poisson: f64,
+    pub yield_strength: f64,
     pub uts: f64,
+    /// kg/m³
+    pub density: f64,
+    /// Hottest the part may be heated to in service or for assembly.
+    pub max_temp: f64,
+    /// Properties against temperature as factors on the 20 ºC values, empty
+    /// for constant properties.
+    pub curve: Cow<'static, [Point]>,
+}
+
+/// A material as saved. Saves from before the curves were added take the
+/// curve of the library material with the same name, if any.
+#[derive(serde::Deserialize)]
+#[serde(default)]
+struct Saved {
+    name: Cow<'static, str>,
+    cte: f64,
+    temp: f64,
+    youngs: f64,
+    poisson: f64,
+    yield_strength: f64,
+    uts: f64,
+    density: f64,
+    max_temp: f64,
+    // Saved as a plain list, RON would otherwise expect `Some(..)`
+    #[serde(deserialize_with = "present")]
+    curve: Option<Cow<'static, [Point]>>,
+}
+
+fn present<'de, D>(deserializer: D) -> Result<Option<Cow<'static, [Point]>>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    serde::Deserialize::deserialize(deserializer).map(Some)
+}
+
+impl Default for Saved {
+    fn default() -> Self {
+        let mat = Material::default();
+
+        Self {
+            name: mat.name,
+            cte: mat.cte,
+            temp: mat.temp,
+            youngs: mat.youngs,
+            poisson: mat.poisson,
+            yield_strength: mat.yield_strength,
+            uts: mat.uts,
+            density: mat.density,
+            max_temp: mat.max_temp,
+            curve: None,
+        }
+    }
+}
+
+impl From<Saved> for Material {
+    fn from(saved: Saved) -> Self {
+        let curve = saved.curve.unwrap_or_else(|| {
+            LIBRARY
+                .iter()
+                .find(|mat| mat.name == saved.name)
+                .map_or(Cow::Borrowed(&[]), |mat| mat.curve.clone())
+        });
+
+        Self {
+            name: saved.name,
+            cte: saved.cte,
+            temp: saved.temp,
+            youngs: saved.youngs,
+            poisson: saved.poisson,
+            yield_strength: saved.yield_strength,
+            uts: saved.uts,
+            density: saved.density,
+            max_temp: saved.max_temp,
+            curve,
+        }
+    }
+}
+
+/// Factors on the 20 ºC CTE, Young's modulus and yield strength at a temperature.
+#[derive(Clone, Copy, Debug, PartialEq, serde::Deserialize, serde::Serialize)]
+pub struct Point {
+    pub temp: f64,
+    pub cte: f64,
+    pub youngs: f64,
+    pub yield_strength: f64,
 }
 
+const fn point(temp: f64, cte: f64, youngs: f64, yield_strength: f64) -> Point {
+    Point {
+        temp,
+        cte,
+        youngs,
+        yield_strength,
+    }
+}
+
+// Instantaneous CTE, so the expansion is the area under the curve. Shapes are
+// typical of each family from handbook and cryogenic data, enough to catch a
+// constant coefficient being out by a third at LN2 but not a grade's own data
+const STEEL: &[Point] = &[
+    point(-196.0, 0.47, 1.06, 1.6),
+    point(-100.0, 0.8, 1.03, 1.2),
+    point(20.0, 1.0, 1.0, 1.0),
+    point(100.0, 1.06, 0.97, 0.93),
+    point(200.0, 1.1, 0.93, 0.85),
+    point(300.0, 1.15, 0.89, 0.75),
+    point(400.0, 1.2, 0.84, 0.65),
+    point(600.0, 1.27, 0.7, 0.35),
+];
+const STAINLESS: &[Point] = &[
+    point(-196.0, 0.6, 1.07, 1.6),
+    point(-100.0, 0.85, 1.04, 1.25),
+    point(20.0, 1.0, 1.0, 1.0),
+    point(200.0, 1.03, 0.94, 0.8),
+    point(300.0, 1.06, 0.91, 0.75),
+    point(500.0, 1.13, 0.84, 0.65),
+    point(800.0, 1.2, 0.72, 0.45),
+];
+const ALUMINIUM: &[Point] = &[
+    point(-196.0, 0.5, 1.1, 1.2),
+    point(-100.0, 0.83, 1.05, 1.08),
+    point(20.0, 1.0, 1.0, 1.0),
+    point(100.0, 1.04, 0.97, 0.95),
+    point(150.0, 1.07, 0.94, 0.85),
+    point(200.0, 1.1, 0.9, 0.55),
+    point(300.0, 1.15, 0.8, 0.12),
+];
+const COPPER: &[Point] = &[
+    point(-196.0, 0.6, 1.06, 1.2),
+    point(-100.0, 0.85, 1.03, 1.1),
+    point(20.0, 1.0, 1.0, 1.0),
+    point(200.0, 1.05, 0.94, 0.9),
+    point(300.0, 1.08, 0.9, 0.75),
+];
+const TITANIUM: &[Point] = &[
+    point(-196.0, 0.6, 1.08, 1.6),
+    point(-100.0, 0.9, 1.04, 1.3),
+    point(20.0, 1.0, 1.0, 1.0),
+    point(200.0, 1.06, 0.92, 0.75),
+    point(400.0, 1.12, 0.83, 0.62),
+];
+// Plastics soften sharply above their glass transition
+const PEEK: &[Point] = &[
+    point(-50.0, 0.9, 1.1, 1.2),
+    point(20.0, 1.0, 1.0, 1.0),
+    point(140.0, 1.1, 0.9, 0.7),
+    point(200.0, 2.3, 0.1, 0.2),
+    point(250.0, 2.4, 0.08, 0.15),
+];
+const POM: &[Point] = &[
+    point(-40.0, 0.9, 1.4, 1.4),
+    point(20.0, 1.0, 1.0, 1.0),
+    point(100.0, 1.1, 0.35, 0.45),
+];
+const PA6: &[Point] = &[
+    point(-40.0, 0.9, 1.3, 1.4),
+    point(20.0, 1.0, 1.0, 1.0),
+    point(60.0, 1.05, 0.6, 0.7),
+    point(90.0, 1.1, 0.3, 0.4),
+];
+
+impl Default for Material {
+    fn default() -> Self {
+        LIBRARY[0].clone()
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+const fn material(
+    name: &'static str,
+    cte: f64,
+    youngs: f64,
+    poisson: f64,
+    yield_strength: f64,
+    uts: f64,
+    density: f64,
+    max_temp: f64,
+    curve: &'static [Point],
+) -> Material {
+    Material {
+        name: Cow::Borrowed(name),
+        cte,
+        temp: 20.0,
+        youngs,
+        poisson,
+        yield_strength,
+        uts,
+        density,
+        max_temp,
+        curve: Cow::Borrowed(curve),
+    }
+}
+
+/// Typical room temperature data sheet values, the grade and condition of
+/// the actual stock matter, particularly for strengths.
+/// CTE µm/m·K, E MPa, ν, yield MPa, UTS MPa, density kg/m³, max ºC, curve
+pub const LIBRARY: [Material; 17] = [
+    material(
+        "Steel", 12.0, 200_000.0, 0.3, 350.0, 500.0, 7_850.0, 250.0, STEEL,
+    ),
+    material(
+        "S235 structural steel",
+        12.0,
+        210_000.0,
+        0.3,
+        235.0,
+        360.0,
+        7_850.0,
+        400.0,
+        STEEL,
+    ),
+    material(
+        "C45 carbon steel",
+        11.5,
+        210_000.0,
+        0.29,
+        340.0,
+        620.0,
+        7_850.0,
+        400.0,
+        STEEL,
+    ),
+    material(
+        "42CrMo4 alloy steel, QT",
+        12.0,
+        210_000.0,
+        0.29,
+        650.0,
+        900.0,
+        7_850.0,
+        450.0,
+        STEEL,
+    ),
+    material(
+        "100Cr6 bearing steel",
+        12.5,
+        210_000.0,
+        0.3,
+        1_700.0,
+        2_000.0,
+        7_810.0,
+        120.0,
+        STEEL,
+    ),
+    material(
+        "304 stainless steel",
+        17.3,
+        193_000.0,
+        0.29,
+        215.0,
+        505.0,
+        8_000.0,
+        800.0,
+        STAINLESS,
+    ),
+    material(
+        "316 stainless steel",
+        16.0,
+        193_000.0,
+        0.3,
+        205.0,
+        515.0,
+        8_000.0,
+        800.0,
+        STAINLESS,
+    ),
+    material(
+        "EN-GJL-250 grey iron",
+        10.5,
+        110_000.0,
+        0.26,
+        250.0,
+        250.0,
+        7_200.0,
+        350.0,
+        STEEL,
+    ),
+    material(
+        "EN-GJS-400 ductile iron",
+        12.5,
+        169_000.0,
+        0.275,
+        250.0,
+        400.0,
+        7_100.0,
+        350.0,
+        STEEL,
+    ),
+    material(
+        "6061-T6 aluminium",
+        23.6,
+        68_900.0,
+        0.33,
+        276.0,
+        310.0,
+        2_700.0,
+        150.0,
+        ALUMINIUM,
+    ),
+    material(
+        "7075-T6 aluminium",
+        23.4,
+        71_700.0,
+        0.33,
+        503.0,
+        572.0,
+        2_810.0,
+        120.0,
+        ALUMINIUM,
+    ),
+    material(
+        "CW614N brass",
+        20.9,
+        97_000.0,
+        0.34,
+        250.0,
+        430.0,
+        8_470.0,
+        200.0,
+        COPPER,
+    ),
+    material(
+        "CuSn12 bronze",
+        18.5,
+        100_000.0,
+        0.34,
+        150.0,
+        280.0,
+        8_700.0,
+        250.0,
+        COPPER,
+    ),
+    material(
+        "Ti-6Al-4V titanium",
+        8.6,
+        114_000.0,
+        0.34,
+        880.0,
+        950.0,
+        4_430.0,
+        400.0,
+        TITANIUM,
+    ),
+    material(
+        "PEEK", 47.0, 3_600.0, 0.4, 97.0, 100.0, 1_300.0, 250.0, PEEK,
+    ),
+    material(
+        "POM acetal",
+        110.0,
+        2_800.0,
+        0.35,
+        65.0,
+        65.0,
+        1_410.0,
+        100.0,
+        POM,
+    ),
+    material(
+        "PA6 nylon, dry",
+        85.0,
+        2_700.0,
+        0.39,
+        80.0,
+        80.0,
+        1_140.0,
+        90.0,
+        PA6,
+    ),
+];
+
 impl Material {
-    pub fn default() -> Self {
+    /// Factors at `temp`, interpolated and held at the ends of the data.
+    pub fn factors(&self, temp: f64) -> Point {
+        let curve = self.curve.as_ref();
+        let (Some(first), Some(last)) = (curve.first(), curve.last()) else {
+            return point(temp, 1.0, 1.0, 1.0);
+        };
+        if temp <= first.temp {
+            return Point { temp, ..*first };
+        }
+        if temp >= last.temp {
+            return Point { temp, ..*last };
+        }
+
+        let above = curve
+            .iter()
+            .position(|p| p.temp > temp)
+            .unwrap_or(curve.len() - 1);
+        let (a, b) = (curve[above - 1], curve[above]);
+        let t = (temp - a.temp) / (b.temp - a.temp);
+        let lerp = |a: f64, b: f64| a + (b - a) * t;
+
+        point(
+            temp,
+            lerp(a.cte, b.cte),
+            lerp(a.youngs, b.youngs),
+            lerp(a.yield_strength, b.yield_strength),
+        )
+    }
+
+    /// Temperatures the curve covers, none for constant properties.
+    pub fn range(&self) -> Option<(f64, f64)> {
+        Some((self.curve.first()?.temp, self.curve.last()?.temp))
+    }
+
+    /// Whether `temp` is within the data, always for constant properties.
+    pub fn in_range(&self, temp: f64) -> bool {
+        self.range()
+            .is_none_or(|(low, high)| (low..=high).contains(&temp))
+    }
+
+    /// Properties at `temp`, with the instantaneous CTE.
+    pub fn at(&self, temp: f64) -> Material {
+        let factors = self.factors(temp);
+
         Material {
-            cte: 12.0,
-            temp: 20.0,
-            youngs: 200_000.0, // MPa
-            uts: 500.0,        // MPa
+            cte: self.cte * factors.cte,
+            youngs: self.youngs * factors.youngs,
+            yield_strength: self.yield_strength * factors.yield_strength,
+            temp,
+            curve: Cow::Borrowed(&[]),
+            ..self.clone()
         }
     }
+
+    /// Growth of a size from 20 ºC to `temp`, the CTE integrated over the
+    /// curve. It is linear between points so the trapezium rule is exact.
+    pub fn scale(&self, temp: f64) -> f64 {
+        let (low, high) = (temp.min(20.0), temp.max(20.0));
+
+        let mut knots = vec![low];
+        knots.extend(
+            self.curve
+                .iter()
+                .map(|p| p.temp)
+                .filter(|&t| low < t && t < high),
+        );
+        knots.push(high);
+
+        let alpha = |t: f64| self.cte * self.factors(t).cte;
+        let area: f64 = knots
+            .windows(2)
+            .map(|pair| (pair[1] - pair[0]) * (alpha(pair[0]) + alpha(pair[1])) / 2.0)
+            .sum();
+
+        if temp < 20.0 {
+            1.0 - area * 0.000_001
+        } else {
+            1.0 + area * 0.000_001
+        }
+    }
+
+    /// Takes the name and properties of `other`, keeping the temperature.
+    pub fn set(&mut self, other: &Material) {
+        *self = Material {
+            temp: self.temp,
+            ..other.clone()
+        };
+    }
+
+    /// Same name and properties, whatever the temperature.
+    pub fn same(&self, other: &Material) -> bool {
+        *self
+            == Material {
+                temp: self.temp,
+                ..other.clone()
+            }
+    }
+
+    fn range_text(&self, units: &Units) -> String {
+        self.range().map_or("Constant".to_owned(), |(low, high)| {
+            format!("{} to {}", units.temp(low), units.temp(high))
+        })
+    }
+
+    /// Warning for a temperature beyond the data, where the end values are held.
+    pub fn range_warning(&self, temp: f64, units: &Units) -> Option<String> {
+        (!self.in_range(temp)).then(|| {
+            format!(
+                "⚠ {} is outside the {} data, {}",
+                units.temp(temp),
+                self.name,
+                self.range_text(units)
+            )
+        })
+    }
+
+    fn summary(&self, units: &Units) -> String {
+        format!(
+            "{}\nE {}, ν {:.2}\nYield {}, UTS {}\n{}\nMax {}\nData {}",
+            units.cte(self.cte),
+            units.stress(self.youngs),
+            self.poisson,
+            units.stress(self.yield_strength),
+            units.stress(self.uts),
+            units.density(self.density),
+            units.temp(self.max_temp),
+            self.range_text(units),
+        )
+    }
+}
+
+/// Material picker from the library and the user's own materials, marked
+/// edited once a property no longer matches the named material.
+pub fn select_ui(ui: &mut Ui, id: &str, mat: &mut Material, custom: &[Material], units: &Units) {
+    let known = LIBRARY.iter().chain(custom).any(|m| mat.same(m));
+    let selected = if known {
+        mat.name.to_string()
+    } else {
+        format!("{} (edited)", mat.name)
+    };
+
+    ComboBox::from_id_salt(format!("{id}_material"))
+        .width(150.0)
+        .selected_text(selected)
+        .show_ui(ui, |ui| {
+            for (i, option) in LIBRARY.iter().chain(custom).enumerate() {
+                if i == LIBRARY.len() {
+                    ui.separator();
+                }
+                if ui
+                    .selectable_label(mat.same(option), option.name.as_ref())
+                    .on_hover_text(option.summary(units))
+                    .clicked()
+                {
+                    mat.set(option);
+                }
+            }
+        })
+        .response
+        .on_hover_text(mat.summary(units));
+}
+
+fn properties_ui(ui: &mut Ui, mat: &mut Material, units: &Units) {
+    let stress_drag = |ui: &mut Ui, value: &mut f64| {
+        ui.add_sized(
+            [70.0, 18.0],
+            DragValue::new(value)
+                .custom_formatter(|s, _| units.stress(s))
+                .custom_parser(|s| units.parse_stress(s))
+                .speed(1.0)
+                .range(1.0..=f64::MAX),
+        );
+    };
+
+    ui.add_sized(
+        [80.0, 18.0],
+        DragValue::new(&mut mat.cte)
+            .custom_formatter(|e, _| units.cte(e))
+            .custom_parser(|e| units.parse_cte(e))
+            .speed(0.1)
+            .range(0.0..=f64::MAX)
+            .min_decimals(1),
+    );
+    stress_drag(ui, &mut mat.youngs);
+    ui.add_sized(
+        [40.0, 18.0],
+        DragValue::new(&mut mat.poisson)
+            .speed(0.01)
+            .range(0.0..=0.5)
+            .min_decimals(2),
+    );
+    stress_drag(ui, &mut mat.yield_strength);
+    stress_drag(ui, &mut mat.uts);
+    ui.add_sized(
+        [80.0, 18.0],
+        DragValue::new(&mut mat.density)
+            .custom_formatter(|d, _| units.density(d))
+            .custom_parser(|d| units.parse_density(d))
+            .speed(10.0)
+            .range(0.0..=f64::MAX),
+    );
+    ui.add_sized(
+        [55.0, 18.0],
+        DragValue::new(&mut mat.max_temp)
+            .custom_formatter(|t, _| units.temp(t))
+            .custom_parser(|t| units.parse_temp(t))
+            .speed(1.0)
+            .range(-273.15..=10_000.0),
+    );
+}
+
+/// The built in library and the user's materials, which can be added from
+/// the hole and shaft or edited in place.
+pub fn show_library(ui: &mut Ui, custom: &mut Vec<Material>, parts: [&Material; 2], units: &Units) {
+    let headings = [
+        "", "CTE", "E", "ν", "Yield", "UTS", "Density", "Max", "Data",
+    ];
+
+    egui::ScrollArea::vertical().show(ui, |ui| {
+        Grid::new("material_library")
+            .striped(true)
+            .min_col_width(10.0)
+            .show(ui, |ui| {
+                for heading in headings {
+                    ui.label(RichText::new(heading).strong());
+                }
+                ui.end_row();
+
+                for mat in &LIBRARY {
+                    ui.label(mat.name.as_ref());
+                    ui.label(units.cte(mat.cte));
+                    ui.label(units.stress(mat.youngs));
+                    ui.label(format!("{:.2}", mat.poisson));
+                    ui.label(units.stress(mat.yield_strength));
+                    ui.label(units.stress(mat.uts));
+                    ui.label(units.density(mat.density));
+                    ui.label(units.temp(mat.max_temp));
+                    ui.label(mat.range_text(units));
+                    ui.end_row();
+                }
+            });
+
+        ui.add_space(10.0);
+        ui.label(RichText::new("Custom").strong());
+        ui.add_space(5.0);
+
+        let mut remove = None;
+        Grid::new("material_custom")
+            .striped(true)
+            .min_col_width(10.0)
+            .show(ui, |ui| {
+                for (i, mat) in custom.iter_mut().enumerate() {
+                    ui.add(TextEdit::singleline(mat.name.to_mut()).desired_width(150.0));
+                    properties_ui(ui, mat, units);
+                    ui.label(mat.range_text(units));
+                    if ui.small_button("🗑").on_hover_text("Delete").clicked() {
+                        remove = Some(i);
+                    }
+                    ui.end_row();
+                }
+            });
+        if let Some(i) = remove {
+            custom.remove(i);
+        }
+
+        ui.horizontal(|ui| {
+            for (part, mat) in ["hole", "shaft"].iter().zip(parts) {
+                if ui
+                    .add(Button::new(format!("Add {part} material")))
+                    .on_hover_text("Save a copy to edit and reuse")
+                    .clicked()
+                {
+                    custom.push(Material {
+                        name: Cow::Owned(format!("{} copy", mat.name)),
+                        temp: 20.0,
+                        ..mat.clone()
+                    });
+                }
+            }
+        });
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_material() {
+        let mut mat = Material {
+            temp: 150.0,
+            ..Material::default()
+        };
+        let aluminium = &LIBRARY[9];
+
+        assert!(mat.same(&LIBRARY[0]));
+        mat.set(aluminium);
+        assert!(mat.same(aluminium));
+        assert_eq!(mat.temp, 150.0);
+        assert_eq!(mat.cte, 23.6);
+
+        mat.youngs = 70_000.0;
+        assert!(!mat.same(aluminium));
+
+        // Steel contracts about 0.2 % to LN2, a constant 12 µm/m·K says 0.26 %
+        let steel = Material::default();
+        let contraction = (5.64 + 9.6) / 2.0 * 96.0 + (9.6 + 12.0) / 2.0 * 120.0;
+        assert!((steel.scale(-196.0) - (1.0 - contraction * 1e-6)).abs() < 1e-12);
+        assert!((steel.scale(20.0) - 1.0).abs() < 1e-15);
+
+        // Between points the CTE is interpolated, beyond them it is held
+        let growth = (12.0 + 12.72) / 2.0 * 80.0 + (12.72 + 12.96) / 2.0 * 50.0;
+        assert!((steel.scale(150.0) - (1.0 + growth * 1e-6)).abs() < 1e-12);
+        assert_eq!(steel.factors(-250.0).cte, 0.47);
+        assert!(!steel.in_range(-250.0) && steel.in_range(-196.0));
+        assert!(steel.range_warning(600.0, &Units::default()).is_none());
+
+        let hot = steel.at(400.0);
+        assert!((hot.youngs - 200_000.0 * 0.84).abs() < 1e-9);
+        assert!((hot.yield_strength - 350.0 * 0.65).abs() < 1e-9);
+
+        let constant = Material {
+            curve: Default::default(),
+            ..Material::default()
+        };
+        assert!((constant.scale(-196.0) - (1.0 - 12.0 * 216.0 * 1e-6)).abs() < 1e-12);
+        assert!(constant.in_range(-273.0));
+
+        // Saves from before the curves keep constant properties unless they
+        // name a library material
+        let old = |name: &str| {
+            ron::from_str::<Material>(&format!(
+                "(name: \"{name}\", cte: 13.0, temp: 20.0, youngs: 190000.0)"
+            ))
+            .unwrap()
+        };
+        let custom = old("Custom");
+        assert!(custom.curve.is_empty());
+        assert_eq!((custom.cte, custom.poisson), (13.0, 0.3));
+        assert_eq!(old("6061-T6 aluminium").curve, aluminium.curve);
+
+        let saved = ron::to_string(&steel).unwrap();
+        assert_eq!(ron::from_str::<Material>(&saved).unwrap(), steel);
+        let saved = ron::to_string(&constant).unwrap();
+        assert_eq!(ron::from_str::<Material>(&saved).unwrap(), constant);
+    }
 }
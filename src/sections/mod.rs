@@ -1,9 +1,30 @@
+pub mod ansi;
+pub mod browser;
+pub mod capability;
+pub mod conformance;
+pub mod designation;
+pub mod explain;
 pub mod feature;
 pub mod fit;
+pub mod formula;
+pub mod general;
+pub mod geometric;
 // pub mod input;
+pub mod length;
 pub mod lookup;
 pub mod material;
+pub mod preferred;
+pub mod reference;
+pub mod rules;
+pub mod selector;
+pub mod shrink;
+pub mod stack;
+pub mod statistics;
+pub mod stress;
+pub mod sweep;
 pub mod tolerance;
+pub mod transmission;
+pub mod units;
 pub mod utils;
 // pub mod visual_fit;
 
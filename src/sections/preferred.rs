@@ -0,0 +1,234 @@
+use std::sync::LazyLock;
+
+use egui::{Grid, RichText, Ui};
+
+use super::tolerance::Iso;
+
+// ISO 286-2 / ISO 1829 preferred fits, loosest to tightest
+// H7/h6 sits in both systems so is only listed once
+
+pub struct PreferredFit {
+    pub hole: &'static str,
+    pub shaft: &'static str,
+    pub name: &'static str,
+    pub description: &'static str,
+    pub uses: &'static str,
+}
+
+pub const PREFERRED_FITS: &[PreferredFit] = &[
+    PreferredFit {
+        hole: "H11",
+        shaft: "c11",
+        name: "Loose running",
+        description: "Wide commercial tolerances or allowances on external members.",
+        uses: "Pivots, latches, agricultural and construction machinery.",
+    },
+    PreferredFit {
+        hole: "H9",
+        shaft: "d9",
+        name: "Free running",
+        description: "Not for use where accuracy is essential, but good for large temperature variations, high running speeds or heavy journal pressures.",
+        uses: "Plain bearings on line shafts, idler pulleys, loose collars.",
+    },
+    PreferredFit {
+        hole: "H8",
+        shaft: "f7",
+        name: "Close running",
+        description: "For running on accurate machines and for accurate location at moderate speeds and journal pressures.",
+        uses: "Gearbox shaft bearings, pump and fan spindles, sliding rods.",
+    },
+    PreferredFit {
+        hole: "H7",
+        shaft: "g6",
+        name: "Sliding",
+        description: "Not intended to run freely, but to move and turn freely and locate accurately.",
+        uses: "Spigots, sliding gears and clutch discs, precision guides.",
+    },
+    PreferredFit {
+        hole: "H7",
+        shaft: "h6",
+        name: "Locational clearance",
+        description: "Provides a snug fit for locating stationary parts, but can be freely assembled and disassembled.",
+        uses: "Gear and pulley hubs on keyed shafts, location spigots, bushes.",
+    },
+    PreferredFit {
+        hole: "H7",
+        shaft: "k6",
+        name: "Locational transition",
+        description: "For accurate location, a compromise between clearance and interference.",
+        uses: "Couplings, pulleys and gears that are keyed, inner rings of light duty bearings.",
+    },
+    PreferredFit {
+        hole: "H7",
+        shaft: "n6",
+        name: "Locational transition",
+        description: "For more accurate location where greater interference is permissible.",
+        uses: "Armatures on shafts, bearing bushes, gears under heavier load.",
+    },
+    PreferredFit {
+        hole: "H7",
+        shaft: "p6",
+        name: "Locational interference",
+        description: "For parts requiring rigidity and alignment with prime accuracy of location, but without special bore pressure requirements.",
+        uses: "Bushes pressed into housings, dowels, split bearings.",
+    },
+    PreferredFit {
+        hole: "H7",
+        shaft: "s6",
+        name: "Medium drive",
+        description: "For ordinary steel parts or shrink fits on light sections, the tightest fit usable with cast iron.",
+        uses: "Permanent gear and coupling hubs, bronze bushes in cast iron.",
+    },
+    PreferredFit {
+        hole: "H7",
+        shaft: "u6",
+        name: "Force",
+        description: "For parts which can be highly stressed, or for shrink fits where the heavy pressing forces required are impractical.",
+        uses: "Railway wheels, crank webs, permanent couplings.",
+    },
+    PreferredFit {
+        hole: "C11",
+        shaft: "h11",
+        name: "Loose running",
+        description: "Wide commercial tolerances or allowances on external members.",
+        uses: "Pivots, latches, agricultural and construction machinery.",
+    },
+    PreferredFit {
+        hole: "D9",
+        shaft: "h9",
+        name: "Free running",
+        description: "Not for use where accuracy is essential, but good for large temperature variations, high running speeds or heavy journal pressures.",
+        uses: "Plain bearings on line shafts, idler pulleys, loose collars.",
+    },
+    PreferredFit {
+        hole: "F8",
+        shaft: "h7",
+        name: "Close running",
+        description: "For running on accurate machines and for accurate location at moderate speeds and journal pressures.",
+        uses: "Gearbox shaft bearings, pump and fan spindles, sliding rods.",
+    },
+    PreferredFit {
+        hole: "G7",
+        shaft: "h6",
+        name: "Sliding",
+        description: "Not intended to run freely, but to move and turn freely and locate accurately.",
+        uses: "Spigots, sliding gears and clutch discs, precision guides.",
+    },
+    PreferredFit {
+        hole: "K7",
+        shaft: "h6",
+        name: "Locational transition",
+        description: "For accurate location, a compromise between clearance and interference.",
+        uses: "Couplings, pulleys and gears that are keyed, outer rings of light duty bearings.",
+    },
+    PreferredFit {
+        hole: "N7",
+        shaft: "h6",
+        name: "Locational transition",
+        description: "For more accurate location where greater interference is permissible.",
+        uses: "Bearing bushes, gears under heavier load.",
+    },
+    PreferredFit {
+        hole: "P7",
+        shaft: "h6",
+        name: "Locational interference",
+        description: "For parts requiring rigidity and alignment with prime accuracy of location, but without special bore pressure requirements.",
+        uses: "Bushes pressed into housings, dowels, split bearings.",
+    },
+    PreferredFit {
+        hole: "S7",
+        shaft: "h6",
+        name: "Medium drive",
+        description: "For ordinary steel parts or shrink fits on light sections, the tightest fit usable with cast iron.",
+        uses: "Permanent gear and coupling hubs, bronze bushes in cast iron.",
+    },
+    PreferredFit {
+        hole: "U7",
+        shaft: "h6",
+        name: "Force",
+        description: "For parts which can be highly stressed, or for shrink fits where the heavy pressing forces required are impractical.",
+        uses: "Railway wheels, crank webs, permanent couplings.",
+    },
+];
+
+// Classes of `PREFERRED_FITS` parsed once, in the same order
+static CLASSES: LazyLock<Vec<(Iso, Iso)>> = LazyLock::new(|| {
+    PREFERRED_FITS
+        .iter()
+        .map(|fit| (fit.hole_iso(), fit.shaft_iso()))
+        .collect()
+});
+
+impl PreferredFit {
+    pub fn hole_iso(&self) -> Iso {
+        self.hole
+            .parse()
+            .expect("Preferred fit hole class is valid")
+    }
+
+    pub fn shaft_iso(&self) -> Iso {
+        self.shaft
+            .parse()
+            .expect("Preferred fit shaft class is valid")
+    }
+
+    pub fn find(hole: &Iso, shaft: &Iso) -> Option<&'static PreferredFit> {
+        PREFERRED_FITS
+            .iter()
+            .zip(CLASSES.iter())
+            .find(|(_, classes)| classes.0 == *hole && classes.1 == *shaft)
+            .map(|(fit, _)| fit)
+    }
+}
+
+/// Lists the catalogue and returns the fit the user picks.
+pub fn show_catalogue(ui: &mut Ui) -> Option<&'static PreferredFit> {
+    let mut selected = None;
+
+    ui.label(RichText::new("Preferred Fits").strong().size(15.0));
+    ui.add_space(5.0);
+
+    egui::Frame::group(ui.style())
+        .inner_margin(10.0)
+        .rounding(10.0)
+        .show(ui, |ui| {
+            Grid::new("preferred_fits")
+                .striped(true)
+                .min_col_width(10.0)
+                .show(ui, |ui| {
+                    for fit in PREFERRED_FITS {
+                        ui.label(format!("{} / {}", fit.hole, fit.shaft));
+                        ui.label(fit.name)
+                            .on_hover_cursor(egui::CursorIcon::Help)
+                            .on_hover_text(format!(
+                                "{}\n\nTypical uses: {}",
+                                fit.description, fit.uses
+                            ));
+                        if ui.button("Use").clicked() {
+                            selected = Some(fit);
+                        }
+                        ui.end_row();
+                    }
+                });
+        });
+
+    selected
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_catalogue() {
+        for fit in PREFERRED_FITS {
+            let (hole, shaft) = (fit.hole_iso(), fit.shaft_iso());
+            assert!(hole.deviation.is_hole() && !shaft.deviation.is_hole());
+            assert!(hole.convert(25.0).is_ok() && shaft.convert(25.0).is_ok());
+            assert_eq!(PreferredFit::find(&hole, &shaft).unwrap().name, fit.name);
+        }
+
+        let (hole, shaft) = ("H7".parse().unwrap(), "f6".parse().unwrap());
+        assert!(PreferredFit::find(&hole, &shaft).is_none());
+    }
+}
@@ -0,0 +1,8849 @@
+// Limit deviations of every tolerance class in ISO 286-2:2010, one row per
+// class and size range in the layout of the printed tables. Deviations are
+// in micrometres and ranges run over the first size up to and including the
+// second in mm. Rows with identical limits across neighbouring ranges are merged.
+// This is kept apart from lookup.rs on purpose, conformance.rs checks one against the other
+
+pub const ISO_286_2: &str = "\
+class,over,up_to,upper,lower
+A9,0,3,295,270
+A9,3,6,300,270
+A9,6,10,316,280
+A9,10,18,333,290
+A9,18,30,352,300
+A9,30,40,372,310
+A9,40,50,382,320
+A9,50,65,414,340
+A9,65,80,434,360
+A9,80,100,467,380
+A9,100,120,497,410
+A9,120,140,560,460
+A9,140,160,620,520
+A9,160,180,680,580
+A9,180,200,775,660
+A9,200,225,855,740
+A9,225,250,935,820
+A9,250,280,1050,920
+A9,280,315,1180,1050
+A9,315,355,1340,1200
+A9,355,400,1490,1350
+A9,400,450,1655,1500
+A9,450,500,1805,1650
+A10,0,3,310,270
+A10,3,6,318,270
+A10,6,10,338,280
+A10,10,18,360,290
+A10,18,30,384,300
+A10,30,40,410,310
+A10,40,50,420,320
+A10,50,65,460,340
+A10,65,80,480,360
+A10,80,100,520,380
+A10,100,120,550,410
+A10,120,140,620,460
+A10,140,160,680,520
+A10,160,180,740,580
+A10,180,200,845,660
+A10,200,225,925,740
+A10,225,250,1005,820
+A10,250,280,1130,920
+A10,280,315,1260,1050
+A10,315,355,1430,1200
+A10,355,400,1580,1350
+A10,400,450,1750,1500
+A10,450,500,1900,1650
+A11,0,3,330,270
+A11,3,6,345,270
+A11,6,10,370,280
+A11,10,18,400,290
+A11,18,30,430,300
+A11,30,40,470,310
+A11,40,50,480,320
+A11,50,65,530,340
+A11,65,80,550,360
+A11,80,100,600,380
+A11,100,120,630,410
+A11,120,140,710,460
+A11,140,160,770,520
+A11,160,180,830,580
+A11,180,200,950,660
+A11,200,225,1030,740
+A11,225,250,1110,820
+A11,250,280,1240,920
+A11,280,315,1370,1050
+A11,315,355,1560,1200
+A11,355,400,1710,1350
+A11,400,450,1900,1500
+A11,450,500,2050,1650
+A12,0,3,370,270
+A12,3,6,390,270
+A12,6,10,430,280
+A12,10,18,470,290
+A12,18,30,510,300
+A12,30,40,560,310
+A12,40,50,570,320
+A12,50,65,640,340
+A12,65,80,660,360
+A12,80,100,730,380
+A12,100,120,760,410
+A12,120,140,860,460
+A12,140,160,920,520
+A12,160,180,980,580
+A12,180,200,1120,660
+A12,200,225,1200,740
+A12,225,250,1280,820
+A12,250,280,1440,920
+A12,280,315,1570,1050
+A12,315,355,1770,1200
+A12,355,400,1920,1350
+A12,400,450,2130,1500
+A12,450,500,2280,1650
+A13,0,3,410,270
+A13,3,6,450,270
+A13,6,10,500,280
+A13,10,18,560,290
+A13,18,30,630,300
+A13,30,40,700,310
+A13,40,50,710,320
+A13,50,65,800,340
+A13,65,80,820,360
+A13,80,100,920,380
+A13,100,120,950,410
+A13,120,140,1090,460
+A13,140,160,1150,520
+A13,160,180,1210,580
+A13,180,200,1380,660
+A13,200,225,1460,740
+A13,225,250,1540,820
+A13,250,280,1730,920
+A13,280,315,1860,1050
+A13,315,355,2090,1200
+A13,355,400,2240,1350
+A13,400,450,2470,1500
+A13,450,500,2620,1650
+B8,0,3,154,140
+B8,3,6,158,140
+B8,6,10,172,150
+B8,10,18,177,150
+B8,18,30,193,160
+B8,30,40,209,170
+B8,40,50,219,180
+B8,50,65,236,190
+B8,65,80,246,200
+B8,80,100,274,220
+B8,100,120,294,240
+B8,120,140,323,260
+B8,140,160,343,280
+B8,160,180,373,310
+B8,180,200,412,340
+B8,200,225,452,380
+B8,225,250,492,420
+B8,250,280,561,480
+B8,280,315,621,540
+B8,315,355,689,600
+B8,355,400,769,680
+B8,400,450,857,760
+B8,450,500,937,840
+B9,0,3,165,140
+B9,3,6,170,140
+B9,6,10,186,150
+B9,10,18,193,150
+B9,18,30,212,160
+B9,30,40,232,170
+B9,40,50,242,180
+B9,50,65,264,190
+B9,65,80,274,200
+B9,80,100,307,220
+B9,100,120,327,240
+B9,120,140,360,260
+B9,140,160,380,280
+B9,160,180,410,310
+B9,180,200,455,340
+B9,200,225,495,380
+B9,225,250,535,420
+B9,250,280,610,480
+B9,280,315,670,540
+B9,315,355,740,600
+B9,355,400,820,680
+B9,400,450,915,760
+B9,450,500,995,840
+B10,0,3,180,140
+B10,3,6,188,140
+B10,6,10,208,150
+B10,10,18,220,150
+B10,18,30,244,160
+B10,30,40,270,170
+B10,40,50,280,180
+B10,50,65,310,190
+B10,65,80,320,200
+B10,80,100,360,220
+B10,100,120,380,240
+B10,120,140,420,260
+B10,140,160,440,280
+B10,160,180,470,310
+B10,180,200,525,340
+B10,200,225,565,380
+B10,225,250,605,420
+B10,250,280,690,480
+B10,280,315,750,540
+B10,315,355,830,600
+B10,355,400,910,680
+B10,400,450,1010,760
+B10,450,500,1090,840
+B11,0,3,200,140
+B11,3,6,215,140
+B11,6,10,240,150
+B11,10,18,260,150
+B11,18,30,290,160
+B11,30,40,330,170
+B11,40,50,340,180
+B11,50,65,380,190
+B11,65,80,390,200
+B11,80,100,440,220
+B11,100,120,460,240
+B11,120,140,510,260
+B11,140,160,530,280
+B11,160,180,560,310
+B11,180,200,630,340
+B11,200,225,670,380
+B11,225,250,710,420
+B11,250,280,800,480
+B11,280,315,860,540
+B11,315,355,960,600
+B11,355,400,1040,680
+B11,400,450,1160,760
+B11,450,500,1240,840
+B12,0,3,240,140
+B12,3,6,260,140
+B12,6,10,300,150
+B12,10,18,330,150
+B12,18,30,370,160
+B12,30,40,420,170
+B12,40,50,430,180
+B12,50,65,490,190
+B12,65,80,500,200
+B12,80,100,570,220
+B12,100,120,590,240
+B12,120,140,660,260
+B12,140,160,680,280
+B12,160,180,710,310
+B12,180,200,800,340
+B12,200,225,840,380
+B12,225,250,880,420
+B12,250,280,1000,480
+B12,280,315,1060,540
+B12,315,355,1170,600
+B12,355,400,1250,680
+B12,400,450,1390,760
+B12,450,500,1470,840
+B13,0,3,280,140
+B13,3,6,320,140
+B13,6,10,370,150
+B13,10,18,420,150
+B13,18,30,490,160
+B13,30,40,560,170
+B13,40,50,570,180
+B13,50,65,650,190
+B13,65,80,660,200
+B13,80,100,760,220
+B13,100,120,780,240
+B13,120,140,890,260
+B13,140,160,910,280
+B13,160,180,940,310
+B13,180,200,1060,340
+B13,200,225,1100,380
+B13,225,250,1140,420
+B13,250,280,1290,480
+B13,280,315,1350,540
+B13,315,355,1490,600
+B13,355,400,1570,680
+B13,400,450,1730,760
+B13,450,500,1810,840
+C8,0,3,74,60
+C8,3,6,88,70
+C8,6,10,102,80
+C8,10,18,122,95
+C8,18,30,143,110
+C8,30,40,159,120
+C8,40,50,169,130
+C8,50,65,186,140
+C8,65,80,196,150
+C8,80,100,224,170
+C8,100,120,234,180
+C8,120,140,263,200
+C8,140,160,273,210
+C8,160,180,293,230
+C8,180,200,312,240
+C8,200,225,332,260
+C8,225,250,352,280
+C8,250,280,381,300
+C8,280,315,411,330
+C8,315,355,449,360
+C8,355,400,489,400
+C8,400,450,537,440
+C8,450,500,577,480
+C9,0,3,85,60
+C9,3,6,100,70
+C9,6,10,116,80
+C9,10,18,138,95
+C9,18,30,162,110
+C9,30,40,182,120
+C9,40,50,192,130
+C9,50,65,214,140
+C9,65,80,224,150
+C9,80,100,257,170
+C9,100,120,267,180
+C9,120,140,300,200
+C9,140,160,310,210
+C9,160,180,330,230
+C9,180,200,355,240
+C9,200,225,375,260
+C9,225,250,395,280
+C9,250,280,430,300
+C9,280,315,460,330
+C9,315,355,500,360
+C9,355,400,540,400
+C9,400,450,595,440
+C9,450,500,635,480
+C10,0,3,100,60
+C10,3,6,118,70
+C10,6,10,138,80
+C10,10,18,165,95
+C10,18,30,194,110
+C10,30,40,220,120
+C10,40,50,230,130
+C10,50,65,260,140
+C10,65,80,270,150
+C10,80,100,310,170
+C10,100,120,320,180
+C10,120,140,360,200
+C10,140,160,370,210
+C10,160,180,390,230
+C10,180,200,425,240
+C10,200,225,445,260
+C10,225,250,465,280
+C10,250,280,510,300
+C10,280,315,540,330
+C10,315,355,590,360
+C10,355,400,630,400
+C10,400,450,690,440
+C10,450,500,730,480
+C11,0,3,120,60
+C11,3,6,145,70
+C11,6,10,170,80
+C11,10,18,205,95
+C11,18,30,240,110
+C11,30,40,280,120
+C11,40,50,290,130
+C11,50,65,330,140
+C11,65,80,340,150
+C11,80,100,390,170
+C11,100,120,400,180
+C11,120,140,450,200
+C11,140,160,460,210
+C11,160,180,480,230
+C11,180,200,530,240
+C11,200,225,550,260
+C11,225,250,570,280
+C11,250,280,620,300
+C11,280,315,650,330
+C11,315,355,720,360
+C11,355,400,760,400
+C11,400,450,840,440
+C11,450,500,880,480
+C12,0,3,160,60
+C12,3,6,190,70
+C12,6,10,230,80
+C12,10,18,275,95
+C12,18,30,320,110
+C12,30,40,370,120
+C12,40,50,380,130
+C12,50,65,440,140
+C12,65,80,450,150
+C12,80,100,520,170
+C12,100,120,530,180
+C12,120,140,600,200
+C12,140,160,610,210
+C12,160,180,630,230
+C12,180,200,700,240
+C12,200,225,720,260
+C12,225,250,740,280
+C12,250,280,820,300
+C12,280,315,850,330
+C12,315,355,930,360
+C12,355,400,970,400
+C12,400,450,1070,440
+C12,450,500,1110,480
+C13,0,3,200,60
+C13,3,6,250,70
+C13,6,10,300,80
+C13,10,18,365,95
+C13,18,30,440,110
+C13,30,40,510,120
+C13,40,50,520,130
+C13,50,65,600,140
+C13,65,80,610,150
+C13,80,100,710,170
+C13,100,120,720,180
+C13,120,140,830,200
+C13,140,160,840,210
+C13,160,180,860,230
+C13,180,200,960,240
+C13,200,225,980,260
+C13,225,250,1000,280
+C13,250,280,1110,300
+C13,280,315,1140,330
+C13,315,355,1250,360
+C13,355,400,1290,400
+C13,400,450,1410,440
+C13,450,500,1450,480
+CD6,0,3,40,34
+CD6,3,6,54,46
+CD6,6,10,65,56
+CD7,0,3,44,34
+CD7,3,6,58,46
+CD7,6,10,71,56
+CD8,0,3,48,34
+CD8,3,6,64,46
+CD8,6,10,78,56
+CD9,0,3,59,34
+CD9,3,6,76,46
+CD9,6,10,92,56
+CD10,0,3,74,34
+CD10,3,6,94,46
+CD10,6,10,114,56
+D6,0,3,26,20
+D6,3,6,38,30
+D6,6,10,49,40
+D6,10,18,61,50
+D6,18,30,78,65
+D6,30,50,96,80
+D6,50,80,119,100
+D6,80,120,142,120
+D6,120,180,170,145
+D6,180,250,199,170
+D6,250,315,222,190
+D6,315,400,246,210
+D6,400,500,270,230
+D6,500,630,304,260
+D6,630,800,340,290
+D6,800,1000,376,320
+D6,1000,1250,416,350
+D6,1250,1600,468,390
+D6,1600,2000,522,430
+D6,2000,2500,590,480
+D6,2500,3150,655,520
+D7,0,3,30,20
+D7,3,6,42,30
+D7,6,10,55,40
+D7,10,18,68,50
+D7,18,30,86,65
+D7,30,50,105,80
+D7,50,80,130,100
+D7,80,120,155,120
+D7,120,180,185,145
+D7,180,250,216,170
+D7,250,315,242,190
+D7,315,400,267,210
+D7,400,500,293,230
+D7,500,630,330,260
+D7,630,800,370,290
+D7,800,1000,410,320
+D7,1000,1250,455,350
+D7,1250,1600,515,390
+D7,1600,2000,580,430
+D7,2000,2500,655,480
+D7,2500,3150,730,520
+D8,0,3,34,20
+D8,3,6,48,30
+D8,6,10,62,40
+D8,10,18,77,50
+D8,18,30,98,65
+D8,30,50,119,80
+D8,50,80,146,100
+D8,80,120,174,120
+D8,120,180,208,145
+D8,180,250,242,170
+D8,250,315,271,190
+D8,315,400,299,210
+D8,400,500,327,230
+D8,500,630,370,260
+D8,630,800,415,290
+D8,800,1000,460,320
+D8,1000,1250,515,350
+D8,1250,1600,585,390
+D8,1600,2000,660,430
+D8,2000,2500,760,480
+D8,2500,3150,850,520
+D9,0,3,45,20
+D9,3,6,60,30
+D9,6,10,76,40
+D9,10,18,93,50
+D9,18,30,117,65
+D9,30,50,142,80
+D9,50,80,174,100
+D9,80,120,207,120
+D9,120,180,245,145
+D9,180,250,285,170
+D9,250,315,320,190
+D9,315,400,350,210
+D9,400,500,385,230
+D9,500,630,435,260
+D9,630,800,490,290
+D9,800,1000,550,320
+D9,1000,1250,610,350
+D9,1250,1600,700,390
+D9,1600,2000,800,430
+D9,2000,2500,920,480
+D9,2500,3150,1060,520
+D10,0,3,60,20
+D10,3,6,78,30
+D10,6,10,98,40
+D10,10,18,120,50
+D10,18,30,149,65
+D10,30,50,180,80
+D10,50,80,220,100
+D10,80,120,260,120
+D10,120,180,305,145
+D10,180,250,355,170
+D10,250,315,400,190
+D10,315,400,440,210
+D10,400,500,480,230
+D10,500,630,540,260
+D10,630,800,610,290
+D10,800,1000,680,320
+D10,1000,1250,770,350
+D10,1250,1600,890,390
+D10,1600,2000,1030,430
+D10,2000,2500,1180,480
+D10,2500,3150,1380,520
+D11,0,3,80,20
+D11,3,6,105,30
+D11,6,10,130,40
+D11,10,18,160,50
+D11,18,30,195,65
+D11,30,50,240,80
+D11,50,80,290,100
+D11,80,120,340,120
+D11,120,180,395,145
+D11,180,250,460,170
+D11,250,315,510,190
+D11,315,400,570,210
+D11,400,500,630,230
+D11,500,630,700,260
+D11,630,800,790,290
+D11,800,1000,880,320
+D11,1000,1250,1010,350
+D11,1250,1600,1170,390
+D11,1600,2000,1350,430
+D11,2000,2500,1580,480
+D11,2500,3150,1870,520
+D12,0,3,120,20
+D12,3,6,150,30
+D12,6,10,190,40
+D12,10,18,230,50
+D12,18,30,275,65
+D12,30,50,330,80
+D12,50,80,400,100
+D12,80,120,470,120
+D12,120,180,545,145
+D12,180,250,630,170
+D12,250,315,710,190
+D12,315,400,780,210
+D12,400,500,860,230
+D12,500,630,960,260
+D12,630,800,1090,290
+D12,800,1000,1220,320
+D12,1000,1250,1400,350
+D12,1250,1600,1640,390
+D12,1600,2000,1930,430
+D12,2000,2500,2230,480
+D12,2500,3150,2620,520
+D13,0,3,160,20
+D13,3,6,210,30
+D13,6,10,260,40
+D13,10,18,320,50
+D13,18,30,395,65
+D13,30,50,470,80
+D13,50,80,560,100
+D13,80,120,660,120
+D13,120,180,775,145
+D13,180,250,890,170
+D13,250,315,1000,190
+D13,315,400,1100,210
+D13,400,500,1200,230
+D13,500,630,1360,260
+D13,630,800,1540,290
+D13,800,1000,1720,320
+D13,1000,1250,2000,350
+D13,1250,1600,2340,390
+D13,1600,2000,2730,430
+D13,2000,2500,3280,480
+D13,2500,3150,3820,520
+E5,0,3,18,14
+E5,3,6,25,20
+E5,6,10,31,25
+E5,10,18,40,32
+E5,18,30,49,40
+E5,30,50,61,50
+E5,50,80,73,60
+E5,80,120,87,72
+E5,120,180,103,85
+E5,180,250,120,100
+E5,250,315,133,110
+E5,315,400,150,125
+E5,400,500,162,135
+E5,500,630,177,145
+E5,630,800,196,160
+E5,800,1000,210,170
+E5,1000,1250,242,195
+E5,1250,1600,275,220
+E5,1600,2000,305,240
+E5,2000,2500,338,260
+E5,2500,3150,386,290
+E6,0,3,20,14
+E6,3,6,28,20
+E6,6,10,34,25
+E6,10,18,43,32
+E6,18,30,53,40
+E6,30,50,66,50
+E6,50,80,79,60
+E6,80,120,94,72
+E6,120,180,110,85
+E6,180,250,129,100
+E6,250,315,142,110
+E6,315,400,161,125
+E6,400,500,175,135
+E6,500,630,189,145
+E6,630,800,210,160
+E6,800,1000,226,170
+E6,1000,1250,261,195
+E6,1250,1600,298,220
+E6,1600,2000,332,240
+E6,2000,2500,370,260
+E6,2500,3150,425,290
+E7,0,3,24,14
+E7,3,6,32,20
+E7,6,10,40,25
+E7,10,18,50,32
+E7,18,30,61,40
+E7,30,50,75,50
+E7,50,80,90,60
+E7,80,120,107,72
+E7,120,180,125,85
+E7,180,250,146,100
+E7,250,315,162,110
+E7,315,400,182,125
+E7,400,500,198,135
+E7,500,630,215,145
+E7,630,800,240,160
+E7,800,1000,260,170
+E7,1000,1250,300,195
+E7,1250,1600,345,220
+E7,1600,2000,390,240
+E7,2000,2500,435,260
+E7,2500,3150,500,290
+E8,0,3,28,14
+E8,3,6,38,20
+E8,6,10,47,25
+E8,10,18,59,32
+E8,18,30,73,40
+E8,30,50,89,50
+E8,50,80,106,60
+E8,80,120,126,72
+E8,120,180,148,85
+E8,180,250,172,100
+E8,250,315,191,110
+E8,315,400,214,125
+E8,400,500,232,135
+E8,500,630,255,145
+E8,630,800,285,160
+E8,800,1000,310,170
+E8,1000,1250,360,195
+E8,1250,1600,415,220
+E8,1600,2000,470,240
+E8,2000,2500,540,260
+E8,2500,3150,620,290
+E9,0,3,39,14
+E9,3,6,50,20
+E9,6,10,61,25
+E9,10,18,75,32
+E9,18,30,92,40
+E9,30,50,112,50
+E9,50,80,134,60
+E9,80,120,159,72
+E9,120,180,185,85
+E9,180,250,215,100
+E9,250,315,240,110
+E9,315,400,265,125
+E9,400,500,290,135
+E9,500,630,320,145
+E9,630,800,360,160
+E9,800,1000,400,170
+E9,1000,1250,455,195
+E9,1250,1600,530,220
+E9,1600,2000,610,240
+E9,2000,2500,700,260
+E9,2500,3150,830,290
+E10,0,3,54,14
+E10,3,6,68,20
+E10,6,10,83,25
+E10,10,18,102,32
+E10,18,30,124,40
+E10,30,50,150,50
+E10,50,80,180,60
+E10,80,120,212,72
+E10,120,180,245,85
+E10,180,250,285,100
+E10,250,315,320,110
+E10,315,400,355,125
+E10,400,500,385,135
+E10,500,630,425,145
+E10,630,800,480,160
+E10,800,1000,530,170
+E10,1000,1250,615,195
+E10,1250,1600,720,220
+E10,1600,2000,840,240
+E10,2000,2500,960,260
+E10,2500,3150,1150,290
+EF3,0,3,12,10
+EF3,3,6,16.5,14
+EF3,6,10,20.5,18
+EF4,0,3,13,10
+EF4,3,6,18,14
+EF4,6,10,22,18
+EF5,0,3,14,10
+EF5,3,6,19,14
+EF5,6,10,24,18
+EF6,0,3,16,10
+EF6,3,6,22,14
+EF6,6,10,27,18
+EF7,0,3,20,10
+EF7,3,6,26,14
+EF7,6,10,33,18
+EF8,0,3,24,10
+EF8,3,6,32,14
+EF8,6,10,40,18
+EF9,0,3,35,10
+EF9,3,6,44,14
+EF9,6,10,54,18
+EF10,0,3,50,10
+EF10,3,6,62,14
+EF10,6,10,76,18
+F3,0,3,8,6
+F3,3,6,12.5,10
+F3,6,10,15.5,13
+F3,10,18,19,16
+F3,18,30,24,20
+F3,30,50,29,25
+F3,50,80,35,30
+F3,80,120,42,36
+F3,120,180,51,43
+F3,180,250,60,50
+F3,250,315,68,56
+F3,315,400,75,62
+F3,400,500,83,68
+F3,500,630,92,76
+F3,630,800,98,80
+F3,800,1000,107,86
+F3,1000,1250,122,98
+F3,1250,1600,139,110
+F3,1600,2000,155,120
+F3,2000,2500,171,130
+F3,2500,3150,195,145
+F4,0,3,9,6
+F4,3,6,14,10
+F4,6,10,17,13
+F4,10,18,21,16
+F4,18,30,26,20
+F4,30,50,32,25
+F4,50,80,38,30
+F4,80,120,46,36
+F4,120,180,55,43
+F4,180,250,64,50
+F4,250,315,72,56
+F4,315,400,80,62
+F4,400,500,88,68
+F4,500,630,98,76
+F4,630,800,105,80
+F4,800,1000,114,86
+F4,1000,1250,131,98
+F4,1250,1600,149,110
+F4,1600,2000,166,120
+F4,2000,2500,185,130
+F4,2500,3150,213,145
+F5,0,3,10,6
+F5,3,6,15,10
+F5,6,10,19,13
+F5,10,18,24,16
+F5,18,30,29,20
+F5,30,50,36,25
+F5,50,80,43,30
+F5,80,120,51,36
+F5,120,180,61,43
+F5,180,250,70,50
+F5,250,315,79,56
+F5,315,400,87,62
+F5,400,500,95,68
+F5,500,630,108,76
+F5,630,800,116,80
+F5,800,1000,126,86
+F5,1000,1250,145,98
+F5,1250,1600,165,110
+F5,1600,2000,185,120
+F5,2000,2500,208,130
+F5,2500,3150,241,145
+F6,0,3,12,6
+F6,3,6,18,10
+F6,6,10,22,13
+F6,10,18,27,16
+F6,18,30,33,20
+F6,30,50,41,25
+F6,50,80,49,30
+F6,80,120,58,36
+F6,120,180,68,43
+F6,180,250,79,50
+F6,250,315,88,56
+F6,315,400,98,62
+F6,400,500,108,68
+F6,500,630,120,76
+F6,630,800,130,80
+F6,800,1000,142,86
+F6,1000,1250,164,98
+F6,1250,1600,188,110
+F6,1600,2000,212,120
+F6,2000,2500,240,130
+F6,2500,3150,280,145
+F7,0,3,16,6
+F7,3,6,22,10
+F7,6,10,28,13
+F7,10,18,34,16
+F7,18,30,41,20
+F7,30,50,50,25
+F7,50,80,60,30
+F7,80,120,71,36
+F7,120,180,83,43
+F7,180,250,96,50
+F7,250,315,108,56
+F7,315,400,119,62
+F7,400,500,131,68
+F7,500,630,146,76
+F7,630,800,160,80
+F7,800,1000,176,86
+F7,1000,1250,203,98
+F7,1250,1600,235,110
+F7,1600,2000,270,120
+F7,2000,2500,305,130
+F7,2500,3150,355,145
+F8,0,3,20,6
+F8,3,6,28,10
+F8,6,10,35,13
+F8,10,18,43,16
+F8,18,30,53,20
+F8,30,50,64,25
+F8,50,80,76,30
+F8,80,120,90,36
+F8,120,180,106,43
+F8,180,250,122,50
+F8,250,315,137,56
+F8,315,400,151,62
+F8,400,500,165,68
+F8,500,630,186,76
+F8,630,800,205,80
+F8,800,1000,226,86
+F8,1000,1250,263,98
+F8,1250,1600,305,110
+F8,1600,2000,350,120
+F8,2000,2500,410,130
+F8,2500,3150,475,145
+F9,0,3,31,6
+F9,3,6,40,10
+F9,6,10,49,13
+F9,10,18,59,16
+F9,18,30,72,20
+F9,30,50,87,25
+F9,50,80,104,30
+F9,80,120,123,36
+F9,120,180,143,43
+F9,180,250,165,50
+F9,250,315,186,56
+F9,315,400,202,62
+F9,400,500,223,68
+F9,500,630,251,76
+F9,630,800,280,80
+F9,800,1000,316,86
+F9,1000,1250,358,98
+F9,1250,1600,420,110
+F9,1600,2000,490,120
+F9,2000,2500,570,130
+F9,2500,3150,685,145
+F10,0,3,46,6
+F10,3,6,58,10
+F10,6,10,71,13
+F10,10,18,86,16
+F10,18,30,104,20
+F10,30,50,125,25
+F10,50,80,150,30
+F10,80,120,176,36
+F10,120,180,203,43
+F10,180,250,235,50
+F10,250,315,266,56
+F10,315,400,292,62
+F10,400,500,318,68
+F10,500,630,356,76
+F10,630,800,400,80
+F10,800,1000,446,86
+F10,1000,1250,518,98
+F10,1250,1600,610,110
+F10,1600,2000,720,120
+F10,2000,2500,830,130
+F10,2500,3150,1005,145
+FG3,0,3,6,4
+FG3,3,6,8.5,6
+FG3,6,10,10.5,8
+FG4,0,3,7,4
+FG4,3,6,10,6
+FG4,6,10,12,8
+FG5,0,3,8,4
+FG5,3,6,11,6
+FG5,6,10,14,8
+FG6,0,3,10,4
+FG6,3,6,14,6
+FG6,6,10,17,8
+FG7,0,3,14,4
+FG7,3,6,18,6
+FG7,6,10,23,8
+FG8,0,3,18,4
+FG8,3,6,24,6
+FG8,6,10,30,8
+FG9,0,3,29,4
+FG9,3,6,36,6
+FG9,6,10,44,8
+FG10,0,3,44,4
+FG10,3,6,54,6
+FG10,6,10,66,8
+G3,0,3,4,2
+G3,3,6,6.5,4
+G3,6,10,7.5,5
+G3,10,18,9,6
+G3,18,30,11,7
+G3,30,50,13,9
+G3,50,80,15,10
+G3,80,120,18,12
+G3,120,180,22,14
+G3,180,250,25,15
+G3,250,315,29,17
+G3,315,400,31,18
+G3,400,500,35,20
+G3,500,630,38,22
+G3,630,800,42,24
+G3,800,1000,47,26
+G3,1000,1250,52,28
+G3,1250,1600,59,30
+G3,1600,2000,67,32
+G3,2000,2500,75,34
+G3,2500,3150,88,38
+G4,0,3,5,2
+G4,3,6,8,4
+G4,6,10,9,5
+G4,10,18,11,6
+G4,18,30,13,7
+G4,30,50,16,9
+G4,50,80,18,10
+G4,80,120,22,12
+G4,120,180,26,14
+G4,180,250,29,15
+G4,250,315,33,17
+G4,315,400,36,18
+G4,400,500,40,20
+G4,500,630,44,22
+G4,630,800,49,24
+G4,800,1000,54,26
+G4,1000,1250,61,28
+G4,1250,1600,69,30
+G4,1600,2000,78,32
+G4,2000,2500,89,34
+G4,2500,3150,106,38
+G5,0,3,6,2
+G5,3,6,9,4
+G5,6,10,11,5
+G5,10,18,14,6
+G5,18,30,16,7
+G5,30,50,20,9
+G5,50,80,23,10
+G5,80,120,27,12
+G5,120,180,32,14
+G5,180,250,35,15
+G5,250,315,40,17
+G5,315,400,43,18
+G5,400,500,47,20
+G5,500,630,54,22
+G5,630,800,60,24
+G5,800,1000,66,26
+G5,1000,1250,75,28
+G5,1250,1600,85,30
+G5,1600,2000,97,32
+G5,2000,2500,112,34
+G5,2500,3150,134,38
+G6,0,3,8,2
+G6,3,6,12,4
+G6,6,10,14,5
+G6,10,18,17,6
+G6,18,30,20,7
+G6,30,50,25,9
+G6,50,80,29,10
+G6,80,120,34,12
+G6,120,180,39,14
+G6,180,250,44,15
+G6,250,315,49,17
+G6,315,400,54,18
+G6,400,500,60,20
+G6,500,630,66,22
+G6,630,800,74,24
+G6,800,1000,82,26
+G6,1000,1250,94,28
+G6,1250,1600,108,30
+G6,1600,2000,124,32
+G6,2000,2500,144,34
+G6,2500,3150,173,38
+G7,0,3,12,2
+G7,3,6,16,4
+G7,6,10,20,5
+G7,10,18,24,6
+G7,18,30,28,7
+G7,30,50,34,9
+G7,50,80,40,10
+G7,80,120,47,12
+G7,120,180,54,14
+G7,180,250,61,15
+G7,250,315,69,17
+G7,315,400,75,18
+G7,400,500,83,20
+G7,500,630,92,22
+G7,630,800,104,24
+G7,800,1000,116,26
+G7,1000,1250,133,28
+G7,1250,1600,155,30
+G7,1600,2000,182,32
+G7,2000,2500,209,34
+G7,2500,3150,248,38
+G8,0,3,16,2
+G8,3,6,22,4
+G8,6,10,27,5
+G8,10,18,33,6
+G8,18,30,40,7
+G8,30,50,48,9
+G8,50,80,56,10
+G8,80,120,66,12
+G8,120,180,77,14
+G8,180,250,87,15
+G8,250,315,98,17
+G8,315,400,107,18
+G8,400,500,117,20
+G8,500,630,132,22
+G8,630,800,149,24
+G8,800,1000,166,26
+G8,1000,1250,193,28
+G8,1250,1600,225,30
+G8,1600,2000,262,32
+G8,2000,2500,314,34
+G8,2500,3150,368,38
+G9,0,3,27,2
+G9,3,6,34,4
+G9,6,10,41,5
+G9,10,18,49,6
+G9,18,30,59,7
+G9,30,50,71,9
+G9,50,80,84,10
+G9,80,120,99,12
+G9,120,180,114,14
+G9,180,250,130,15
+G9,250,315,147,17
+G9,315,400,158,18
+G9,400,500,175,20
+G9,500,630,197,22
+G9,630,800,224,24
+G9,800,1000,256,26
+G9,1000,1250,288,28
+G9,1250,1600,340,30
+G9,1600,2000,402,32
+G9,2000,2500,474,34
+G9,2500,3150,578,38
+G10,0,3,42,2
+G10,3,6,52,4
+G10,6,10,63,5
+G10,10,18,76,6
+G10,18,30,91,7
+G10,30,50,109,9
+G10,50,80,130,10
+G10,80,120,152,12
+G10,120,180,174,14
+G10,180,250,200,15
+G10,250,315,227,17
+G10,315,400,248,18
+G10,400,500,270,20
+G10,500,630,302,22
+G10,630,800,344,24
+G10,800,1000,386,26
+G10,1000,1250,448,28
+G10,1250,1600,530,30
+G10,1600,2000,632,32
+G10,2000,2500,734,34
+G10,2500,3150,898,38
+H01,0,3,0.3,0
+H01,3,10,0.4,0
+H01,10,18,0.5,0
+H01,18,50,0.6,0
+H01,50,80,0.8,0
+H01,80,120,1,0
+H01,120,180,1.2,0
+H01,180,250,2,0
+H01,250,315,2.5,0
+H01,315,400,3,0
+H01,400,500,4,0
+H0,0,3,0.5,0
+H0,3,10,0.6,0
+H0,10,18,0.8,0
+H0,18,50,1,0
+H0,50,80,1.2,0
+H0,80,120,1.5,0
+H0,120,180,2,0
+H0,180,250,3,0
+H0,250,315,4,0
+H0,315,400,5,0
+H0,400,500,6,0
+H1,0,3,0.8,0
+H1,3,10,1,0
+H1,10,18,1.2,0
+H1,18,50,1.5,0
+H1,50,80,2,0
+H1,80,120,2.5,0
+H1,120,180,3.5,0
+H1,180,250,4.5,0
+H1,250,315,6,0
+H1,315,400,7,0
+H1,400,500,8,0
+H1,500,630,9,0
+H1,630,800,10,0
+H1,800,1000,11,0
+H1,1000,1250,13,0
+H1,1250,1600,15,0
+H1,1600,2000,18,0
+H1,2000,2500,22,0
+H1,2500,3150,26,0
+H2,0,3,1.2,0
+H2,3,10,1.5,0
+H2,10,18,2,0
+H2,18,50,2.5,0
+H2,50,80,3,0
+H2,80,120,4,0
+H2,120,180,5,0
+H2,180,250,7,0
+H2,250,315,8,0
+H2,315,400,9,0
+H2,400,500,10,0
+H2,500,630,11,0
+H2,630,800,13,0
+H2,800,1000,15,0
+H2,1000,1250,18,0
+H2,1250,1600,21,0
+H2,1600,2000,25,0
+H2,2000,2500,30,0
+H2,2500,3150,36,0
+H3,0,3,2,0
+H3,3,10,2.5,0
+H3,10,18,3,0
+H3,18,50,4,0
+H3,50,80,5,0
+H3,80,120,6,0
+H3,120,180,8,0
+H3,180,250,10,0
+H3,250,315,12,0
+H3,315,400,13,0
+H3,400,500,15,0
+H3,500,630,16,0
+H3,630,800,18,0
+H3,800,1000,21,0
+H3,1000,1250,24,0
+H3,1250,1600,29,0
+H3,1600,2000,35,0
+H3,2000,2500,41,0
+H3,2500,3150,50,0
+H4,0,3,3,0
+H4,3,10,4,0
+H4,10,18,5,0
+H4,18,30,6,0
+H4,30,50,7,0
+H4,50,80,8,0
+H4,80,120,10,0
+H4,120,180,12,0
+H4,180,250,14,0
+H4,250,315,16,0
+H4,315,400,18,0
+H4,400,500,20,0
+H4,500,630,22,0
+H4,630,800,25,0
+H4,800,1000,28,0
+H4,1000,1250,33,0
+H4,1250,1600,39,0
+H4,1600,2000,46,0
+H4,2000,2500,55,0
+H4,2500,3150,68,0
+H5,0,3,4,0
+H5,3,6,5,0
+H5,6,10,6,0
+H5,10,18,8,0
+H5,18,30,9,0
+H5,30,50,11,0
+H5,50,80,13,0
+H5,80,120,15,0
+H5,120,180,18,0
+H5,180,250,20,0
+H5,250,315,23,0
+H5,315,400,25,0
+H5,400,500,27,0
+H5,500,630,32,0
+H5,630,800,36,0
+H5,800,1000,40,0
+H5,1000,1250,47,0
+H5,1250,1600,55,0
+H5,1600,2000,65,0
+H5,2000,2500,78,0
+H5,2500,3150,96,0
+H6,0,3,6,0
+H6,3,6,8,0
+H6,6,10,9,0
+H6,10,18,11,0
+H6,18,30,13,0
+H6,30,50,16,0
+H6,50,80,19,0
+H6,80,120,22,0
+H6,120,180,25,0
+H6,180,250,29,0
+H6,250,315,32,0
+H6,315,400,36,0
+H6,400,500,40,0
+H6,500,630,44,0
+H6,630,800,50,0
+H6,800,1000,56,0
+H6,1000,1250,66,0
+H6,1250,1600,78,0
+H6,1600,2000,92,0
+H6,2000,2500,110,0
+H6,2500,3150,135,0
+H7,0,3,10,0
+H7,3,6,12,0
+H7,6,10,15,0
+H7,10,18,18,0
+H7,18,30,21,0
+H7,30,50,25,0
+H7,50,80,30,0
+H7,80,120,35,0
+H7,120,180,40,0
+H7,180,250,46,0
+H7,250,315,52,0
+H7,315,400,57,0
+H7,400,500,63,0
+H7,500,630,70,0
+H7,630,800,80,0
+H7,800,1000,90,0
+H7,1000,1250,105,0
+H7,1250,1600,125,0
+H7,1600,2000,150,0
+H7,2000,2500,175,0
+H7,2500,3150,210,0
+H8,0,3,14,0
+H8,3,6,18,0
+H8,6,10,22,0
+H8,10,18,27,0
+H8,18,30,33,0
+H8,30,50,39,0
+H8,50,80,46,0
+H8,80,120,54,0
+H8,120,180,63,0
+H8,180,250,72,0
+H8,250,315,81,0
+H8,315,400,89,0
+H8,400,500,97,0
+H8,500,630,110,0
+H8,630,800,125,0
+H8,800,1000,140,0
+H8,1000,1250,165,0
+H8,1250,1600,195,0
+H8,1600,2000,230,0
+H8,2000,2500,280,0
+H8,2500,3150,330,0
+H9,0,3,25,0
+H9,3,6,30,0
+H9,6,10,36,0
+H9,10,18,43,0
+H9,18,30,52,0
+H9,30,50,62,0
+H9,50,80,74,0
+H9,80,120,87,0
+H9,120,180,100,0
+H9,180,250,115,0
+H9,250,315,130,0
+H9,315,400,140,0
+H9,400,500,155,0
+H9,500,630,175,0
+H9,630,800,200,0
+H9,800,1000,230,0
+H9,1000,1250,260,0
+H9,1250,1600,310,0
+H9,1600,2000,370,0
+H9,2000,2500,440,0
+H9,2500,3150,540,0
+H10,0,3,40,0
+H10,3,6,48,0
+H10,6,10,58,0
+H10,10,18,70,0
+H10,18,30,84,0
+H10,30,50,100,0
+H10,50,80,120,0
+H10,80,120,140,0
+H10,120,180,160,0
+H10,180,250,185,0
+H10,250,315,210,0
+H10,315,400,230,0
+H10,400,500,250,0
+H10,500,630,280,0
+H10,630,800,320,0
+H10,800,1000,360,0
+H10,1000,1250,420,0
+H10,1250,1600,500,0
+H10,1600,2000,600,0
+H10,2000,2500,700,0
+H10,2500,3150,860,0
+H11,0,3,60,0
+H11,3,6,75,0
+H11,6,10,90,0
+H11,10,18,110,0
+H11,18,30,130,0
+H11,30,50,160,0
+H11,50,80,190,0
+H11,80,120,220,0
+H11,120,180,250,0
+H11,180,250,290,0
+H11,250,315,320,0
+H11,315,400,360,0
+H11,400,500,400,0
+H11,500,630,440,0
+H11,630,800,500,0
+H11,800,1000,560,0
+H11,1000,1250,660,0
+H11,1250,1600,780,0
+H11,1600,2000,920,0
+H11,2000,2500,1100,0
+H11,2500,3150,1350,0
+H12,0,3,100,0
+H12,3,6,120,0
+H12,6,10,150,0
+H12,10,18,180,0
+H12,18,30,210,0
+H12,30,50,250,0
+H12,50,80,300,0
+H12,80,120,350,0
+H12,120,180,400,0
+H12,180,250,460,0
+H12,250,315,520,0
+H12,315,400,570,0
+H12,400,500,630,0
+H12,500,630,700,0
+H12,630,800,800,0
+H12,800,1000,900,0
+H12,1000,1250,1050,0
+H12,1250,1600,1250,0
+H12,1600,2000,1500,0
+H12,2000,2500,1750,0
+H12,2500,3150,2100,0
+H13,0,3,140,0
+H13,3,6,180,0
+H13,6,10,220,0
+H13,10,18,270,0
+H13,18,30,330,0
+H13,30,50,390,0
+H13,50,80,460,0
+H13,80,120,540,0
+H13,120,180,630,0
+H13,180,250,720,0
+H13,250,315,810,0
+H13,315,400,890,0
+H13,400,500,970,0
+H13,500,630,1100,0
+H13,630,800,1250,0
+H13,800,1000,1400,0
+H13,1000,1250,1650,0
+H13,1250,1600,1950,0
+H13,1600,2000,2300,0
+H13,2000,2500,2800,0
+H13,2500,3150,3300,0
+H14,0,3,250,0
+H14,3,6,300,0
+H14,6,10,360,0
+H14,10,18,430,0
+H14,18,30,520,0
+H14,30,50,620,0
+H14,50,80,740,0
+H14,80,120,870,0
+H14,120,180,1000,0
+H14,180,250,1150,0
+H14,250,315,1300,0
+H14,315,400,1400,0
+H14,400,500,1550,0
+H14,500,630,1750,0
+H14,630,800,2000,0
+H14,800,1000,2300,0
+H14,1000,1250,2600,0
+H14,1250,1600,3100,0
+H14,1600,2000,3700,0
+H14,2000,2500,4400,0
+H14,2500,3150,5400,0
+H15,0,3,400,0
+H15,3,6,480,0
+H15,6,10,580,0
+H15,10,18,700,0
+H15,18,30,840,0
+H15,30,50,1000,0
+H15,50,80,1200,0
+H15,80,120,1400,0
+H15,120,180,1600,0
+H15,180,250,1850,0
+H15,250,315,2100,0
+H15,315,400,2300,0
+H15,400,500,2500,0
+H15,500,630,2800,0
+H15,630,800,3200,0
+H15,800,1000,3600,0
+H15,1000,1250,4200,0
+H15,1250,1600,5000,0
+H15,1600,2000,6000,0
+H15,2000,2500,7000,0
+H15,2500,3150,8600,0
+H16,0,3,600,0
+H16,3,6,750,0
+H16,6,10,900,0
+H16,10,18,1100,0
+H16,18,30,1300,0
+H16,30,50,1600,0
+H16,50,80,1900,0
+H16,80,120,2200,0
+H16,120,180,2500,0
+H16,180,250,2900,0
+H16,250,315,3200,0
+H16,315,400,3600,0
+H16,400,500,4000,0
+H16,500,630,4400,0
+H16,630,800,5000,0
+H16,800,1000,5600,0
+H16,1000,1250,6600,0
+H16,1250,1600,7800,0
+H16,1600,2000,9200,0
+H16,2000,2500,11000,0
+H16,2500,3150,13500,0
+H17,0,3,1000,0
+H17,3,6,1200,0
+H17,6,10,1500,0
+H17,10,18,1800,0
+H17,18,30,2100,0
+H17,30,50,2500,0
+H17,50,80,3000,0
+H17,80,120,3500,0
+H17,120,180,4000,0
+H17,180,250,4600,0
+H17,250,315,5200,0
+H17,315,400,5700,0
+H17,400,500,6300,0
+H17,500,630,7000,0
+H17,630,800,8000,0
+H17,800,1000,9000,0
+H17,1000,1250,10500,0
+H17,1250,1600,12500,0
+H17,1600,2000,15000,0
+H17,2000,2500,17500,0
+H17,2500,3150,21000,0
+H18,0,3,1400,0
+H18,3,6,1800,0
+H18,6,10,2200,0
+H18,10,18,2700,0
+H18,18,30,3300,0
+H18,30,50,3900,0
+H18,50,80,4600,0
+H18,80,120,5400,0
+H18,120,180,6300,0
+H18,180,250,7200,0
+H18,250,315,8100,0
+H18,315,400,8900,0
+H18,400,500,9700,0
+H18,500,630,11000,0
+H18,630,800,12500,0
+H18,800,1000,14000,0
+H18,1000,1250,16500,0
+H18,1250,1600,19500,0
+H18,1600,2000,23000,0
+H18,2000,2500,28000,0
+H18,2500,3150,33000,0
+JS01,0,3,0.15,-0.15
+JS01,3,10,0.2,-0.2
+JS01,10,18,0.25,-0.25
+JS01,18,50,0.3,-0.3
+JS01,50,80,0.4,-0.4
+JS01,80,120,0.5,-0.5
+JS01,120,180,0.6,-0.6
+JS01,180,250,1,-1
+JS01,250,315,1.25,-1.25
+JS01,315,400,1.5,-1.5
+JS01,400,500,2,-2
+JS0,0,3,0.25,-0.25
+JS0,3,10,0.3,-0.3
+JS0,10,18,0.4,-0.4
+JS0,18,50,0.5,-0.5
+JS0,50,80,0.6,-0.6
+JS0,80,120,0.75,-0.75
+JS0,120,180,1,-1
+JS0,180,250,1.5,-1.5
+JS0,250,315,2,-2
+JS0,315,400,2.5,-2.5
+JS0,400,500,3,-3
+JS1,0,3,0.4,-0.4
+JS1,3,10,0.5,-0.5
+JS1,10,18,0.6,-0.6
+JS1,18,50,0.75,-0.75
+JS1,50,80,1,-1
+JS1,80,120,1.25,-1.25
+JS1,120,180,1.75,-1.75
+JS1,180,250,2.25,-2.25
+JS1,250,315,3,-3
+JS1,315,400,3.5,-3.5
+JS1,400,500,4,-4
+JS1,500,630,4.5,-4.5
+JS1,630,800,5,-5
+JS1,800,1000,5.5,-5.5
+JS1,1000,1250,6.5,-6.5
+JS1,1250,1600,7.5,-7.5
+JS1,1600,2000,9,-9
+JS1,2000,2500,11,-11
+JS1,2500,3150,13,-13
+JS2,0,3,0.6,-0.6
+JS2,3,10,0.75,-0.75
+JS2,10,18,1,-1
+JS2,18,50,1.25,-1.25
+JS2,50,80,1.5,-1.5
+JS2,80,120,2,-2
+JS2,120,180,2.5,-2.5
+JS2,180,250,3.5,-3.5
+JS2,250,315,4,-4
+JS2,315,400,4.5,-4.5
+JS2,400,500,5,-5
+JS2,500,630,5.5,-5.5
+JS2,630,800,6.5,-6.5
+JS2,800,1000,7.5,-7.5
+JS2,1000,1250,9,-9
+JS2,1250,1600,10.5,-10.5
+JS2,1600,2000,12.5,-12.5
+JS2,2000,2500,15,-15
+JS2,2500,3150,18,-18
+JS3,0,3,1,-1
+JS3,3,10,1.25,-1.25
+JS3,10,18,1.5,-1.5
+JS3,18,50,2,-2
+JS3,50,80,2.5,-2.5
+JS3,80,120,3,-3
+JS3,120,180,4,-4
+JS3,180,250,5,-5
+JS3,250,315,6,-6
+JS3,315,400,6.5,-6.5
+JS3,400,500,7.5,-7.5
+JS3,500,630,8,-8
+JS3,630,800,9,-9
+JS3,800,1000,10.5,-10.5
+JS3,1000,1250,12,-12
+JS3,1250,1600,14.5,-14.5
+JS3,1600,2000,17.5,-17.5
+JS3,2000,2500,20.5,-20.5
+JS3,2500,3150,25,-25
+JS4,0,3,1.5,-1.5
+JS4,3,10,2,-2
+JS4,10,18,2.5,-2.5
+JS4,18,30,3,-3
+JS4,30,50,3.5,-3.5
+JS4,50,80,4,-4
+JS4,80,120,5,-5
+JS4,120,180,6,-6
+JS4,180,250,7,-7
+JS4,250,315,8,-8
+JS4,315,400,9,-9
+JS4,400,500,10,-10
+JS4,500,630,11,-11
+JS4,630,800,12.5,-12.5
+JS4,800,1000,14,-14
+JS4,1000,1250,16.5,-16.5
+JS4,1250,1600,19.5,-19.5
+JS4,1600,2000,23,-23
+JS4,2000,2500,27.5,-27.5
+JS4,2500,3150,34,-34
+JS5,0,3,2,-2
+JS5,3,6,2.5,-2.5
+JS5,6,10,3,-3
+JS5,10,18,4,-4
+JS5,18,30,4.5,-4.5
+JS5,30,50,5.5,-5.5
+JS5,50,80,6.5,-6.5
+JS5,80,120,7.5,-7.5
+JS5,120,180,9,-9
+JS5,180,250,10,-10
+JS5,250,315,11.5,-11.5
+JS5,315,400,12.5,-12.5
+JS5,400,500,13.5,-13.5
+JS5,500,630,16,-16
+JS5,630,800,18,-18
+JS5,800,1000,20,-20
+JS5,1000,1250,23.5,-23.5
+JS5,1250,1600,27.5,-27.5
+JS5,1600,2000,32.5,-32.5
+JS5,2000,2500,39,-39
+JS5,2500,3150,48,-48
+JS6,0,3,3,-3
+JS6,3,6,4,-4
+JS6,6,10,4.5,-4.5
+JS6,10,18,5.5,-5.5
+JS6,18,30,6.5,-6.5
+JS6,30,50,8,-8
+JS6,50,80,9.5,-9.5
+JS6,80,120,11,-11
+JS6,120,180,12.5,-12.5
+JS6,180,250,14.5,-14.5
+JS6,250,315,16,-16
+JS6,315,400,18,-18
+JS6,400,500,20,-20
+JS6,500,630,22,-22
+JS6,630,800,25,-25
+JS6,800,1000,28,-28
+JS6,1000,1250,33,-33
+JS6,1250,1600,39,-39
+JS6,1600,2000,46,-46
+JS6,2000,2500,55,-55
+JS6,2500,3150,67.5,-67.5
+JS7,0,3,5,-5
+JS7,3,6,6,-6
+JS7,6,10,7,-7
+JS7,10,18,9,-9
+JS7,18,30,10,-10
+JS7,30,50,12,-12
+JS7,50,80,15,-15
+JS7,80,120,17,-17
+JS7,120,180,20,-20
+JS7,180,250,23,-23
+JS7,250,315,26,-26
+JS7,315,400,28,-28
+JS7,400,500,31,-31
+JS7,500,630,35,-35
+JS7,630,800,40,-40
+JS7,800,1000,45,-45
+JS7,1000,1250,52,-52
+JS7,1250,1600,62,-62
+JS7,1600,2000,75,-75
+JS7,2000,2500,87,-87
+JS7,2500,3150,105,-105
+JS8,0,3,7,-7
+JS8,3,6,9,-9
+JS8,6,10,11,-11
+JS8,10,18,13,-13
+JS8,18,30,16,-16
+JS8,30,50,19,-19
+JS8,50,80,23,-23
+JS8,80,120,27,-27
+JS8,120,180,31,-31
+JS8,180,250,36,-36
+JS8,250,315,40,-40
+JS8,315,400,44,-44
+JS8,400,500,48,-48
+JS8,500,630,55,-55
+JS8,630,800,62,-62
+JS8,800,1000,70,-70
+JS8,1000,1250,82,-82
+JS8,1250,1600,97,-97
+JS8,1600,2000,115,-115
+JS8,2000,2500,140,-140
+JS8,2500,3150,165,-165
+JS9,0,3,12,-12
+JS9,3,6,15,-15
+JS9,6,10,18,-18
+JS9,10,18,21,-21
+JS9,18,30,26,-26
+JS9,30,50,31,-31
+JS9,50,80,37,-37
+JS9,80,120,43,-43
+JS9,120,180,50,-50
+JS9,180,250,57,-57
+JS9,250,315,65,-65
+JS9,315,400,70,-70
+JS9,400,500,77,-77
+JS9,500,630,87,-87
+JS9,630,800,100,-100
+JS9,800,1000,115,-115
+JS9,1000,1250,130,-130
+JS9,1250,1600,155,-155
+JS9,1600,2000,185,-185
+JS9,2000,2500,220,-220
+JS9,2500,3150,270,-270
+JS10,0,3,20,-20
+JS10,3,6,24,-24
+JS10,6,10,29,-29
+JS10,10,18,35,-35
+JS10,18,30,42,-42
+JS10,30,50,50,-50
+JS10,50,80,60,-60
+JS10,80,120,70,-70
+JS10,120,180,80,-80
+JS10,180,250,92,-92
+JS10,250,315,105,-105
+JS10,315,400,115,-115
+JS10,400,500,125,-125
+JS10,500,630,140,-140
+JS10,630,800,160,-160
+JS10,800,1000,180,-180
+JS10,1000,1250,210,-210
+JS10,1250,1600,250,-250
+JS10,1600,2000,300,-300
+JS10,2000,2500,350,-350
+JS10,2500,3150,430,-430
+JS11,0,3,30,-30
+JS11,3,6,37,-37
+JS11,6,10,45,-45
+JS11,10,18,55,-55
+JS11,18,30,65,-65
+JS11,30,50,80,-80
+JS11,50,80,95,-95
+JS11,80,120,110,-110
+JS11,120,180,125,-125
+JS11,180,250,145,-145
+JS11,250,315,160,-160
+JS11,315,400,180,-180
+JS11,400,500,200,-200
+JS11,500,630,220,-220
+JS11,630,800,250,-250
+JS11,800,1000,280,-280
+JS11,1000,1250,330,-330
+JS11,1250,1600,390,-390
+JS11,1600,2000,460,-460
+JS11,2000,2500,550,-550
+JS11,2500,3150,675,-675
+JS12,0,3,50,-50
+JS12,3,6,60,-60
+JS12,6,10,75,-75
+JS12,10,18,90,-90
+JS12,18,30,105,-105
+JS12,30,50,125,-125
+JS12,50,80,150,-150
+JS12,80,120,175,-175
+JS12,120,180,200,-200
+JS12,180,250,230,-230
+JS12,250,315,260,-260
+JS12,315,400,285,-285
+JS12,400,500,315,-315
+JS12,500,630,350,-350
+JS12,630,800,400,-400
+JS12,800,1000,450,-450
+JS12,1000,1250,525,-525
+JS12,1250,1600,625,-625
+JS12,1600,2000,750,-750
+JS12,2000,2500,875,-875
+JS12,2500,3150,1050,-1050
+JS13,0,3,70,-70
+JS13,3,6,90,-90
+JS13,6,10,110,-110
+JS13,10,18,135,-135
+JS13,18,30,165,-165
+JS13,30,50,195,-195
+JS13,50,80,230,-230
+JS13,80,120,270,-270
+JS13,120,180,315,-315
+JS13,180,250,360,-360
+JS13,250,315,405,-405
+JS13,315,400,445,-445
+JS13,400,500,485,-485
+JS13,500,630,550,-550
+JS13,630,800,625,-625
+JS13,800,1000,700,-700
+JS13,1000,1250,825,-825
+JS13,1250,1600,975,-975
+JS13,1600,2000,1150,-1150
+JS13,2000,2500,1400,-1400
+JS13,2500,3150,1650,-1650
+JS14,0,3,125,-125
+JS14,3,6,150,-150
+JS14,6,10,180,-180
+JS14,10,18,215,-215
+JS14,18,30,260,-260
+JS14,30,50,310,-310
+JS14,50,80,370,-370
+JS14,80,120,435,-435
+JS14,120,180,500,-500
+JS14,180,250,575,-575
+JS14,250,315,650,-650
+JS14,315,400,700,-700
+JS14,400,500,775,-775
+JS14,500,630,875,-875
+JS14,630,800,1000,-1000
+JS14,800,1000,1150,-1150
+JS14,1000,1250,1300,-1300
+JS14,1250,1600,1550,-1550
+JS14,1600,2000,1850,-1850
+JS14,2000,2500,2200,-2200
+JS14,2500,3150,2700,-2700
+JS15,0,3,200,-200
+JS15,3,6,240,-240
+JS15,6,10,290,-290
+JS15,10,18,350,-350
+JS15,18,30,420,-420
+JS15,30,50,500,-500
+JS15,50,80,600,-600
+JS15,80,120,700,-700
+JS15,120,180,800,-800
+JS15,180,250,925,-925
+JS15,250,315,1050,-1050
+JS15,315,400,1150,-1150
+JS15,400,500,1250,-1250
+JS15,500,630,1400,-1400
+JS15,630,800,1600,-1600
+JS15,800,1000,1800,-1800
+JS15,1000,1250,2100,-2100
+JS15,1250,1600,2500,-2500
+JS15,1600,2000,3000,-3000
+JS15,2000,2500,3500,-3500
+JS15,2500,3150,4300,-4300
+JS16,0,3,300,-300
+JS16,3,6,375,-375
+JS16,6,10,450,-450
+JS16,10,18,550,-550
+JS16,18,30,650,-650
+JS16,30,50,800,-800
+JS16,50,80,950,-950
+JS16,80,120,1100,-1100
+JS16,120,180,1250,-1250
+JS16,180,250,1450,-1450
+JS16,250,315,1600,-1600
+JS16,315,400,1800,-1800
+JS16,400,500,2000,-2000
+JS16,500,630,2200,-2200
+JS16,630,800,2500,-2500
+JS16,800,1000,2800,-2800
+JS16,1000,1250,3300,-3300
+JS16,1250,1600,3900,-3900
+JS16,1600,2000,4600,-4600
+JS16,2000,2500,5500,-5500
+JS16,2500,3150,6750,-6750
+JS17,0,3,500,-500
+JS17,3,6,600,-600
+JS17,6,10,750,-750
+JS17,10,18,900,-900
+JS17,18,30,1050,-1050
+JS17,30,50,1250,-1250
+JS17,50,80,1500,-1500
+JS17,80,120,1750,-1750
+JS17,120,180,2000,-2000
+JS17,180,250,2300,-2300
+JS17,250,315,2600,-2600
+JS17,315,400,2850,-2850
+JS17,400,500,3150,-3150
+JS17,500,630,3500,-3500
+JS17,630,800,4000,-4000
+JS17,800,1000,4500,-4500
+JS17,1000,1250,5250,-5250
+JS17,1250,1600,6250,-6250
+JS17,1600,2000,7500,-7500
+JS17,2000,2500,8750,-8750
+JS17,2500,3150,10500,-10500
+JS18,0,3,700,-700
+JS18,3,6,900,-900
+JS18,6,10,1100,-1100
+JS18,10,18,1350,-1350
+JS18,18,30,1650,-1650
+JS18,30,50,1950,-1950
+JS18,50,80,2300,-2300
+JS18,80,120,2700,-2700
+JS18,120,180,3150,-3150
+JS18,180,250,3600,-3600
+JS18,250,315,4050,-4050
+JS18,315,400,4450,-4450
+JS18,400,500,4850,-4850
+JS18,500,630,5500,-5500
+JS18,630,800,6250,-6250
+JS18,800,1000,7000,-7000
+JS18,1000,1250,8250,-8250
+JS18,1250,1600,9750,-9750
+JS18,1600,2000,11500,-11500
+JS18,2000,2500,14000,-14000
+JS18,2500,3150,16500,-16500
+J6,0,3,2,-4
+J6,3,6,5,-3
+J6,6,10,5,-4
+J6,10,18,6,-5
+J6,18,30,8,-5
+J6,30,50,10,-6
+J6,50,80,13,-6
+J6,80,120,16,-6
+J6,120,180,18,-7
+J6,180,250,22,-7
+J6,250,315,25,-7
+J6,315,400,29,-7
+J6,400,500,33,-7
+J7,0,3,4,-6
+J7,3,6,6,-6
+J7,6,10,8,-7
+J7,10,18,10,-8
+J7,18,30,12,-9
+J7,30,50,14,-11
+J7,50,80,18,-12
+J7,80,120,22,-13
+J7,120,180,26,-14
+J7,180,250,30,-16
+J7,250,315,36,-16
+J7,315,400,39,-18
+J7,400,500,43,-20
+J8,0,3,6,-8
+J8,3,6,10,-8
+J8,6,10,12,-10
+J8,10,18,15,-12
+J8,18,30,20,-13
+J8,30,50,24,-15
+J8,50,80,28,-18
+J8,80,120,34,-20
+J8,120,180,41,-22
+J8,180,250,47,-25
+J8,250,315,55,-26
+J8,315,400,60,-29
+J8,400,500,66,-31
+K3,0,3,0,-2
+K3,3,10,0,-2.5
+K3,10,18,0,-3
+K3,18,50,-0.5,-4.5
+K3,50,80,0,-5
+K3,80,120,-1,-7
+K3,120,180,0,-8
+K3,180,250,-1,-11
+K3,250,315,0,-12
+K3,315,400,0,-13
+K3,400,500,0,-15
+K3,500,630,0,-16
+K3,630,800,0,-18
+K3,800,1000,0,-21
+K3,1000,1250,0,-24
+K3,1250,1600,0,-29
+K3,1600,2000,0,-35
+K3,2000,2500,0,-41
+K3,2500,3150,0,-50
+K4,0,3,0,-3
+K4,3,10,0.5,-3.5
+K4,10,18,1,-4
+K4,18,30,0,-6
+K4,30,50,1,-6
+K4,50,80,1,-7
+K4,80,120,1,-9
+K4,120,180,1,-11
+K4,180,250,0,-14
+K4,250,315,0,-16
+K4,315,400,1,-17
+K4,400,500,0,-20
+K4,500,630,0,-22
+K4,630,800,0,-25
+K4,800,1000,0,-28
+K4,1000,1250,0,-33
+K4,1250,1600,0,-39
+K4,1600,2000,0,-46
+K4,2000,2500,0,-55
+K4,2500,3150,0,-68
+K5,0,3,0,-4
+K5,3,6,0,-5
+K5,6,10,1,-5
+K5,10,18,2,-6
+K5,18,30,1,-8
+K5,30,50,2,-9
+K5,50,80,3,-10
+K5,80,120,2,-13
+K5,120,180,3,-15
+K5,180,250,2,-18
+K5,250,315,3,-20
+K5,315,400,3,-22
+K5,400,500,2,-25
+K5,500,630,0,-32
+K5,630,800,0,-36
+K5,800,1000,0,-40
+K5,1000,1250,0,-47
+K5,1250,1600,0,-55
+K5,1600,2000,0,-65
+K5,2000,2500,0,-78
+K5,2500,3150,0,-96
+K6,0,3,0,-6
+K6,3,6,2,-6
+K6,6,10,2,-7
+K6,10,18,2,-9
+K6,18,30,2,-11
+K6,30,50,3,-13
+K6,50,80,4,-15
+K6,80,120,4,-18
+K6,120,180,4,-21
+K6,180,250,5,-24
+K6,250,315,5,-27
+K6,315,400,7,-29
+K6,400,500,8,-32
+K6,500,630,0,-44
+K6,630,800,0,-50
+K6,800,1000,0,-56
+K6,1000,1250,0,-66
+K6,1250,1600,0,-78
+K6,1600,2000,0,-92
+K6,2000,2500,0,-110
+K6,2500,3150,0,-135
+K7,0,3,0,-10
+K7,3,6,3,-9
+K7,6,10,5,-10
+K7,10,18,6,-12
+K7,18,30,6,-15
+K7,30,50,7,-18
+K7,50,80,9,-21
+K7,80,120,10,-25
+K7,120,180,12,-28
+K7,180,250,13,-33
+K7,250,315,16,-36
+K7,315,400,17,-40
+K7,400,500,18,-45
+K7,500,630,0,-70
+K7,630,800,0,-80
+K7,800,1000,0,-90
+K7,1000,1250,0,-105
+K7,1250,1600,0,-125
+K7,1600,2000,0,-150
+K7,2000,2500,0,-175
+K7,2500,3150,0,-210
+K8,0,3,0,-14
+K8,3,6,5,-13
+K8,6,10,6,-16
+K8,10,18,8,-19
+K8,18,30,10,-23
+K8,30,50,12,-27
+K8,50,80,14,-32
+K8,80,120,16,-38
+K8,120,180,20,-43
+K8,180,250,22,-50
+K8,250,315,25,-56
+K8,315,400,28,-61
+K8,400,500,29,-68
+K8,500,630,0,-110
+K8,630,800,0,-125
+K8,800,1000,0,-140
+K8,1000,1250,0,-165
+K8,1250,1600,0,-195
+K8,1600,2000,0,-230
+K8,2000,2500,0,-280
+K8,2500,3150,0,-330
+K9,0,3,0,-25
+K10,0,3,0,-40
+M3,0,3,-2,-4
+M3,3,6,-3,-5.5
+M3,6,10,-5,-7.5
+M3,10,18,-6,-9
+M3,18,30,-6.5,-10.5
+M3,30,50,-7.5,-11.5
+M3,50,80,-9,-14
+M3,80,120,-11,-17
+M3,120,180,-12,-20
+M3,180,250,-14,-24
+M3,250,315,-16,-28
+M3,315,400,-17,-30
+M3,400,500,-18,-33
+M3,500,630,-26,-42
+M3,630,800,-30,-48
+M3,800,1000,-34,-55
+M3,1000,1250,-40,-64
+M3,1250,1600,-48,-77
+M3,1600,2000,-58,-93
+M3,2000,2500,-68,-109
+M3,2500,3150,-76,-126
+M4,0,3,-2,-5
+M4,3,6,-2.5,-6.5
+M4,6,10,-4.5,-8.5
+M4,10,18,-5,-10
+M4,18,30,-6,-12
+M4,30,50,-6,-13
+M4,50,80,-8,-16
+M4,80,120,-9,-19
+M4,120,180,-11,-23
+M4,180,250,-13,-27
+M4,250,315,-16,-32
+M4,315,400,-16,-34
+M4,400,500,-18,-38
+M4,500,630,-26,-48
+M4,630,800,-30,-55
+M4,800,1000,-34,-62
+M4,1000,1250,-40,-73
+M4,1250,1600,-48,-87
+M4,1600,2000,-58,-104
+M4,2000,2500,-68,-123
+M4,2500,3150,-76,-144
+M5,0,3,-2,-6
+M5,3,6,-3,-8
+M5,6,10,-4,-10
+M5,10,18,-4,-12
+M5,18,30,-5,-14
+M5,30,50,-5,-16
+M5,50,80,-6,-19
+M5,80,120,-8,-23
+M5,120,180,-9,-27
+M5,180,250,-11,-31
+M5,250,315,-13,-36
+M5,315,400,-14,-39
+M5,400,500,-16,-43
+M5,500,630,-26,-58
+M5,630,800,-30,-66
+M5,800,1000,-34,-74
+M5,1000,1250,-40,-87
+M5,1250,1600,-48,-103
+M5,1600,2000,-58,-123
+M5,2000,2500,-68,-146
+M5,2500,3150,-76,-172
+M6,0,3,-2,-8
+M6,3,6,-1,-9
+M6,6,10,-3,-12
+M6,10,18,-4,-15
+M6,18,30,-4,-17
+M6,30,50,-4,-20
+M6,50,80,-5,-24
+M6,80,120,-6,-28
+M6,120,180,-8,-33
+M6,180,250,-8,-37
+M6,250,315,-9,-41
+M6,315,400,-10,-46
+M6,400,500,-10,-50
+M6,500,630,-26,-70
+M6,630,800,-30,-80
+M6,800,1000,-34,-90
+M6,1000,1250,-40,-106
+M6,1250,1600,-48,-126
+M6,1600,2000,-58,-150
+M6,2000,2500,-68,-178
+M6,2500,3150,-76,-211
+M7,0,3,-2,-12
+M7,3,6,0,-12
+M7,6,10,0,-15
+M7,10,18,0,-18
+M7,18,30,0,-21
+M7,30,50,0,-25
+M7,50,80,0,-30
+M7,80,120,0,-35
+M7,120,180,0,-40
+M7,180,250,0,-46
+M7,250,315,0,-52
+M7,315,400,0,-57
+M7,400,500,0,-63
+M7,500,630,-26,-96
+M7,630,800,-30,-110
+M7,800,1000,-34,-124
+M7,1000,1250,-40,-145
+M7,1250,1600,-48,-173
+M7,1600,2000,-58,-208
+M7,2000,2500,-68,-243
+M7,2500,3150,-76,-286
+M8,0,3,-2,-16
+M8,3,6,2,-16
+M8,6,10,1,-21
+M8,10,18,2,-25
+M8,18,30,4,-29
+M8,30,50,5,-34
+M8,50,80,5,-41
+M8,80,120,6,-48
+M8,120,180,8,-55
+M8,180,250,9,-63
+M8,250,315,9,-72
+M8,315,400,11,-78
+M8,400,500,11,-86
+M8,500,630,-26,-136
+M8,630,800,-30,-155
+M8,800,1000,-34,-174
+M8,1000,1250,-40,-205
+M8,1250,1600,-48,-243
+M8,1600,2000,-58,-288
+M8,2000,2500,-68,-348
+M8,2500,3150,-76,-406
+M9,0,3,-2,-27
+M9,3,6,-4,-34
+M9,6,10,-6,-42
+M9,10,18,-7,-50
+M9,18,30,-8,-60
+M9,30,50,-9,-71
+M9,50,80,-11,-85
+M9,80,120,-13,-100
+M9,120,180,-15,-115
+M9,180,250,-17,-132
+M9,250,315,-20,-150
+M9,315,400,-21,-161
+M9,400,500,-23,-178
+M9,500,630,-26,-201
+M9,630,800,-30,-230
+M9,800,1000,-34,-264
+M9,1000,1250,-40,-300
+M9,1250,1600,-48,-358
+M9,1600,2000,-58,-428
+M9,2000,2500,-68,-508
+M9,2500,3150,-76,-616
+M10,0,3,-2,-42
+M10,3,6,-4,-52
+M10,6,10,-6,-64
+M10,10,18,-7,-77
+M10,18,30,-8,-92
+M10,30,50,-9,-109
+M10,50,80,-11,-131
+M10,80,120,-13,-153
+M10,120,180,-15,-175
+M10,180,250,-17,-202
+M10,250,315,-20,-230
+M10,315,400,-21,-251
+M10,400,500,-23,-273
+M10,500,630,-26,-306
+M10,630,800,-30,-350
+M10,800,1000,-34,-394
+M10,1000,1250,-40,-460
+M10,1250,1600,-48,-548
+M10,1600,2000,-58,-658
+M10,2000,2500,-68,-768
+M10,2500,3150,-76,-936
+N3,0,3,-4,-6
+N3,3,6,-7,-9.5
+N3,6,10,-9,-11.5
+N3,10,18,-11,-14
+N3,18,30,-13.5,-17.5
+N3,30,50,-15.5,-19.5
+N3,50,80,-18,-23
+N3,80,120,-21,-27
+N3,120,180,-24,-32
+N3,180,250,-28,-38
+N3,250,315,-30,-42
+N3,315,400,-33,-46
+N3,400,500,-35,-50
+N3,500,630,-44,-60
+N3,630,800,-50,-68
+N3,800,1000,-56,-77
+N3,1000,1250,-66,-90
+N3,1250,1600,-78,-107
+N3,1600,2000,-92,-127
+N3,2000,2500,-110,-151
+N3,2500,3150,-135,-185
+N4,0,3,-4,-7
+N4,3,6,-6.5,-10.5
+N4,6,10,-8.5,-12.5
+N4,10,18,-10,-15
+N4,18,30,-13,-19
+N4,30,50,-14,-21
+N4,50,80,-17,-25
+N4,80,120,-19,-29
+N4,120,180,-23,-35
+N4,180,250,-27,-41
+N4,250,315,-30,-46
+N4,315,400,-32,-50
+N4,400,500,-35,-55
+N4,500,630,-44,-66
+N4,630,800,-50,-75
+N4,800,1000,-56,-84
+N4,1000,1250,-66,-99
+N4,1250,1600,-78,-117
+N4,1600,2000,-92,-138
+N4,2000,2500,-110,-165
+N4,2500,3150,-135,-203
+N5,0,3,-4,-8
+N5,3,6,-7,-12
+N5,6,10,-8,-14
+N5,10,18,-9,-17
+N5,18,30,-12,-21
+N5,30,50,-13,-24
+N5,50,80,-15,-28
+N5,80,120,-18,-33
+N5,120,180,-21,-39
+N5,180,250,-25,-45
+N5,250,315,-27,-50
+N5,315,400,-30,-55
+N5,400,500,-33,-60
+N5,500,630,-44,-76
+N5,630,800,-50,-86
+N5,800,1000,-56,-96
+N5,1000,1250,-66,-113
+N5,1250,1600,-78,-133
+N5,1600,2000,-92,-157
+N5,2000,2500,-110,-188
+N5,2500,3150,-135,-231
+N6,0,3,-4,-10
+N6,3,6,-5,-13
+N6,6,10,-7,-16
+N6,10,18,-9,-20
+N6,18,30,-11,-24
+N6,30,50,-12,-28
+N6,50,80,-14,-33
+N6,80,120,-16,-38
+N6,120,180,-20,-45
+N6,180,250,-22,-51
+N6,250,315,-25,-57
+N6,315,400,-26,-62
+N6,400,500,-27,-67
+N6,500,630,-44,-88
+N6,630,800,-50,-100
+N6,800,1000,-56,-112
+N6,1000,1250,-66,-132
+N6,1250,1600,-78,-156
+N6,1600,2000,-92,-184
+N6,2000,2500,-110,-220
+N6,2500,3150,-135,-270
+N7,0,3,-4,-14
+N7,3,6,-4,-16
+N7,6,10,-4,-19
+N7,10,18,-5,-23
+N7,18,30,-7,-28
+N7,30,50,-8,-33
+N7,50,80,-9,-39
+N7,80,120,-10,-45
+N7,120,180,-12,-52
+N7,180,250,-14,-60
+N7,250,315,-14,-66
+N7,315,400,-16,-73
+N7,400,500,-17,-80
+N7,500,630,-44,-114
+N7,630,800,-50,-130
+N7,800,1000,-56,-146
+N7,1000,1250,-66,-171
+N7,1250,1600,-78,-203
+N7,1600,2000,-92,-242
+N7,2000,2500,-110,-285
+N7,2500,3150,-135,-345
+N8,0,3,-4,-18
+N8,3,6,-2,-20
+N8,6,10,-3,-25
+N8,10,18,-3,-30
+N8,18,30,-3,-36
+N8,30,50,-3,-42
+N8,50,80,-4,-50
+N8,80,120,-4,-58
+N8,120,180,-4,-67
+N8,180,250,-5,-77
+N8,250,315,-5,-86
+N8,315,400,-5,-94
+N8,400,500,-6,-103
+N8,500,630,-44,-154
+N8,630,800,-50,-175
+N8,800,1000,-56,-196
+N8,1000,1250,-66,-231
+N8,1250,1600,-78,-273
+N8,1600,2000,-92,-322
+N8,2000,2500,-110,-390
+N8,2500,3150,-135,-465
+N9,0,3,-4,-29
+N9,3,6,0,-30
+N9,6,10,0,-36
+N9,10,18,0,-43
+N9,18,30,0,-52
+N9,30,50,0,-62
+N9,50,80,0,-74
+N9,80,120,0,-87
+N9,120,180,0,-100
+N9,180,250,0,-115
+N9,250,315,0,-130
+N9,315,400,0,-140
+N9,400,500,0,-155
+N10,0,3,-4,-44
+N10,3,6,0,-48
+N10,6,10,0,-58
+N10,10,18,0,-70
+N10,18,30,0,-84
+N10,30,50,0,-100
+N10,50,80,0,-120
+N10,80,120,0,-140
+N10,120,180,0,-160
+N10,180,250,0,-185
+N10,250,315,0,-210
+N10,315,400,0,-230
+N10,400,500,0,-250
+N11,0,3,-4,-64
+N11,3,6,0,-75
+N11,6,10,0,-90
+N11,10,18,0,-110
+N11,18,30,0,-130
+N11,30,50,0,-160
+N11,50,80,0,-190
+N11,80,120,0,-220
+N11,120,180,0,-250
+N11,180,250,0,-290
+N11,250,315,0,-320
+N11,315,400,0,-360
+N11,400,500,0,-400
+P3,0,3,-6,-8
+P3,3,6,-11,-13.5
+P3,6,10,-14,-16.5
+P3,10,18,-17,-20
+P3,18,30,-20.5,-24.5
+P3,30,50,-24.5,-28.5
+P3,50,80,-30,-35
+P3,80,120,-35,-41
+P3,120,180,-40,-48
+P3,180,250,-47,-57
+P3,250,315,-52,-64
+P3,315,400,-58,-71
+P3,400,500,-63,-78
+P3,500,630,-78,-94
+P3,630,800,-88,-106
+P3,800,1000,-100,-121
+P3,1000,1250,-120,-144
+P3,1250,1600,-140,-169
+P3,1600,2000,-170,-205
+P3,2000,2500,-195,-236
+P3,2500,3150,-240,-290
+P4,0,3,-6,-9
+P4,3,6,-10.5,-14.5
+P4,6,10,-13.5,-17.5
+P4,10,18,-16,-21
+P4,18,30,-20,-26
+P4,30,50,-23,-30
+P4,50,80,-29,-37
+P4,80,120,-33,-43
+P4,120,180,-39,-51
+P4,180,250,-46,-60
+P4,250,315,-52,-68
+P4,315,400,-57,-75
+P4,400,500,-63,-83
+P4,500,630,-78,-100
+P4,630,800,-88,-113
+P4,800,1000,-100,-128
+P4,1000,1250,-120,-153
+P4,1250,1600,-140,-179
+P4,1600,2000,-170,-216
+P4,2000,2500,-195,-250
+P4,2500,3150,-240,-308
+P5,0,3,-6,-10
+P5,3,6,-11,-16
+P5,6,10,-13,-19
+P5,10,18,-15,-23
+P5,18,30,-19,-28
+P5,30,50,-22,-33
+P5,50,80,-27,-40
+P5,80,120,-32,-47
+P5,120,180,-37,-55
+P5,180,250,-44,-64
+P5,250,315,-49,-72
+P5,315,400,-55,-80
+P5,400,500,-61,-88
+P5,500,630,-78,-110
+P5,630,800,-88,-124
+P5,800,1000,-100,-140
+P5,1000,1250,-120,-167
+P5,1250,1600,-140,-195
+P5,1600,2000,-170,-235
+P5,2000,2500,-195,-273
+P5,2500,3150,-240,-336
+P6,0,3,-6,-12
+P6,3,6,-9,-17
+P6,6,10,-12,-21
+P6,10,18,-15,-26
+P6,18,30,-18,-31
+P6,30,50,-21,-37
+P6,50,80,-26,-45
+P6,80,120,-30,-52
+P6,120,180,-36,-61
+P6,180,250,-41,-70
+P6,250,315,-47,-79
+P6,315,400,-51,-87
+P6,400,500,-55,-95
+P6,500,630,-78,-122
+P6,630,800,-88,-138
+P6,800,1000,-100,-156
+P6,1000,1250,-120,-186
+P6,1250,1600,-140,-218
+P6,1600,2000,-170,-262
+P6,2000,2500,-195,-305
+P6,2500,3150,-240,-375
+P7,0,3,-6,-16
+P7,3,6,-8,-20
+P7,6,10,-9,-24
+P7,10,18,-11,-29
+P7,18,30,-14,-35
+P7,30,50,-17,-42
+P7,50,80,-21,-51
+P7,80,120,-24,-59
+P7,120,180,-28,-68
+P7,180,250,-33,-79
+P7,250,315,-36,-88
+P7,315,400,-41,-98
+P7,400,500,-45,-108
+P7,500,630,-78,-148
+P7,630,800,-88,-168
+P7,800,1000,-100,-190
+P7,1000,1250,-120,-225
+P7,1250,1600,-140,-265
+P7,1600,2000,-170,-320
+P7,2000,2500,-195,-370
+P7,2500,3150,-240,-450
+P8,0,3,-6,-20
+P8,3,6,-12,-30
+P8,6,10,-15,-37
+P8,10,18,-18,-45
+P8,18,30,-22,-55
+P8,30,50,-26,-65
+P8,50,80,-32,-78
+P8,80,120,-37,-91
+P8,120,180,-43,-106
+P8,180,250,-50,-122
+P8,250,315,-56,-137
+P8,315,400,-62,-151
+P8,400,500,-68,-165
+P8,500,630,-78,-188
+P8,630,800,-88,-213
+P8,800,1000,-100,-240
+P8,1000,1250,-120,-285
+P8,1250,1600,-140,-335
+P8,1600,2000,-170,-400
+P8,2000,2500,-195,-475
+P8,2500,3150,-240,-570
+P9,0,3,-6,-31
+P9,3,6,-12,-42
+P9,6,10,-15,-51
+P9,10,18,-18,-61
+P9,18,30,-22,-74
+P9,30,50,-26,-88
+P9,50,80,-32,-106
+P9,80,120,-37,-124
+P9,120,180,-43,-143
+P9,180,250,-50,-165
+P9,250,315,-56,-186
+P9,315,400,-62,-202
+P9,400,500,-68,-223
+P9,500,630,-78,-253
+P9,630,800,-88,-288
+P9,800,1000,-100,-330
+P9,1000,1250,-120,-380
+P9,1250,1600,-140,-450
+P9,1600,2000,-170,-540
+P9,2000,2500,-195,-635
+P9,2500,3150,-240,-780
+P10,0,3,-6,-46
+P10,3,6,-12,-60
+P10,6,10,-15,-73
+P10,10,18,-18,-88
+P10,18,30,-22,-106
+P10,30,50,-26,-126
+P10,50,80,-32,-152
+P10,80,120,-37,-177
+P10,120,180,-43,-203
+P10,180,250,-50,-235
+P10,250,315,-56,-266
+P10,315,400,-62,-292
+P10,400,500,-68,-318
+P10,500,630,-78,-358
+P10,630,800,-88,-408
+P10,800,1000,-100,-460
+P10,1000,1250,-120,-540
+P10,1250,1600,-140,-640
+P10,1600,2000,-170,-770
+P10,2000,2500,-195,-895
+P10,2500,3150,-240,-1100
+R3,0,3,-10,-12
+R3,3,6,-14,-16.5
+R3,6,10,-18,-20.5
+R3,10,18,-22,-25
+R3,18,30,-26.5,-30.5
+R3,30,50,-32.5,-36.5
+R3,50,65,-39,-44
+R3,65,80,-41,-46
+R3,80,100,-49,-55
+R3,100,120,-52,-58
+R3,120,140,-60,-68
+R3,140,160,-62,-70
+R3,160,180,-65,-73
+R3,180,200,-74,-84
+R3,200,225,-77,-87
+R3,225,250,-81,-91
+R3,250,280,-90,-102
+R3,280,315,-94,-106
+R3,315,355,-104,-117
+R3,355,400,-110,-123
+R3,400,450,-121,-136
+R3,450,500,-127,-142
+R3,500,560,-150,-166
+R3,560,630,-155,-171
+R3,630,710,-175,-193
+R3,710,800,-185,-203
+R3,800,900,-210,-231
+R3,900,1000,-220,-241
+R3,1000,1120,-250,-274
+R3,1120,1250,-260,-284
+R3,1250,1400,-300,-329
+R3,1400,1600,-330,-359
+R3,1600,1800,-370,-405
+R3,1800,2000,-400,-435
+R3,2000,2240,-440,-481
+R3,2240,2500,-460,-501
+R3,2500,2800,-550,-600
+R3,2800,3150,-580,-630
+R4,0,3,-10,-13
+R4,3,6,-13.5,-17.5
+R4,6,10,-17.5,-21.5
+R4,10,18,-21,-26
+R4,18,30,-26,-32
+R4,30,50,-31,-38
+R4,50,65,-38,-46
+R4,65,80,-40,-48
+R4,80,100,-47,-57
+R4,100,120,-50,-60
+R4,120,140,-59,-71
+R4,140,160,-61,-73
+R4,160,180,-64,-76
+R4,180,200,-73,-87
+R4,200,225,-76,-90
+R4,225,250,-80,-94
+R4,250,280,-90,-106
+R4,280,315,-94,-110
+R4,315,355,-103,-121
+R4,355,400,-109,-127
+R4,400,450,-121,-141
+R4,450,500,-127,-147
+R4,500,560,-150,-172
+R4,560,630,-155,-177
+R4,630,710,-175,-200
+R4,710,800,-185,-210
+R4,800,900,-210,-238
+R4,900,1000,-220,-248
+R4,1000,1120,-250,-283
+R4,1120,1250,-260,-293
+R4,1250,1400,-300,-339
+R4,1400,1600,-330,-369
+R4,1600,1800,-370,-416
+R4,1800,2000,-400,-446
+R4,2000,2240,-440,-495
+R4,2240,2500,-460,-515
+R4,2500,2800,-550,-618
+R4,2800,3150,-580,-648
+R5,0,3,-10,-14
+R5,3,6,-14,-19
+R5,6,10,-17,-23
+R5,10,18,-20,-28
+R5,18,30,-25,-34
+R5,30,50,-30,-41
+R5,50,65,-36,-49
+R5,65,80,-38,-51
+R5,80,100,-46,-61
+R5,100,120,-49,-64
+R5,120,140,-57,-75
+R5,140,160,-59,-77
+R5,160,180,-62,-80
+R5,180,200,-71,-91
+R5,200,225,-74,-94
+R5,225,250,-78,-98
+R5,250,280,-87,-110
+R5,280,315,-91,-114
+R5,315,355,-101,-126
+R5,355,400,-107,-132
+R5,400,450,-119,-146
+R5,450,500,-125,-152
+R5,500,560,-150,-182
+R5,560,630,-155,-187
+R5,630,710,-175,-211
+R5,710,800,-185,-221
+R5,800,900,-210,-250
+R5,900,1000,-220,-260
+R5,1000,1120,-250,-297
+R5,1120,1250,-260,-307
+R5,1250,1400,-300,-355
+R5,1400,1600,-330,-385
+R5,1600,1800,-370,-435
+R5,1800,2000,-400,-465
+R5,2000,2240,-440,-518
+R5,2240,2500,-460,-538
+R5,2500,2800,-550,-646
+R5,2800,3150,-580,-676
+R6,0,3,-10,-16
+R6,3,6,-12,-20
+R6,6,10,-16,-25
+R6,10,18,-20,-31
+R6,18,30,-24,-37
+R6,30,50,-29,-45
+R6,50,65,-35,-54
+R6,65,80,-37,-56
+R6,80,100,-44,-66
+R6,100,120,-47,-69
+R6,120,140,-56,-81
+R6,140,160,-58,-83
+R6,160,180,-61,-86
+R6,180,200,-68,-97
+R6,200,225,-71,-100
+R6,225,250,-75,-104
+R6,250,280,-85,-117
+R6,280,315,-89,-121
+R6,315,355,-97,-133
+R6,355,400,-103,-139
+R6,400,450,-113,-153
+R6,450,500,-119,-159
+R6,500,560,-150,-194
+R6,560,630,-155,-199
+R6,630,710,-175,-225
+R6,710,800,-185,-235
+R6,800,900,-210,-266
+R6,900,1000,-220,-276
+R6,1000,1120,-250,-316
+R6,1120,1250,-260,-326
+R6,1250,1400,-300,-378
+R6,1400,1600,-330,-408
+R6,1600,1800,-370,-462
+R6,1800,2000,-400,-492
+R6,2000,2240,-440,-550
+R6,2240,2500,-460,-570
+R6,2500,2800,-550,-685
+R6,2800,3150,-580,-715
+R7,0,3,-10,-20
+R7,3,6,-11,-23
+R7,6,10,-13,-28
+R7,10,18,-16,-34
+R7,18,30,-20,-41
+R7,30,50,-25,-50
+R7,50,65,-30,-60
+R7,65,80,-32,-62
+R7,80,100,-38,-73
+R7,100,120,-41,-76
+R7,120,140,-48,-88
+R7,140,160,-50,-90
+R7,160,180,-53,-93
+R7,180,200,-60,-106
+R7,200,225,-63,-109
+R7,225,250,-67,-113
+R7,250,280,-74,-126
+R7,280,315,-78,-130
+R7,315,355,-87,-144
+R7,355,400,-93,-150
+R7,400,450,-103,-166
+R7,450,500,-109,-172
+R7,500,560,-150,-220
+R7,560,630,-155,-225
+R7,630,710,-175,-255
+R7,710,800,-185,-265
+R7,800,900,-210,-300
+R7,900,1000,-220,-310
+R7,1000,1120,-250,-355
+R7,1120,1250,-260,-365
+R7,1250,1400,-300,-425
+R7,1400,1600,-330,-455
+R7,1600,1800,-370,-520
+R7,1800,2000,-400,-550
+R7,2000,2240,-440,-615
+R7,2240,2500,-460,-635
+R7,2500,2800,-550,-760
+R7,2800,3150,-580,-790
+R8,0,3,-10,-24
+R8,3,6,-15,-33
+R8,6,10,-19,-41
+R8,10,18,-23,-50
+R8,18,30,-28,-61
+R8,30,50,-34,-73
+R8,50,65,-41,-87
+R8,65,80,-43,-89
+R8,80,100,-51,-105
+R8,100,120,-54,-108
+R8,120,140,-63,-126
+R8,140,160,-65,-128
+R8,160,180,-68,-131
+R8,180,200,-77,-149
+R8,200,225,-80,-152
+R8,225,250,-84,-156
+R8,250,280,-94,-175
+R8,280,315,-98,-179
+R8,315,355,-108,-197
+R8,355,400,-114,-203
+R8,400,450,-126,-223
+R8,450,500,-132,-229
+R8,500,560,-150,-260
+R8,560,630,-155,-265
+R8,630,710,-175,-300
+R8,710,800,-185,-310
+R8,800,900,-210,-350
+R8,900,1000,-220,-360
+R8,1000,1120,-250,-415
+R8,1120,1250,-260,-425
+R8,1250,1400,-300,-495
+R8,1400,1600,-330,-525
+R8,1600,1800,-370,-600
+R8,1800,2000,-400,-630
+R8,2000,2240,-440,-720
+R8,2240,2500,-460,-740
+R8,2500,2800,-550,-880
+R8,2800,3150,-580,-910
+R9,0,3,-10,-35
+R9,3,6,-15,-45
+R9,6,10,-19,-55
+R9,10,18,-23,-66
+R9,18,30,-28,-80
+R9,30,50,-34,-96
+R9,50,65,-41,-115
+R9,65,80,-43,-117
+R9,80,100,-51,-138
+R9,100,120,-54,-141
+R9,120,140,-63,-163
+R9,140,160,-65,-165
+R9,160,180,-68,-168
+R9,180,200,-77,-192
+R9,200,225,-80,-195
+R9,225,250,-84,-199
+R9,250,280,-94,-224
+R9,280,315,-98,-228
+R9,315,355,-108,-248
+R9,355,400,-114,-254
+R9,400,450,-126,-281
+R9,450,500,-132,-287
+R9,500,560,-150,-325
+R9,560,630,-155,-330
+R9,630,710,-175,-375
+R9,710,800,-185,-385
+R9,800,900,-210,-440
+R9,900,1000,-220,-450
+R9,1000,1120,-250,-510
+R9,1120,1250,-260,-520
+R9,1250,1400,-300,-610
+R9,1400,1600,-330,-640
+R9,1600,1800,-370,-740
+R9,1800,2000,-400,-770
+R9,2000,2240,-440,-880
+R9,2240,2500,-460,-900
+R9,2500,2800,-550,-1090
+R9,2800,3150,-580,-1120
+R10,0,3,-10,-50
+R10,3,6,-15,-63
+R10,6,10,-19,-77
+R10,10,18,-23,-93
+R10,18,30,-28,-112
+R10,30,50,-34,-134
+R10,50,65,-41,-161
+R10,65,80,-43,-163
+R10,80,100,-51,-191
+R10,100,120,-54,-194
+R10,120,140,-63,-223
+R10,140,160,-65,-225
+R10,160,180,-68,-228
+R10,180,200,-77,-262
+R10,200,225,-80,-265
+R10,225,250,-84,-269
+R10,250,280,-94,-304
+R10,280,315,-98,-308
+R10,315,355,-108,-338
+R10,355,400,-114,-344
+R10,400,450,-126,-376
+R10,450,500,-132,-382
+R10,500,560,-150,-430
+R10,560,630,-155,-435
+R10,630,710,-175,-495
+R10,710,800,-185,-505
+R10,800,900,-210,-570
+R10,900,1000,-220,-580
+R10,1000,1120,-250,-670
+R10,1120,1250,-260,-680
+R10,1250,1400,-300,-800
+R10,1400,1600,-330,-830
+R10,1600,1800,-370,-970
+R10,1800,2000,-400,-1000
+R10,2000,2240,-440,-1140
+R10,2240,2500,-460,-1160
+R10,2500,2800,-550,-1410
+R10,2800,3150,-580,-1440
+S3,0,3,-14,-16
+S3,3,6,-18,-20.5
+S3,6,10,-22,-24.5
+S3,10,18,-27,-30
+S3,18,30,-33.5,-37.5
+S3,30,50,-41.5,-45.5
+S3,50,65,-51,-56
+S3,65,80,-57,-62
+S3,80,100,-69,-75
+S3,100,120,-77,-83
+S3,120,140,-89,-97
+S3,140,160,-97,-105
+S3,160,180,-105,-113
+S3,180,200,-119,-129
+S3,200,225,-127,-137
+S3,225,250,-137,-147
+S3,250,280,-154,-166
+S3,280,315,-166,-178
+S3,315,355,-186,-199
+S3,355,400,-204,-217
+S3,400,450,-227,-242
+S3,450,500,-247,-262
+S3,500,560,-280,-296
+S3,560,630,-310,-326
+S3,630,710,-340,-358
+S3,710,800,-380,-398
+S3,800,900,-430,-451
+S3,900,1000,-470,-491
+S3,1000,1120,-520,-544
+S3,1120,1250,-580,-604
+S3,1250,1400,-640,-669
+S3,1400,1600,-720,-749
+S3,1600,1800,-820,-855
+S3,1800,2000,-920,-955
+S3,2000,2240,-1000,-1041
+S3,2240,2500,-1100,-1141
+S3,2500,2800,-1250,-1300
+S3,2800,3150,-1400,-1450
+S4,0,3,-14,-17
+S4,3,6,-17.5,-21.5
+S4,6,10,-21.5,-25.5
+S4,10,18,-26,-31
+S4,18,30,-33,-39
+S4,30,50,-40,-47
+S4,50,65,-50,-58
+S4,65,80,-56,-64
+S4,80,100,-67,-77
+S4,100,120,-75,-85
+S4,120,140,-88,-100
+S4,140,160,-96,-108
+S4,160,180,-104,-116
+S4,180,200,-118,-132
+S4,200,225,-126,-140
+S4,225,250,-136,-150
+S4,250,280,-154,-170
+S4,280,315,-166,-182
+S4,315,355,-185,-203
+S4,355,400,-203,-221
+S4,400,450,-227,-247
+S4,450,500,-247,-267
+S4,500,560,-280,-302
+S4,560,630,-310,-332
+S4,630,710,-340,-365
+S4,710,800,-380,-405
+S4,800,900,-430,-458
+S4,900,1000,-470,-498
+S4,1000,1120,-520,-553
+S4,1120,1250,-580,-613
+S4,1250,1400,-640,-679
+S4,1400,1600,-720,-759
+S4,1600,1800,-820,-866
+S4,1800,2000,-920,-966
+S4,2000,2240,-1000,-1055
+S4,2240,2500,-1100,-1155
+S4,2500,2800,-1250,-1318
+S4,2800,3150,-1400,-1468
+S5,0,3,-14,-18
+S5,3,6,-18,-23
+S5,6,10,-21,-27
+S5,10,18,-25,-33
+S5,18,30,-32,-41
+S5,30,50,-39,-50
+S5,50,65,-48,-61
+S5,65,80,-54,-67
+S5,80,100,-66,-81
+S5,100,120,-74,-89
+S5,120,140,-86,-104
+S5,140,160,-94,-112
+S5,160,180,-102,-120
+S5,180,200,-116,-136
+S5,200,225,-124,-144
+S5,225,250,-134,-154
+S5,250,280,-151,-174
+S5,280,315,-163,-186
+S5,315,355,-183,-208
+S5,355,400,-201,-226
+S5,400,450,-225,-252
+S5,450,500,-245,-272
+S5,500,560,-280,-312
+S5,560,630,-310,-342
+S5,630,710,-340,-376
+S5,710,800,-380,-416
+S5,800,900,-430,-470
+S5,900,1000,-470,-510
+S5,1000,1120,-520,-567
+S5,1120,1250,-580,-627
+S5,1250,1400,-640,-695
+S5,1400,1600,-720,-775
+S5,1600,1800,-820,-885
+S5,1800,2000,-920,-985
+S5,2000,2240,-1000,-1078
+S5,2240,2500,-1100,-1178
+S5,2500,2800,-1250,-1346
+S5,2800,3150,-1400,-1496
+S6,0,3,-14,-20
+S6,3,6,-16,-24
+S6,6,10,-20,-29
+S6,10,18,-25,-36
+S6,18,30,-31,-44
+S6,30,50,-38,-54
+S6,50,65,-47,-66
+S6,65,80,-53,-72
+S6,80,100,-64,-86
+S6,100,120,-72,-94
+S6,120,140,-85,-110
+S6,140,160,-93,-118
+S6,160,180,-101,-126
+S6,180,200,-113,-142
+S6,200,225,-121,-150
+S6,225,250,-131,-160
+S6,250,280,-149,-181
+S6,280,315,-161,-193
+S6,315,355,-179,-215
+S6,355,400,-197,-233
+S6,400,450,-219,-259
+S6,450,500,-239,-279
+S6,500,560,-280,-324
+S6,560,630,-310,-354
+S6,630,710,-340,-390
+S6,710,800,-380,-430
+S6,800,900,-430,-486
+S6,900,1000,-470,-526
+S6,1000,1120,-520,-586
+S6,1120,1250,-580,-646
+S6,1250,1400,-640,-718
+S6,1400,1600,-720,-798
+S6,1600,1800,-820,-912
+S6,1800,2000,-920,-1012
+S6,2000,2240,-1000,-1110
+S6,2240,2500,-1100,-1210
+S6,2500,2800,-1250,-1385
+S6,2800,3150,-1400,-1535
+S7,0,3,-14,-24
+S7,3,6,-15,-27
+S7,6,10,-17,-32
+S7,10,18,-21,-39
+S7,18,30,-27,-48
+S7,30,50,-34,-59
+S7,50,65,-42,-72
+S7,65,80,-48,-78
+S7,80,100,-58,-93
+S7,100,120,-66,-101
+S7,120,140,-77,-117
+S7,140,160,-85,-125
+S7,160,180,-93,-133
+S7,180,200,-105,-151
+S7,200,225,-113,-159
+S7,225,250,-123,-169
+S7,250,280,-138,-190
+S7,280,315,-150,-202
+S7,315,355,-169,-226
+S7,355,400,-187,-244
+S7,400,450,-209,-272
+S7,450,500,-229,-292
+S7,500,560,-280,-350
+S7,560,630,-310,-380
+S7,630,710,-340,-420
+S7,710,800,-380,-460
+S7,800,900,-430,-520
+S7,900,1000,-470,-560
+S7,1000,1120,-520,-625
+S7,1120,1250,-580,-685
+S7,1250,1400,-640,-765
+S7,1400,1600,-720,-845
+S7,1600,1800,-820,-970
+S7,1800,2000,-920,-1070
+S7,2000,2240,-1000,-1175
+S7,2240,2500,-1100,-1275
+S7,2500,2800,-1250,-1460
+S7,2800,3150,-1400,-1610
+S8,0,3,-14,-28
+S8,3,6,-19,-37
+S8,6,10,-23,-45
+S8,10,18,-28,-55
+S8,18,30,-35,-68
+S8,30,50,-43,-82
+S8,50,65,-53,-99
+S8,65,80,-59,-105
+S8,80,100,-71,-125
+S8,100,120,-79,-133
+S8,120,140,-92,-155
+S8,140,160,-100,-163
+S8,160,180,-108,-171
+S8,180,200,-122,-194
+S8,200,225,-130,-202
+S8,225,250,-140,-212
+S8,250,280,-158,-239
+S8,280,315,-170,-251
+S8,315,355,-190,-279
+S8,355,400,-208,-297
+S8,400,450,-232,-329
+S8,450,500,-252,-349
+S8,500,560,-280,-390
+S8,560,630,-310,-420
+S8,630,710,-340,-465
+S8,710,800,-380,-505
+S8,800,900,-430,-570
+S8,900,1000,-470,-610
+S8,1000,1120,-520,-685
+S8,1120,1250,-580,-745
+S8,1250,1400,-640,-835
+S8,1400,1600,-720,-915
+S8,1600,1800,-820,-1050
+S8,1800,2000,-920,-1150
+S8,2000,2240,-1000,-1280
+S8,2240,2500,-1100,-1380
+S8,2500,2800,-1250,-1580
+S8,2800,3150,-1400,-1730
+S9,0,3,-14,-39
+S9,3,6,-19,-49
+S9,6,10,-23,-59
+S9,10,18,-28,-71
+S9,18,30,-35,-87
+S9,30,50,-43,-105
+S9,50,65,-53,-127
+S9,65,80,-59,-133
+S9,80,100,-71,-158
+S9,100,120,-79,-166
+S9,120,140,-92,-192
+S9,140,160,-100,-200
+S9,160,180,-108,-208
+S9,180,200,-122,-237
+S9,200,225,-130,-245
+S9,225,250,-140,-255
+S9,250,280,-158,-288
+S9,280,315,-170,-300
+S9,315,355,-190,-330
+S9,355,400,-208,-348
+S9,400,450,-232,-387
+S9,450,500,-252,-407
+S9,500,560,-280,-455
+S9,560,630,-310,-485
+S9,630,710,-340,-540
+S9,710,800,-380,-580
+S9,800,900,-430,-660
+S9,900,1000,-470,-700
+S9,1000,1120,-520,-780
+S9,1120,1250,-580,-840
+S9,1250,1400,-640,-950
+S9,1400,1600,-720,-1030
+S9,1600,1800,-820,-1190
+S9,1800,2000,-920,-1290
+S9,2000,2240,-1000,-1440
+S9,2240,2500,-1100,-1540
+S9,2500,2800,-1250,-1790
+S9,2800,3150,-1400,-1940
+S10,0,3,-14,-54
+S10,3,6,-19,-67
+S10,6,10,-23,-81
+S10,10,18,-28,-98
+S10,18,30,-35,-119
+S10,30,50,-43,-143
+S10,50,65,-53,-173
+S10,65,80,-59,-179
+S10,80,100,-71,-211
+S10,100,120,-79,-219
+S10,120,140,-92,-252
+S10,140,160,-100,-260
+S10,160,180,-108,-268
+S10,180,200,-122,-307
+S10,200,225,-130,-315
+S10,225,250,-140,-325
+S10,250,280,-158,-368
+S10,280,315,-170,-380
+S10,315,355,-190,-420
+S10,355,400,-208,-438
+S10,400,450,-232,-482
+S10,450,500,-252,-502
+S10,500,560,-280,-560
+S10,560,630,-310,-590
+S10,630,710,-340,-660
+S10,710,800,-380,-700
+S10,800,900,-430,-790
+S10,900,1000,-470,-830
+S10,1000,1120,-520,-940
+S10,1120,1250,-580,-1000
+S10,1250,1400,-640,-1140
+S10,1400,1600,-720,-1220
+S10,1600,1800,-820,-1420
+S10,1800,2000,-920,-1520
+S10,2000,2240,-1000,-1700
+S10,2240,2500,-1100,-1800
+S10,2500,2800,-1250,-2110
+S10,2800,3150,-1400,-2260
+T5,24,30,-38,-47
+T5,30,40,-44,-55
+T5,40,50,-50,-61
+T5,50,65,-61,-74
+T5,65,80,-70,-83
+T5,80,100,-86,-101
+T5,100,120,-99,-114
+T5,120,140,-116,-134
+T5,140,160,-128,-146
+T5,160,180,-140,-158
+T5,180,200,-160,-180
+T5,200,225,-174,-194
+T5,225,250,-190,-210
+T5,250,280,-211,-234
+T5,280,315,-233,-256
+T5,315,355,-261,-286
+T5,355,400,-287,-312
+T5,400,450,-323,-350
+T5,450,500,-353,-380
+T5,500,560,-400,-432
+T5,560,630,-450,-482
+T5,630,710,-500,-536
+T5,710,800,-560,-596
+T5,800,900,-620,-660
+T5,900,1000,-680,-720
+T5,1000,1120,-780,-827
+T5,1120,1250,-840,-887
+T5,1250,1400,-960,-1015
+T5,1400,1600,-1050,-1105
+T5,1600,1800,-1200,-1265
+T5,1800,2000,-1350,-1415
+T5,2000,2240,-1500,-1578
+T5,2240,2500,-1650,-1728
+T5,2500,2800,-1900,-1996
+T5,2800,3150,-2100,-2196
+T6,24,30,-37,-50
+T6,30,40,-43,-59
+T6,40,50,-49,-65
+T6,50,65,-60,-79
+T6,65,80,-69,-88
+T6,80,100,-84,-106
+T6,100,120,-97,-119
+T6,120,140,-115,-140
+T6,140,160,-127,-152
+T6,160,180,-139,-164
+T6,180,200,-157,-186
+T6,200,225,-171,-200
+T6,225,250,-187,-216
+T6,250,280,-209,-241
+T6,280,315,-231,-263
+T6,315,355,-257,-293
+T6,355,400,-283,-319
+T6,400,450,-317,-357
+T6,450,500,-347,-387
+T6,500,560,-400,-444
+T6,560,630,-450,-494
+T6,630,710,-500,-550
+T6,710,800,-560,-610
+T6,800,900,-620,-676
+T6,900,1000,-680,-736
+T6,1000,1120,-780,-846
+T6,1120,1250,-840,-906
+T6,1250,1400,-960,-1038
+T6,1400,1600,-1050,-1128
+T6,1600,1800,-1200,-1292
+T6,1800,2000,-1350,-1442
+T6,2000,2240,-1500,-1610
+T6,2240,2500,-1650,-1760
+T6,2500,2800,-1900,-2035
+T6,2800,3150,-2100,-2235
+T7,24,30,-33,-54
+T7,30,40,-39,-64
+T7,40,50,-45,-70
+T7,50,65,-55,-85
+T7,65,80,-64,-94
+T7,80,100,-78,-113
+T7,100,120,-91,-126
+T7,120,140,-107,-147
+T7,140,160,-119,-159
+T7,160,180,-131,-171
+T7,180,200,-149,-195
+T7,200,225,-163,-209
+T7,225,250,-179,-225
+T7,250,280,-198,-250
+T7,280,315,-220,-272
+T7,315,355,-247,-304
+T7,355,400,-273,-330
+T7,400,450,-307,-370
+T7,450,500,-337,-400
+T7,500,560,-400,-470
+T7,560,630,-450,-520
+T7,630,710,-500,-580
+T7,710,800,-560,-640
+T7,800,900,-620,-710
+T7,900,1000,-680,-770
+T7,1000,1120,-780,-885
+T7,1120,1250,-840,-945
+T7,1250,1400,-960,-1085
+T7,1400,1600,-1050,-1175
+T7,1600,1800,-1200,-1350
+T7,1800,2000,-1350,-1500
+T7,2000,2240,-1500,-1675
+T7,2240,2500,-1650,-1825
+T7,2500,2800,-1900,-2110
+T7,2800,3150,-2100,-2310
+T8,24,30,-41,-74
+T8,30,40,-48,-87
+T8,40,50,-54,-93
+T8,50,65,-66,-112
+T8,65,80,-75,-121
+T8,80,100,-91,-145
+T8,100,120,-104,-158
+T8,120,140,-122,-185
+T8,140,160,-134,-197
+T8,160,180,-146,-209
+T8,180,200,-166,-238
+T8,200,225,-180,-252
+T8,225,250,-196,-268
+T8,250,280,-218,-299
+T8,280,315,-240,-321
+T8,315,355,-268,-357
+T8,355,400,-294,-383
+T8,400,450,-330,-427
+T8,450,500,-360,-457
+T8,500,560,-400,-510
+T8,560,630,-450,-560
+T8,630,710,-500,-625
+T8,710,800,-560,-685
+T8,800,900,-620,-760
+T8,900,1000,-680,-820
+T8,1000,1120,-780,-945
+T8,1120,1250,-840,-1005
+T8,1250,1400,-960,-1155
+T8,1400,1600,-1050,-1245
+T8,1600,1800,-1200,-1430
+T8,1800,2000,-1350,-1580
+T8,2000,2240,-1500,-1780
+T8,2240,2500,-1650,-1930
+T8,2500,2800,-1900,-2230
+T8,2800,3150,-2100,-2430
+U5,0,3,-18,-22
+U5,3,6,-22,-27
+U5,6,10,-26,-32
+U5,10,18,-30,-38
+U5,18,24,-38,-47
+U5,24,30,-45,-54
+U5,30,40,-56,-67
+U5,40,50,-66,-77
+U5,50,65,-82,-95
+U5,65,80,-97,-110
+U5,80,100,-119,-134
+U5,100,120,-139,-154
+U5,120,140,-164,-182
+U5,140,160,-184,-202
+U5,160,180,-204,-222
+U5,180,200,-230,-250
+U5,200,225,-252,-272
+U5,225,250,-278,-298
+U5,250,280,-308,-331
+U5,280,315,-343,-366
+U5,315,355,-383,-408
+U5,355,400,-428,-453
+U5,400,450,-483,-510
+U5,450,500,-533,-560
+U5,500,560,-600,-632
+U5,560,630,-660,-692
+U5,630,710,-740,-776
+U5,710,800,-840,-876
+U5,800,900,-940,-980
+U5,900,1000,-1050,-1090
+U5,1000,1120,-1150,-1197
+U5,1120,1250,-1300,-1347
+U5,1250,1400,-1450,-1505
+U5,1400,1600,-1600,-1655
+U5,1600,1800,-1850,-1915
+U5,1800,2000,-2000,-2065
+U5,2000,2240,-2300,-2378
+U5,2240,2500,-2500,-2578
+U5,2500,2800,-2900,-2996
+U5,2800,3150,-3200,-3296
+U6,0,3,-18,-24
+U6,3,6,-20,-28
+U6,6,10,-25,-34
+U6,10,18,-30,-41
+U6,18,24,-37,-50
+U6,24,30,-44,-57
+U6,30,40,-55,-71
+U6,40,50,-65,-81
+U6,50,65,-81,-100
+U6,65,80,-96,-115
+U6,80,100,-117,-139
+U6,100,120,-137,-159
+U6,120,140,-163,-188
+U6,140,160,-183,-208
+U6,160,180,-203,-228
+U6,180,200,-227,-256
+U6,200,225,-249,-278
+U6,225,250,-275,-304
+U6,250,280,-306,-338
+U6,280,315,-341,-373
+U6,315,355,-379,-415
+U6,355,400,-424,-460
+U6,400,450,-477,-517
+U6,450,500,-527,-567
+U6,500,560,-600,-644
+U6,560,630,-660,-704
+U6,630,710,-740,-790
+U6,710,800,-840,-890
+U6,800,900,-940,-996
+U6,900,1000,-1050,-1106
+U6,1000,1120,-1150,-1216
+U6,1120,1250,-1300,-1366
+U6,1250,1400,-1450,-1528
+U6,1400,1600,-1600,-1678
+U6,1600,1800,-1850,-1942
+U6,1800,2000,-2000,-2092
+U6,2000,2240,-2300,-2410
+U6,2240,2500,-2500,-2610
+U6,2500,2800,-2900,-3035
+U6,2800,3150,-3200,-3335
+U7,0,3,-18,-28
+U7,3,6,-19,-31
+U7,6,10,-22,-37
+U7,10,18,-26,-44
+U7,18,24,-33,-54
+U7,24,30,-40,-61
+U7,30,40,-51,-76
+U7,40,50,-61,-86
+U7,50,65,-76,-106
+U7,65,80,-91,-121
+U7,80,100,-111,-146
+U7,100,120,-131,-166
+U7,120,140,-155,-195
+U7,140,160,-175,-215
+U7,160,180,-195,-235
+U7,180,200,-219,-265
+U7,200,225,-241,-287
+U7,225,250,-267,-313
+U7,250,280,-295,-347
+U7,280,315,-330,-382
+U7,315,355,-369,-426
+U7,355,400,-414,-471
+U7,400,450,-467,-530
+U7,450,500,-517,-580
+U7,500,560,-600,-670
+U7,560,630,-660,-730
+U7,630,710,-740,-820
+U7,710,800,-840,-920
+U7,800,900,-940,-1030
+U7,900,1000,-1050,-1140
+U7,1000,1120,-1150,-1255
+U7,1120,1250,-1300,-1405
+U7,1250,1400,-1450,-1575
+U7,1400,1600,-1600,-1725
+U7,1600,1800,-1850,-2000
+U7,1800,2000,-2000,-2150
+U7,2000,2240,-2300,-2475
+U7,2240,2500,-2500,-2675
+U7,2500,2800,-2900,-3110
+U7,2800,3150,-3200,-3410
+U8,0,3,-18,-32
+U8,3,6,-23,-41
+U8,6,10,-28,-50
+U8,10,18,-33,-60
+U8,18,24,-41,-74
+U8,24,30,-48,-81
+U8,30,40,-60,-99
+U8,40,50,-70,-109
+U8,50,65,-87,-133
+U8,65,80,-102,-148
+U8,80,100,-124,-178
+U8,100,120,-144,-198
+U8,120,140,-170,-233
+U8,140,160,-190,-253
+U8,160,180,-210,-273
+U8,180,200,-236,-308
+U8,200,225,-258,-330
+U8,225,250,-284,-356
+U8,250,280,-315,-396
+U8,280,315,-350,-431
+U8,315,355,-390,-479
+U8,355,400,-435,-524
+U8,400,450,-490,-587
+U8,450,500,-540,-637
+U8,500,560,-600,-710
+U8,560,630,-660,-770
+U8,630,710,-740,-865
+U8,710,800,-840,-965
+U8,800,900,-940,-1080
+U8,900,1000,-1050,-1190
+U8,1000,1120,-1150,-1315
+U8,1120,1250,-1300,-1465
+U8,1250,1400,-1450,-1645
+U8,1400,1600,-1600,-1795
+U8,1600,1800,-1850,-2080
+U8,1800,2000,-2000,-2230
+U8,2000,2240,-2300,-2580
+U8,2240,2500,-2500,-2780
+U8,2500,2800,-2900,-3230
+U8,2800,3150,-3200,-3530
+U9,0,3,-18,-43
+U9,3,6,-23,-53
+U9,6,10,-28,-64
+U9,10,18,-33,-76
+U9,18,24,-41,-93
+U9,24,30,-48,-100
+U9,30,40,-60,-122
+U9,40,50,-70,-132
+U9,50,65,-87,-161
+U9,65,80,-102,-176
+U9,80,100,-124,-211
+U9,100,120,-144,-231
+U9,120,140,-170,-270
+U9,140,160,-190,-290
+U9,160,180,-210,-310
+U9,180,200,-236,-351
+U9,200,225,-258,-373
+U9,225,250,-284,-399
+U9,250,280,-315,-445
+U9,280,315,-350,-480
+U9,315,355,-390,-530
+U9,355,400,-435,-575
+U9,400,450,-490,-645
+U9,450,500,-540,-695
+U9,500,560,-600,-775
+U9,560,630,-660,-835
+U9,630,710,-740,-940
+U9,710,800,-840,-1040
+U9,800,900,-940,-1170
+U9,900,1000,-1050,-1280
+U9,1000,1120,-1150,-1410
+U9,1120,1250,-1300,-1560
+U9,1250,1400,-1450,-1760
+U9,1400,1600,-1600,-1910
+U9,1600,1800,-1850,-2220
+U9,1800,2000,-2000,-2370
+U9,2000,2240,-2300,-2740
+U9,2240,2500,-2500,-2940
+U9,2500,2800,-2900,-3440
+U9,2800,3150,-3200,-3740
+U10,0,3,-18,-58
+U10,3,6,-23,-71
+U10,6,10,-28,-86
+U10,10,18,-33,-103
+U10,18,24,-41,-125
+U10,24,30,-48,-132
+U10,30,40,-60,-160
+U10,40,50,-70,-170
+U10,50,65,-87,-207
+U10,65,80,-102,-222
+U10,80,100,-124,-264
+U10,100,120,-144,-284
+U10,120,140,-170,-330
+U10,140,160,-190,-350
+U10,160,180,-210,-370
+U10,180,200,-236,-421
+U10,200,225,-258,-443
+U10,225,250,-284,-469
+U10,250,280,-315,-525
+U10,280,315,-350,-560
+U10,315,355,-390,-620
+U10,355,400,-435,-665
+U10,400,450,-490,-740
+U10,450,500,-540,-790
+U10,500,560,-600,-880
+U10,560,630,-660,-940
+U10,630,710,-740,-1060
+U10,710,800,-840,-1160
+U10,800,900,-940,-1300
+U10,900,1000,-1050,-1410
+U10,1000,1120,-1150,-1570
+U10,1120,1250,-1300,-1720
+U10,1250,1400,-1450,-1950
+U10,1400,1600,-1600,-2100
+U10,1600,1800,-1850,-2450
+U10,1800,2000,-2000,-2600
+U10,2000,2240,-2300,-3000
+U10,2240,2500,-2500,-3200
+U10,2500,2800,-2900,-3760
+U10,2800,3150,-3200,-4060
+V5,14,18,-36,-44
+V5,18,24,-44,-53
+V5,24,30,-52,-61
+V5,30,40,-64,-75
+V5,40,50,-77,-88
+V5,50,65,-97,-110
+V5,65,80,-115,-128
+V5,80,100,-141,-156
+V5,100,120,-167,-182
+V5,120,140,-196,-214
+V5,140,160,-222,-240
+V5,160,180,-246,-264
+V5,180,200,-278,-298
+V5,200,225,-304,-324
+V5,225,250,-334,-354
+V5,250,280,-378,-401
+V5,280,315,-418,-441
+V5,315,355,-468,-493
+V5,355,400,-523,-548
+V5,400,450,-588,-615
+V5,450,500,-653,-680
+V6,14,18,-36,-47
+V6,18,24,-43,-56
+V6,24,30,-51,-64
+V6,30,40,-63,-79
+V6,40,50,-76,-92
+V6,50,65,-96,-115
+V6,65,80,-114,-133
+V6,80,100,-139,-161
+V6,100,120,-165,-187
+V6,120,140,-195,-220
+V6,140,160,-221,-246
+V6,160,180,-245,-270
+V6,180,200,-275,-304
+V6,200,225,-301,-330
+V6,225,250,-331,-360
+V6,250,280,-376,-408
+V6,280,315,-416,-448
+V6,315,355,-464,-500
+V6,355,400,-519,-555
+V6,400,450,-582,-622
+V6,450,500,-647,-687
+V7,14,18,-32,-50
+V7,18,24,-39,-60
+V7,24,30,-47,-68
+V7,30,40,-59,-84
+V7,40,50,-72,-97
+V7,50,65,-91,-121
+V7,65,80,-109,-139
+V7,80,100,-133,-168
+V7,100,120,-159,-194
+V7,120,140,-187,-227
+V7,140,160,-213,-253
+V7,160,180,-237,-277
+V7,180,200,-267,-313
+V7,200,225,-293,-339
+V7,225,250,-323,-369
+V7,250,280,-365,-417
+V7,280,315,-405,-457
+V7,315,355,-454,-511
+V7,355,400,-509,-566
+V7,400,450,-572,-635
+V7,450,500,-637,-700
+V8,14,18,-39,-66
+V8,18,24,-47,-80
+V8,24,30,-55,-88
+V8,30,40,-68,-107
+V8,40,50,-81,-120
+V8,50,65,-102,-148
+V8,65,80,-120,-166
+V8,80,100,-146,-200
+V8,100,120,-172,-226
+V8,120,140,-202,-265
+V8,140,160,-228,-291
+V8,160,180,-252,-315
+V8,180,200,-284,-356
+V8,200,225,-310,-382
+V8,225,250,-340,-412
+V8,250,280,-385,-466
+V8,280,315,-425,-506
+V8,315,355,-475,-564
+V8,355,400,-530,-619
+V8,400,450,-595,-692
+V8,450,500,-660,-757
+X5,0,3,-20,-24
+X5,3,6,-27,-32
+X5,6,10,-32,-38
+X5,10,14,-37,-45
+X5,14,18,-42,-50
+X5,18,24,-51,-60
+X5,24,30,-61,-70
+X5,30,40,-76,-87
+X5,40,50,-93,-104
+X5,50,65,-117,-130
+X5,65,80,-141,-154
+X5,80,100,-173,-188
+X5,100,120,-205,-220
+X5,120,140,-242,-260
+X5,140,160,-274,-292
+X5,160,180,-304,-322
+X5,180,200,-344,-364
+X5,200,225,-379,-399
+X5,225,250,-419,-439
+X5,250,280,-468,-491
+X5,280,315,-518,-541
+X5,315,355,-583,-608
+X5,355,400,-653,-678
+X5,400,450,-733,-760
+X5,450,500,-813,-840
+X6,0,3,-20,-26
+X6,3,6,-25,-33
+X6,6,10,-31,-40
+X6,10,14,-37,-48
+X6,14,18,-42,-53
+X6,18,24,-50,-63
+X6,24,30,-60,-73
+X6,30,40,-75,-91
+X6,40,50,-92,-108
+X6,50,65,-116,-135
+X6,65,80,-140,-159
+X6,80,100,-171,-193
+X6,100,120,-203,-225
+X6,120,140,-241,-266
+X6,140,160,-273,-298
+X6,160,180,-303,-328
+X6,180,200,-341,-370
+X6,200,225,-376,-405
+X6,225,250,-416,-445
+X6,250,280,-466,-498
+X6,280,315,-516,-548
+X6,315,355,-579,-615
+X6,355,400,-649,-685
+X6,400,450,-727,-767
+X6,450,500,-807,-847
+X7,0,3,-20,-30
+X7,3,6,-24,-36
+X7,6,10,-28,-43
+X7,10,14,-33,-51
+X7,14,18,-38,-56
+X7,18,24,-46,-67
+X7,24,30,-56,-77
+X7,30,40,-71,-96
+X7,40,50,-88,-113
+X7,50,65,-111,-141
+X7,65,80,-135,-165
+X7,80,100,-165,-200
+X7,100,120,-197,-232
+X7,120,140,-233,-273
+X7,140,160,-265,-305
+X7,160,180,-295,-335
+X7,180,200,-333,-379
+X7,200,225,-368,-414
+X7,225,250,-408,-454
+X7,250,280,-455,-507
+X7,280,315,-505,-557
+X7,315,355,-569,-626
+X7,355,400,-639,-696
+X7,400,450,-717,-780
+X7,450,500,-797,-860
+X8,0,3,-20,-34
+X8,3,6,-28,-46
+X8,6,10,-34,-56
+X8,10,14,-40,-67
+X8,14,18,-45,-72
+X8,18,24,-54,-87
+X8,24,30,-64,-97
+X8,30,40,-80,-119
+X8,40,50,-97,-136
+X8,50,65,-122,-168
+X8,65,80,-146,-192
+X8,80,100,-178,-232
+X8,100,120,-210,-264
+X8,120,140,-248,-311
+X8,140,160,-280,-343
+X8,160,180,-310,-373
+X8,180,200,-350,-422
+X8,200,225,-385,-457
+X8,225,250,-425,-497
+X8,250,280,-475,-556
+X8,280,315,-525,-606
+X8,315,355,-590,-679
+X8,355,400,-660,-749
+X8,400,450,-740,-837
+X8,450,500,-820,-917
+X9,0,3,-20,-45
+X9,3,6,-28,-58
+X9,6,10,-34,-70
+X9,10,14,-40,-83
+X9,14,18,-45,-88
+X9,18,24,-54,-106
+X9,24,30,-64,-116
+X9,30,40,-80,-142
+X9,40,50,-97,-159
+X9,50,65,-122,-196
+X9,65,80,-146,-220
+X9,80,100,-178,-265
+X9,100,120,-210,-297
+X9,120,140,-248,-348
+X9,140,160,-280,-380
+X9,160,180,-310,-410
+X9,180,200,-350,-465
+X9,200,225,-385,-500
+X9,225,250,-425,-540
+X9,250,280,-475,-605
+X9,280,315,-525,-655
+X9,315,355,-590,-730
+X9,355,400,-660,-800
+X9,400,450,-740,-895
+X9,450,500,-820,-975
+X10,0,3,-20,-60
+X10,3,6,-28,-76
+X10,6,10,-34,-92
+X10,10,14,-40,-110
+X10,14,18,-45,-115
+X10,18,24,-54,-138
+X10,24,30,-64,-148
+X10,30,40,-80,-180
+X10,40,50,-97,-197
+X10,50,65,-122,-242
+X10,65,80,-146,-266
+X10,80,100,-178,-318
+X10,100,120,-210,-350
+X10,120,140,-248,-408
+X10,140,160,-280,-440
+X10,160,180,-310,-470
+X10,180,200,-350,-535
+X10,200,225,-385,-570
+X10,225,250,-425,-610
+X10,250,280,-475,-685
+X10,280,315,-525,-735
+X10,315,355,-590,-820
+X10,355,400,-660,-890
+X10,400,450,-740,-990
+X10,450,500,-820,-1070
+Y6,18,24,-59,-72
+Y6,24,30,-71,-84
+Y6,30,40,-89,-105
+Y6,40,50,-109,-125
+Y6,50,65,-138,-157
+Y6,65,80,-168,-187
+Y6,80,100,-207,-229
+Y6,100,120,-247,-269
+Y6,120,140,-293,-318
+Y6,140,160,-333,-358
+Y6,160,180,-373,-398
+Y6,180,200,-416,-445
+Y6,200,225,-461,-490
+Y6,225,250,-511,-540
+Y6,250,280,-571,-603
+Y6,280,315,-641,-673
+Y6,315,355,-719,-755
+Y6,355,400,-809,-845
+Y6,400,450,-907,-947
+Y6,450,500,-987,-1027
+Y7,18,24,-55,-76
+Y7,24,30,-67,-88
+Y7,30,40,-85,-110
+Y7,40,50,-105,-130
+Y7,50,65,-133,-163
+Y7,65,80,-163,-193
+Y7,80,100,-201,-236
+Y7,100,120,-241,-276
+Y7,120,140,-285,-325
+Y7,140,160,-325,-365
+Y7,160,180,-365,-405
+Y7,180,200,-408,-454
+Y7,200,225,-453,-499
+Y7,225,250,-503,-549
+Y7,250,280,-560,-612
+Y7,280,315,-630,-682
+Y7,315,355,-709,-766
+Y7,355,400,-799,-856
+Y7,400,450,-897,-960
+Y7,450,500,-977,-1040
+Y8,18,24,-63,-96
+Y8,24,30,-75,-108
+Y8,30,40,-94,-133
+Y8,40,50,-114,-153
+Y8,50,65,-144,-190
+Y8,65,80,-174,-220
+Y8,80,100,-214,-268
+Y8,100,120,-254,-308
+Y8,120,140,-300,-363
+Y8,140,160,-340,-403
+Y8,160,180,-380,-443
+Y8,180,200,-425,-497
+Y8,200,225,-470,-542
+Y8,225,250,-520,-592
+Y8,250,280,-580,-661
+Y8,280,315,-650,-731
+Y8,315,355,-730,-819
+Y8,355,400,-820,-909
+Y8,400,450,-920,-1017
+Y8,450,500,-1000,-1097
+Y9,18,24,-63,-115
+Y9,24,30,-75,-127
+Y9,30,40,-94,-156
+Y9,40,50,-114,-176
+Y9,50,65,-144,-218
+Y9,65,80,-174,-248
+Y9,80,100,-214,-301
+Y9,100,120,-254,-341
+Y9,120,140,-300,-400
+Y9,140,160,-340,-440
+Y9,160,180,-380,-480
+Y9,180,200,-425,-540
+Y9,200,225,-470,-585
+Y9,225,250,-520,-635
+Y9,250,280,-580,-710
+Y9,280,315,-650,-780
+Y9,315,355,-730,-870
+Y9,355,400,-820,-960
+Y9,400,450,-920,-1075
+Y9,450,500,-1000,-1155
+Y10,18,24,-63,-147
+Y10,24,30,-75,-159
+Y10,30,40,-94,-194
+Y10,40,50,-114,-214
+Y10,50,65,-144,-264
+Y10,65,80,-174,-294
+Y10,80,100,-214,-354
+Y10,100,120,-254,-394
+Y10,120,140,-300,-460
+Y10,140,160,-340,-500
+Y10,160,180,-380,-540
+Y10,180,200,-425,-610
+Y10,200,225,-470,-655
+Y10,225,250,-520,-705
+Y10,250,280,-580,-790
+Y10,280,315,-650,-860
+Y10,315,355,-730,-960
+Y10,355,400,-820,-1050
+Y10,400,450,-920,-1170
+Y10,450,500,-1000,-1250
+Z6,0,3,-26,-32
+Z6,3,6,-32,-40
+Z6,6,10,-39,-48
+Z6,10,14,-47,-58
+Z6,14,18,-57,-68
+Z6,18,24,-69,-82
+Z6,24,30,-84,-97
+Z6,30,40,-107,-123
+Z6,40,50,-131,-147
+Z6,50,65,-166,-185
+Z6,65,80,-204,-223
+Z6,80,100,-251,-273
+Z6,100,120,-303,-325
+Z6,120,140,-358,-383
+Z6,140,160,-408,-433
+Z6,160,180,-458,-483
+Z6,180,200,-511,-540
+Z6,200,225,-566,-595
+Z6,225,250,-631,-660
+Z6,250,280,-701,-733
+Z6,280,315,-781,-813
+Z6,315,355,-889,-925
+Z6,355,400,-989,-1025
+Z6,400,450,-1087,-1127
+Z6,450,500,-1237,-1277
+Z7,0,3,-26,-36
+Z7,3,6,-31,-43
+Z7,6,10,-36,-51
+Z7,10,14,-43,-61
+Z7,14,18,-53,-71
+Z7,18,24,-65,-86
+Z7,24,30,-80,-101
+Z7,30,40,-103,-128
+Z7,40,50,-127,-152
+Z7,50,65,-161,-191
+Z7,65,80,-199,-229
+Z7,80,100,-245,-280
+Z7,100,120,-297,-332
+Z7,120,140,-350,-390
+Z7,140,160,-400,-440
+Z7,160,180,-450,-490
+Z7,180,200,-503,-549
+Z7,200,225,-558,-604
+Z7,225,250,-623,-669
+Z7,250,280,-690,-742
+Z7,280,315,-770,-822
+Z7,315,355,-879,-936
+Z7,355,400,-979,-1036
+Z7,400,450,-1077,-1140
+Z7,450,500,-1227,-1290
+Z8,0,3,-26,-40
+Z8,3,6,-35,-53
+Z8,6,10,-42,-64
+Z8,10,14,-50,-77
+Z8,14,18,-60,-87
+Z8,18,24,-73,-106
+Z8,24,30,-88,-121
+Z8,30,40,-112,-151
+Z8,40,50,-136,-175
+Z8,50,65,-172,-218
+Z8,65,80,-210,-256
+Z8,80,100,-258,-312
+Z8,100,120,-310,-364
+Z8,120,140,-365,-428
+Z8,140,160,-415,-478
+Z8,160,180,-465,-528
+Z8,180,200,-520,-592
+Z8,200,225,-575,-647
+Z8,225,250,-640,-712
+Z8,250,280,-710,-791
+Z8,280,315,-790,-871
+Z8,315,355,-900,-989
+Z8,355,400,-1000,-1089
+Z8,400,450,-1100,-1197
+Z8,450,500,-1250,-1347
+Z9,0,3,-26,-51
+Z9,3,6,-35,-65
+Z9,6,10,-42,-78
+Z9,10,14,-50,-93
+Z9,14,18,-60,-103
+Z9,18,24,-73,-125
+Z9,24,30,-88,-140
+Z9,30,40,-112,-174
+Z9,40,50,-136,-198
+Z9,50,65,-172,-246
+Z9,65,80,-210,-284
+Z9,80,100,-258,-345
+Z9,100,120,-310,-397
+Z9,120,140,-365,-465
+Z9,140,160,-415,-515
+Z9,160,180,-465,-565
+Z9,180,200,-520,-635
+Z9,200,225,-575,-690
+Z9,225,250,-640,-755
+Z9,250,280,-710,-840
+Z9,280,315,-790,-920
+Z9,315,355,-900,-1040
+Z9,355,400,-1000,-1140
+Z9,400,450,-1100,-1255
+Z9,450,500,-1250,-1405
+Z10,0,3,-26,-66
+Z10,3,6,-35,-83
+Z10,6,10,-42,-100
+Z10,10,14,-50,-120
+Z10,14,18,-60,-130
+Z10,18,24,-73,-157
+Z10,24,30,-88,-172
+Z10,30,40,-112,-212
+Z10,40,50,-136,-236
+Z10,50,65,-172,-292
+Z10,65,80,-210,-330
+Z10,80,100,-258,-398
+Z10,100,120,-310,-450
+Z10,120,140,-365,-525
+Z10,140,160,-415,-575
+Z10,160,180,-465,-625
+Z10,180,200,-520,-705
+Z10,200,225,-575,-760
+Z10,225,250,-640,-825
+Z10,250,280,-710,-920
+Z10,280,315,-790,-1000
+Z10,315,355,-900,-1130
+Z10,355,400,-1000,-1230
+Z10,400,450,-1100,-1350
+Z10,450,500,-1250,-1500
+Z11,0,3,-26,-86
+Z11,3,6,-35,-110
+Z11,6,10,-42,-132
+Z11,10,14,-50,-160
+Z11,14,18,-60,-170
+Z11,18,24,-73,-203
+Z11,24,30,-88,-218
+Z11,30,40,-112,-272
+Z11,40,50,-136,-296
+Z11,50,65,-172,-362
+Z11,65,80,-210,-400
+Z11,80,100,-258,-478
+Z11,100,120,-310,-530
+Z11,120,140,-365,-615
+Z11,140,160,-415,-665
+Z11,160,180,-465,-715
+Z11,180,200,-520,-810
+Z11,200,225,-575,-865
+Z11,225,250,-640,-930
+Z11,250,280,-710,-1030
+Z11,280,315,-790,-1110
+Z11,315,355,-900,-1260
+Z11,355,400,-1000,-1360
+Z11,400,450,-1100,-1500
+Z11,450,500,-1250,-1650
+ZA6,0,3,-32,-38
+ZA6,3,6,-39,-47
+ZA6,6,10,-49,-58
+ZA6,10,14,-61,-72
+ZA6,14,18,-74,-85
+ZA6,18,24,-94,-107
+ZA6,24,30,-114,-127
+ZA6,30,40,-143,-159
+ZA6,40,50,-175,-191
+ZA6,50,65,-220,-239
+ZA6,65,80,-268,-287
+ZA6,80,100,-328,-350
+ZA6,100,120,-393,-415
+ZA6,120,140,-463,-488
+ZA6,140,160,-528,-553
+ZA6,160,180,-593,-618
+ZA6,180,200,-661,-690
+ZA6,200,225,-731,-760
+ZA6,225,250,-811,-840
+ZA6,250,280,-911,-943
+ZA6,280,315,-991,-1023
+ZA6,315,355,-1139,-1175
+ZA6,355,400,-1289,-1325
+ZA6,400,450,-1437,-1477
+ZA6,450,500,-1587,-1627
+ZA7,0,3,-32,-42
+ZA7,3,6,-38,-50
+ZA7,6,10,-46,-61
+ZA7,10,14,-57,-75
+ZA7,14,18,-70,-88
+ZA7,18,24,-90,-111
+ZA7,24,30,-110,-131
+ZA7,30,40,-139,-164
+ZA7,40,50,-171,-196
+ZA7,50,65,-215,-245
+ZA7,65,80,-263,-293
+ZA7,80,100,-322,-357
+ZA7,100,120,-387,-422
+ZA7,120,140,-455,-495
+ZA7,140,160,-520,-560
+ZA7,160,180,-585,-625
+ZA7,180,200,-653,-699
+ZA7,200,225,-723,-769
+ZA7,225,250,-803,-849
+ZA7,250,280,-900,-952
+ZA7,280,315,-980,-1032
+ZA7,315,355,-1129,-1186
+ZA7,355,400,-1279,-1336
+ZA7,400,450,-1427,-1490
+ZA7,450,500,-1577,-1640
+ZA8,0,3,-32,-46
+ZA8,3,6,-42,-60
+ZA8,6,10,-52,-74
+ZA8,10,14,-64,-91
+ZA8,14,18,-77,-104
+ZA8,18,24,-98,-131
+ZA8,24,30,-118,-151
+ZA8,30,40,-148,-187
+ZA8,40,50,-180,-219
+ZA8,50,65,-226,-272
+ZA8,65,80,-274,-320
+ZA8,80,100,-335,-389
+ZA8,100,120,-400,-454
+ZA8,120,140,-470,-533
+ZA8,140,160,-535,-598
+ZA8,160,180,-600,-663
+ZA8,180,200,-670,-742
+ZA8,200,225,-740,-812
+ZA8,225,250,-820,-892
+ZA8,250,280,-920,-1001
+ZA8,280,315,-1000,-1081
+ZA8,315,355,-1150,-1239
+ZA8,355,400,-1300,-1389
+ZA8,400,450,-1450,-1547
+ZA8,450,500,-1600,-1697
+ZA9,0,3,-32,-57
+ZA9,3,6,-42,-72
+ZA9,6,10,-52,-88
+ZA9,10,14,-64,-107
+ZA9,14,18,-77,-120
+ZA9,18,24,-98,-150
+ZA9,24,30,-118,-170
+ZA9,30,40,-148,-210
+ZA9,40,50,-180,-242
+ZA9,50,65,-226,-300
+ZA9,65,80,-274,-348
+ZA9,80,100,-335,-422
+ZA9,100,120,-400,-487
+ZA9,120,140,-470,-570
+ZA9,140,160,-535,-635
+ZA9,160,180,-600,-700
+ZA9,180,200,-670,-785
+ZA9,200,225,-740,-855
+ZA9,225,250,-820,-935
+ZA9,250,280,-920,-1050
+ZA9,280,315,-1000,-1130
+ZA9,315,355,-1150,-1290
+ZA9,355,400,-1300,-1440
+ZA9,400,450,-1450,-1605
+ZA9,450,500,-1600,-1755
+ZA10,0,3,-32,-72
+ZA10,3,6,-42,-90
+ZA10,6,10,-52,-110
+ZA10,10,14,-64,-134
+ZA10,14,18,-77,-147
+ZA10,18,24,-98,-182
+ZA10,24,30,-118,-202
+ZA10,30,40,-148,-248
+ZA10,40,50,-180,-280
+ZA10,50,65,-226,-346
+ZA10,65,80,-274,-394
+ZA10,80,100,-335,-475
+ZA10,100,120,-400,-540
+ZA10,120,140,-470,-630
+ZA10,140,160,-535,-695
+ZA10,160,180,-600,-760
+ZA10,180,200,-670,-855
+ZA10,200,225,-740,-925
+ZA10,225,250,-820,-1005
+ZA10,250,280,-920,-1130
+ZA10,280,315,-1000,-1210
+ZA10,315,355,-1150,-1380
+ZA10,355,400,-1300,-1530
+ZA10,400,450,-1450,-1700
+ZA10,450,500,-1600,-1850
+ZA11,0,3,-32,-92
+ZA11,3,6,-42,-117
+ZA11,6,10,-52,-142
+ZA11,10,14,-64,-174
+ZA11,14,18,-77,-187
+ZA11,18,24,-98,-228
+ZA11,24,30,-118,-248
+ZA11,30,40,-148,-308
+ZA11,40,50,-180,-340
+ZA11,50,65,-226,-416
+ZA11,65,80,-274,-464
+ZA11,80,100,-335,-555
+ZA11,100,120,-400,-620
+ZA11,120,140,-470,-720
+ZA11,140,160,-535,-785
+ZA11,160,180,-600,-850
+ZA11,180,200,-670,-960
+ZA11,200,225,-740,-1030
+ZA11,225,250,-820,-1110
+ZA11,250,280,-920,-1240
+ZA11,280,315,-1000,-1320
+ZA11,315,355,-1150,-1510
+ZA11,355,400,-1300,-1660
+ZA11,400,450,-1450,-1850
+ZA11,450,500,-1600,-2000
+ZB7,0,3,-40,-50
+ZB7,3,6,-46,-58
+ZB7,6,10,-61,-76
+ZB7,10,14,-83,-101
+ZB7,14,18,-101,-119
+ZB7,18,24,-128,-149
+ZB7,24,30,-152,-173
+ZB7,30,40,-191,-216
+ZB7,40,50,-233,-258
+ZB7,50,65,-289,-319
+ZB7,65,80,-349,-379
+ZB7,80,100,-432,-467
+ZB7,100,120,-512,-547
+ZB7,120,140,-605,-645
+ZB7,140,160,-685,-725
+ZB7,160,180,-765,-805
+ZB7,180,200,-863,-909
+ZB7,200,225,-943,-989
+ZB7,225,250,-1033,-1079
+ZB7,250,280,-1180,-1232
+ZB7,280,315,-1280,-1332
+ZB7,315,355,-1479,-1536
+ZB7,355,400,-1629,-1686
+ZB7,400,450,-1827,-1890
+ZB7,450,500,-2077,-2140
+ZB8,0,3,-40,-54
+ZB8,3,6,-50,-68
+ZB8,6,10,-67,-89
+ZB8,10,14,-90,-117
+ZB8,14,18,-108,-135
+ZB8,18,24,-136,-169
+ZB8,24,30,-160,-193
+ZB8,30,40,-200,-239
+ZB8,40,50,-242,-281
+ZB8,50,65,-300,-346
+ZB8,65,80,-360,-406
+ZB8,80,100,-445,-499
+ZB8,100,120,-525,-579
+ZB8,120,140,-620,-683
+ZB8,140,160,-700,-763
+ZB8,160,180,-780,-843
+ZB8,180,200,-880,-952
+ZB8,200,225,-960,-1032
+ZB8,225,250,-1050,-1122
+ZB8,250,280,-1200,-1281
+ZB8,280,315,-1300,-1381
+ZB8,315,355,-1500,-1589
+ZB8,355,400,-1650,-1739
+ZB8,400,450,-1850,-1947
+ZB8,450,500,-2100,-2197
+ZB9,0,3,-40,-65
+ZB9,3,6,-50,-80
+ZB9,6,10,-67,-103
+ZB9,10,14,-90,-133
+ZB9,14,18,-108,-151
+ZB9,18,24,-136,-188
+ZB9,24,30,-160,-212
+ZB9,30,40,-200,-262
+ZB9,40,50,-242,-304
+ZB9,50,65,-300,-374
+ZB9,65,80,-360,-434
+ZB9,80,100,-445,-532
+ZB9,100,120,-525,-612
+ZB9,120,140,-620,-720
+ZB9,140,160,-700,-800
+ZB9,160,180,-780,-880
+ZB9,180,200,-880,-995
+ZB9,200,225,-960,-1075
+ZB9,225,250,-1050,-1165
+ZB9,250,280,-1200,-1330
+ZB9,280,315,-1300,-1430
+ZB9,315,355,-1500,-1640
+ZB9,355,400,-1650,-1790
+ZB9,400,450,-1850,-2005
+ZB9,450,500,-2100,-2255
+ZB10,0,3,-40,-80
+ZB10,3,6,-50,-98
+ZB10,6,10,-67,-125
+ZB10,10,14,-90,-160
+ZB10,14,18,-108,-178
+ZB10,18,24,-136,-220
+ZB10,24,30,-160,-244
+ZB10,30,40,-200,-300
+ZB10,40,50,-242,-342
+ZB10,50,65,-300,-420
+ZB10,65,80,-360,-480
+ZB10,80,100,-445,-585
+ZB10,100,120,-525,-665
+ZB10,120,140,-620,-780
+ZB10,140,160,-700,-860
+ZB10,160,180,-780,-940
+ZB10,180,200,-880,-1065
+ZB10,200,225,-960,-1145
+ZB10,225,250,-1050,-1235
+ZB10,250,280,-1200,-1410
+ZB10,280,315,-1300,-1510
+ZB10,315,355,-1500,-1730
+ZB10,355,400,-1650,-1880
+ZB10,400,450,-1850,-2100
+ZB10,450,500,-2100,-2350
+ZB11,0,3,-40,-100
+ZB11,3,6,-50,-125
+ZB11,6,10,-67,-157
+ZB11,10,14,-90,-200
+ZB11,14,18,-108,-218
+ZB11,18,24,-136,-266
+ZB11,24,30,-160,-290
+ZB11,30,40,-200,-360
+ZB11,40,50,-242,-402
+ZB11,50,65,-300,-490
+ZB11,65,80,-360,-550
+ZB11,80,100,-445,-665
+ZB11,100,120,-525,-745
+ZB11,120,140,-620,-870
+ZB11,140,160,-700,-950
+ZB11,160,180,-780,-1030
+ZB11,180,200,-880,-1170
+ZB11,200,225,-960,-1250
+ZB11,225,250,-1050,-1340
+ZB11,250,280,-1200,-1520
+ZB11,280,315,-1300,-1620
+ZB11,315,355,-1500,-1860
+ZB11,355,400,-1650,-2010
+ZB11,400,450,-1850,-2250
+ZB11,450,500,-2100,-2500
+ZC7,0,3,-60,-70
+ZC7,3,6,-76,-88
+ZC7,6,10,-91,-106
+ZC7,10,14,-123,-141
+ZC7,14,18,-143,-161
+ZC7,18,24,-180,-201
+ZC7,24,30,-210,-231
+ZC7,30,40,-265,-290
+ZC7,40,50,-316,-341
+ZC7,50,65,-394,-424
+ZC7,65,80,-469,-499
+ZC7,80,100,-572,-607
+ZC7,100,120,-677,-712
+ZC7,120,140,-785,-825
+ZC7,140,160,-885,-925
+ZC7,160,180,-985,-1025
+ZC7,180,200,-1133,-1179
+ZC7,200,225,-1233,-1279
+ZC7,225,250,-1333,-1379
+ZC7,250,280,-1530,-1582
+ZC7,280,315,-1680,-1732
+ZC7,315,355,-1879,-1936
+ZC7,355,400,-2079,-2136
+ZC7,400,450,-2377,-2440
+ZC7,450,500,-2577,-2640
+ZC8,0,3,-60,-74
+ZC8,3,6,-80,-98
+ZC8,6,10,-97,-119
+ZC8,10,14,-130,-157
+ZC8,14,18,-150,-177
+ZC8,18,24,-188,-221
+ZC8,24,30,-218,-251
+ZC8,30,40,-274,-313
+ZC8,40,50,-325,-364
+ZC8,50,65,-405,-451
+ZC8,65,80,-480,-526
+ZC8,80,100,-585,-639
+ZC8,100,120,-690,-744
+ZC8,120,140,-800,-863
+ZC8,140,160,-900,-963
+ZC8,160,180,-1000,-1063
+ZC8,180,200,-1150,-1222
+ZC8,200,225,-1250,-1322
+ZC8,225,250,-1350,-1422
+ZC8,250,280,-1550,-1631
+ZC8,280,315,-1700,-1781
+ZC8,315,355,-1900,-1989
+ZC8,355,400,-2100,-2189
+ZC8,400,450,-2400,-2497
+ZC8,450,500,-2600,-2697
+ZC9,0,3,-60,-85
+ZC9,3,6,-80,-110
+ZC9,6,10,-97,-133
+ZC9,10,14,-130,-173
+ZC9,14,18,-150,-193
+ZC9,18,24,-188,-240
+ZC9,24,30,-218,-270
+ZC9,30,40,-274,-336
+ZC9,40,50,-325,-387
+ZC9,50,65,-405,-479
+ZC9,65,80,-480,-554
+ZC9,80,100,-585,-672
+ZC9,100,120,-690,-777
+ZC9,120,140,-800,-900
+ZC9,140,160,-900,-1000
+ZC9,160,180,-1000,-1100
+ZC9,180,200,-1150,-1265
+ZC9,200,225,-1250,-1365
+ZC9,225,250,-1350,-1465
+ZC9,250,280,-1550,-1680
+ZC9,280,315,-1700,-1830
+ZC9,315,355,-1900,-2040
+ZC9,355,400,-2100,-2240
+ZC9,400,450,-2400,-2555
+ZC9,450,500,-2600,-2755
+ZC10,0,3,-60,-100
+ZC10,3,6,-80,-128
+ZC10,6,10,-97,-155
+ZC10,10,14,-130,-200
+ZC10,14,18,-150,-220
+ZC10,18,24,-188,-272
+ZC10,24,30,-218,-302
+ZC10,30,40,-274,-374
+ZC10,40,50,-325,-425
+ZC10,50,65,-405,-525
+ZC10,65,80,-480,-600
+ZC10,80,100,-585,-725
+ZC10,100,120,-690,-830
+ZC10,120,140,-800,-960
+ZC10,140,160,-900,-1060
+ZC10,160,180,-1000,-1160
+ZC10,180,200,-1150,-1335
+ZC10,200,225,-1250,-1435
+ZC10,225,250,-1350,-1535
+ZC10,250,280,-1550,-1760
+ZC10,280,315,-1700,-1910
+ZC10,315,355,-1900,-2130
+ZC10,355,400,-2100,-2330
+ZC10,400,450,-2400,-2650
+ZC10,450,500,-2600,-2850
+ZC11,0,3,-60,-120
+ZC11,3,6,-80,-155
+ZC11,6,10,-97,-187
+ZC11,10,14,-130,-240
+ZC11,14,18,-150,-260
+ZC11,18,24,-188,-318
+ZC11,24,30,-218,-348
+ZC11,30,40,-274,-434
+ZC11,40,50,-325,-485
+ZC11,50,65,-405,-595
+ZC11,65,80,-480,-670
+ZC11,80,100,-585,-805
+ZC11,100,120,-690,-910
+ZC11,120,140,-800,-1050
+ZC11,140,160,-900,-1150
+ZC11,160,180,-1000,-1250
+ZC11,180,200,-1150,-1440
+ZC11,200,225,-1250,-1540
+ZC11,225,250,-1350,-1640
+ZC11,250,280,-1550,-1870
+ZC11,280,315,-1700,-2020
+ZC11,315,355,-1900,-2260
+ZC11,355,400,-2100,-2460
+ZC11,400,450,-2400,-2800
+ZC11,450,500,-2600,-3000
+a9,0,3,-270,-295
+a9,3,6,-270,-300
+a9,6,10,-280,-316
+a9,10,18,-290,-333
+a9,18,30,-300,-352
+a9,30,40,-310,-372
+a9,40,50,-320,-382
+a9,50,65,-340,-414
+a9,65,80,-360,-434
+a9,80,100,-380,-467
+a9,100,120,-410,-497
+a9,120,140,-460,-560
+a9,140,160,-520,-620
+a9,160,180,-580,-680
+a9,180,200,-660,-775
+a9,200,225,-740,-855
+a9,225,250,-820,-935
+a9,250,280,-920,-1050
+a9,280,315,-1050,-1180
+a9,315,355,-1200,-1340
+a9,355,400,-1350,-1490
+a9,400,450,-1500,-1655
+a9,450,500,-1650,-1805
+a10,0,3,-270,-310
+a10,3,6,-270,-318
+a10,6,10,-280,-338
+a10,10,18,-290,-360
+a10,18,30,-300,-384
+a10,30,40,-310,-410
+a10,40,50,-320,-420
+a10,50,65,-340,-460
+a10,65,80,-360,-480
+a10,80,100,-380,-520
+a10,100,120,-410,-550
+a10,120,140,-460,-620
+a10,140,160,-520,-680
+a10,160,180,-580,-740
+a10,180,200,-660,-845
+a10,200,225,-740,-925
+a10,225,250,-820,-1005
+a10,250,280,-920,-1130
+a10,280,315,-1050,-1260
+a10,315,355,-1200,-1430
+a10,355,400,-1350,-1580
+a10,400,450,-1500,-1750
+a10,450,500,-1650,-1900
+a11,0,3,-270,-330
+a11,3,6,-270,-345
+a11,6,10,-280,-370
+a11,10,18,-290,-400
+a11,18,30,-300,-430
+a11,30,40,-310,-470
+a11,40,50,-320,-480
+a11,50,65,-340,-530
+a11,65,80,-360,-550
+a11,80,100,-380,-600
+a11,100,120,-410,-630
+a11,120,140,-460,-710
+a11,140,160,-520,-770
+a11,160,180,-580,-830
+a11,180,200,-660,-950
+a11,200,225,-740,-1030
+a11,225,250,-820,-1110
+a11,250,280,-920,-1240
+a11,280,315,-1050,-1370
+a11,315,355,-1200,-1560
+a11,355,400,-1350,-1710
+a11,400,450,-1500,-1900
+a11,450,500,-1650,-2050
+a12,0,3,-270,-370
+a12,3,6,-270,-390
+a12,6,10,-280,-430
+a12,10,18,-290,-470
+a12,18,30,-300,-510
+a12,30,40,-310,-560
+a12,40,50,-320,-570
+a12,50,65,-340,-640
+a12,65,80,-360,-660
+a12,80,100,-380,-730
+a12,100,120,-410,-760
+a12,120,140,-460,-860
+a12,140,160,-520,-920
+a12,160,180,-580,-980
+a12,180,200,-660,-1120
+a12,200,225,-740,-1200
+a12,225,250,-820,-1280
+a12,250,280,-920,-1440
+a12,280,315,-1050,-1570
+a12,315,355,-1200,-1770
+a12,355,400,-1350,-1920
+a12,400,450,-1500,-2130
+a12,450,500,-1650,-2280
+a13,0,3,-270,-410
+a13,3,6,-270,-450
+a13,6,10,-280,-500
+a13,10,18,-290,-560
+a13,18,30,-300,-630
+a13,30,40,-310,-700
+a13,40,50,-320,-710
+a13,50,65,-340,-800
+a13,65,80,-360,-820
+a13,80,100,-380,-920
+a13,100,120,-410,-950
+a13,120,140,-460,-1090
+a13,140,160,-520,-1150
+a13,160,180,-580,-1210
+a13,180,200,-660,-1380
+a13,200,225,-740,-1460
+a13,225,250,-820,-1540
+a13,250,280,-920,-1730
+a13,280,315,-1050,-1860
+a13,315,355,-1200,-2090
+a13,355,400,-1350,-2240
+a13,400,450,-1500,-2470
+a13,450,500,-1650,-2620
+b8,0,3,-140,-154
+b8,3,6,-140,-158
+b8,6,10,-150,-172
+b8,10,18,-150,-177
+b8,18,30,-160,-193
+b8,30,40,-170,-209
+b8,40,50,-180,-219
+b8,50,65,-190,-236
+b8,65,80,-200,-246
+b8,80,100,-220,-274
+b8,100,120,-240,-294
+b8,120,140,-260,-323
+b8,140,160,-280,-343
+b8,160,180,-310,-373
+b8,180,200,-340,-412
+b8,200,225,-380,-452
+b8,225,250,-420,-492
+b8,250,280,-480,-561
+b8,280,315,-540,-621
+b8,315,355,-600,-689
+b8,355,400,-680,-769
+b8,400,450,-760,-857
+b8,450,500,-840,-937
+b9,0,3,-140,-165
+b9,3,6,-140,-170
+b9,6,10,-150,-186
+b9,10,18,-150,-193
+b9,18,30,-160,-212
+b9,30,40,-170,-232
+b9,40,50,-180,-242
+b9,50,65,-190,-264
+b9,65,80,-200,-274
+b9,80,100,-220,-307
+b9,100,120,-240,-327
+b9,120,140,-260,-360
+b9,140,160,-280,-380
+b9,160,180,-310,-410
+b9,180,200,-340,-455
+b9,200,225,-380,-495
+b9,225,250,-420,-535
+b9,250,280,-480,-610
+b9,280,315,-540,-670
+b9,315,355,-600,-740
+b9,355,400,-680,-820
+b9,400,450,-760,-915
+b9,450,500,-840,-995
+b10,0,3,-140,-180
+b10,3,6,-140,-188
+b10,6,10,-150,-208
+b10,10,18,-150,-220
+b10,18,30,-160,-244
+b10,30,40,-170,-270
+b10,40,50,-180,-280
+b10,50,65,-190,-310
+b10,65,80,-200,-320
+b10,80,100,-220,-360
+b10,100,120,-240,-380
+b10,120,140,-260,-420
+b10,140,160,-280,-440
+b10,160,180,-310,-470
+b10,180,200,-340,-525
+b10,200,225,-380,-565
+b10,225,250,-420,-605
+b10,250,280,-480,-690
+b10,280,315,-540,-750
+b10,315,355,-600,-830
+b10,355,400,-680,-910
+b10,400,450,-760,-1010
+b10,450,500,-840,-1090
+b11,0,3,-140,-200
+b11,3,6,-140,-215
+b11,6,10,-150,-240
+b11,10,18,-150,-260
+b11,18,30,-160,-290
+b11,30,40,-170,-330
+b11,40,50,-180,-340
+b11,50,65,-190,-380
+b11,65,80,-200,-390
+b11,80,100,-220,-440
+b11,100,120,-240,-460
+b11,120,140,-260,-510
+b11,140,160,-280,-530
+b11,160,180,-310,-560
+b11,180,200,-340,-630
+b11,200,225,-380,-670
+b11,225,250,-420,-710
+b11,250,280,-480,-800
+b11,280,315,-540,-860
+b11,315,355,-600,-960
+b11,355,400,-680,-1040
+b11,400,450,-760,-1160
+b11,450,500,-840,-1240
+b12,0,3,-140,-240
+b12,3,6,-140,-260
+b12,6,10,-150,-300
+b12,10,18,-150,-330
+b12,18,30,-160,-370
+b12,30,40,-170,-420
+b12,40,50,-180,-430
+b12,50,65,-190,-490
+b12,65,80,-200,-500
+b12,80,100,-220,-570
+b12,100,120,-240,-590
+b12,120,140,-260,-660
+b12,140,160,-280,-680
+b12,160,180,-310,-710
+b12,180,200,-340,-800
+b12,200,225,-380,-840
+b12,225,250,-420,-880
+b12,250,280,-480,-1000
+b12,280,315,-540,-1060
+b12,315,355,-600,-1170
+b12,355,400,-680,-1250
+b12,400,450,-760,-1390
+b12,450,500,-840,-1470
+b13,0,3,-140,-280
+b13,3,6,-140,-320
+b13,6,10,-150,-370
+b13,10,18,-150,-420
+b13,18,30,-160,-490
+b13,30,40,-170,-560
+b13,40,50,-180,-570
+b13,50,65,-190,-650
+b13,65,80,-200,-660
+b13,80,100,-220,-760
+b13,100,120,-240,-780
+b13,120,140,-260,-890
+b13,140,160,-280,-910
+b13,160,180,-310,-940
+b13,180,200,-340,-1060
+b13,200,225,-380,-1100
+b13,225,250,-420,-1140
+b13,250,280,-480,-1290
+b13,280,315,-540,-1350
+b13,315,355,-600,-1490
+b13,355,400,-680,-1570
+b13,400,450,-760,-1730
+b13,450,500,-840,-1810
+c8,0,3,-60,-74
+c8,3,6,-70,-88
+c8,6,10,-80,-102
+c8,10,18,-95,-122
+c8,18,30,-110,-143
+c8,30,40,-120,-159
+c8,40,50,-130,-169
+c8,50,65,-140,-186
+c8,65,80,-150,-196
+c8,80,100,-170,-224
+c8,100,120,-180,-234
+c8,120,140,-200,-263
+c8,140,160,-210,-273
+c8,160,180,-230,-293
+c8,180,200,-240,-312
+c8,200,225,-260,-332
+c8,225,250,-280,-352
+c8,250,280,-300,-381
+c8,280,315,-330,-411
+c8,315,355,-360,-449
+c8,355,400,-400,-489
+c8,400,450,-440,-537
+c8,450,500,-480,-577
+c9,0,3,-60,-85
+c9,3,6,-70,-100
+c9,6,10,-80,-116
+c9,10,18,-95,-138
+c9,18,30,-110,-162
+c9,30,40,-120,-182
+c9,40,50,-130,-192
+c9,50,65,-140,-214
+c9,65,80,-150,-224
+c9,80,100,-170,-257
+c9,100,120,-180,-267
+c9,120,140,-200,-300
+c9,140,160,-210,-310
+c9,160,180,-230,-330
+c9,180,200,-240,-355
+c9,200,225,-260,-375
+c9,225,250,-280,-395
+c9,250,280,-300,-430
+c9,280,315,-330,-460
+c9,315,355,-360,-500
+c9,355,400,-400,-540
+c9,400,450,-440,-595
+c9,450,500,-480,-635
+c10,0,3,-60,-100
+c10,3,6,-70,-118
+c10,6,10,-80,-138
+c10,10,18,-95,-165
+c10,18,30,-110,-194
+c10,30,40,-120,-220
+c10,40,50,-130,-230
+c10,50,65,-140,-260
+c10,65,80,-150,-270
+c10,80,100,-170,-310
+c10,100,120,-180,-320
+c10,120,140,-200,-360
+c10,140,160,-210,-370
+c10,160,180,-230,-390
+c10,180,200,-240,-425
+c10,200,225,-260,-445
+c10,225,250,-280,-465
+c10,250,280,-300,-510
+c10,280,315,-330,-540
+c10,315,355,-360,-590
+c10,355,400,-400,-630
+c10,400,450,-440,-690
+c10,450,500,-480,-730
+c11,0,3,-60,-120
+c11,3,6,-70,-145
+c11,6,10,-80,-170
+c11,10,18,-95,-205
+c11,18,30,-110,-240
+c11,30,40,-120,-280
+c11,40,50,-130,-290
+c11,50,65,-140,-330
+c11,65,80,-150,-340
+c11,80,100,-170,-390
+c11,100,120,-180,-400
+c11,120,140,-200,-450
+c11,140,160,-210,-460
+c11,160,180,-230,-480
+c11,180,200,-240,-530
+c11,200,225,-260,-550
+c11,225,250,-280,-570
+c11,250,280,-300,-620
+c11,280,315,-330,-650
+c11,315,355,-360,-720
+c11,355,400,-400,-760
+c11,400,450,-440,-840
+c11,450,500,-480,-880
+c12,0,3,-60,-160
+c12,3,6,-70,-190
+c12,6,10,-80,-230
+c12,10,18,-95,-275
+c12,18,30,-110,-320
+c12,30,40,-120,-370
+c12,40,50,-130,-380
+c12,50,65,-140,-440
+c12,65,80,-150,-450
+c12,80,100,-170,-520
+c12,100,120,-180,-530
+c12,120,140,-200,-600
+c12,140,160,-210,-610
+c12,160,180,-230,-630
+c12,180,200,-240,-700
+c12,200,225,-260,-720
+c12,225,250,-280,-740
+c12,250,280,-300,-820
+c12,280,315,-330,-850
+c12,315,355,-360,-930
+c12,355,400,-400,-970
+c12,400,450,-440,-1070
+c12,450,500,-480,-1110
+cd5,0,3,-34,-38
+cd5,3,6,-46,-51
+cd5,6,10,-56,-62
+cd6,0,3,-34,-40
+cd6,3,6,-46,-54
+cd6,6,10,-56,-65
+cd7,0,3,-34,-44
+cd7,3,6,-46,-58
+cd7,6,10,-56,-71
+cd8,0,3,-34,-48
+cd8,3,6,-46,-64
+cd8,6,10,-56,-78
+cd9,0,3,-34,-59
+cd9,3,6,-46,-76
+cd9,6,10,-56,-92
+cd10,0,3,-34,-74
+cd10,3,6,-46,-94
+cd10,6,10,-56,-114
+d5,0,3,-20,-24
+d5,3,6,-30,-35
+d5,6,10,-40,-46
+d5,10,18,-50,-58
+d5,18,30,-65,-74
+d5,30,50,-80,-91
+d5,50,80,-100,-113
+d5,80,120,-120,-135
+d5,120,180,-145,-163
+d5,180,250,-170,-190
+d5,250,315,-190,-213
+d5,315,400,-210,-235
+d5,400,500,-230,-257
+d5,500,630,-260,-292
+d5,630,800,-290,-326
+d5,800,1000,-320,-360
+d5,1000,1250,-350,-397
+d5,1250,1600,-390,-445
+d5,1600,2000,-430,-495
+d5,2000,2500,-480,-558
+d5,2500,3150,-520,-616
+d6,0,3,-20,-26
+d6,3,6,-30,-38
+d6,6,10,-40,-49
+d6,10,18,-50,-61
+d6,18,30,-65,-78
+d6,30,50,-80,-96
+d6,50,80,-100,-119
+d6,80,120,-120,-142
+d6,120,180,-145,-170
+d6,180,250,-170,-199
+d6,250,315,-190,-222
+d6,315,400,-210,-246
+d6,400,500,-230,-270
+d6,500,630,-260,-304
+d6,630,800,-290,-340
+d6,800,1000,-320,-376
+d6,1000,1250,-350,-416
+d6,1250,1600,-390,-468
+d6,1600,2000,-430,-522
+d6,2000,2500,-480,-590
+d6,2500,3150,-520,-655
+d7,0,3,-20,-30
+d7,3,6,-30,-42
+d7,6,10,-40,-55
+d7,10,18,-50,-68
+d7,18,30,-65,-86
+d7,30,50,-80,-105
+d7,50,80,-100,-130
+d7,80,120,-120,-155
+d7,120,180,-145,-185
+d7,180,250,-170,-216
+d7,250,315,-190,-242
+d7,315,400,-210,-267
+d7,400,500,-230,-293
+d7,500,630,-260,-330
+d7,630,800,-290,-370
+d7,800,1000,-320,-410
+d7,1000,1250,-350,-455
+d7,1250,1600,-390,-515
+d7,1600,2000,-430,-580
+d7,2000,2500,-480,-655
+d7,2500,3150,-520,-730
+d8,0,3,-20,-34
+d8,3,6,-30,-48
+d8,6,10,-40,-62
+d8,10,18,-50,-77
+d8,18,30,-65,-98
+d8,30,50,-80,-119
+d8,50,80,-100,-146
+d8,80,120,-120,-174
+d8,120,180,-145,-208
+d8,180,250,-170,-242
+d8,250,315,-190,-271
+d8,315,400,-210,-299
+d8,400,500,-230,-327
+d8,500,630,-260,-370
+d8,630,800,-290,-415
+d8,800,1000,-320,-460
+d8,1000,1250,-350,-515
+d8,1250,1600,-390,-585
+d8,1600,2000,-430,-660
+d8,2000,2500,-480,-760
+d8,2500,3150,-520,-850
+d9,0,3,-20,-45
+d9,3,6,-30,-60
+d9,6,10,-40,-76
+d9,10,18,-50,-93
+d9,18,30,-65,-117
+d9,30,50,-80,-142
+d9,50,80,-100,-174
+d9,80,120,-120,-207
+d9,120,180,-145,-245
+d9,180,250,-170,-285
+d9,250,315,-190,-320
+d9,315,400,-210,-350
+d9,400,500,-230,-385
+d9,500,630,-260,-435
+d9,630,800,-290,-490
+d9,800,1000,-320,-550
+d9,1000,1250,-350,-610
+d9,1250,1600,-390,-700
+d9,1600,2000,-430,-800
+d9,2000,2500,-480,-920
+d9,2500,3150,-520,-1060
+d10,0,3,-20,-60
+d10,3,6,-30,-78
+d10,6,10,-40,-98
+d10,10,18,-50,-120
+d10,18,30,-65,-149
+d10,30,50,-80,-180
+d10,50,80,-100,-220
+d10,80,120,-120,-260
+d10,120,180,-145,-305
+d10,180,250,-170,-355
+d10,250,315,-190,-400
+d10,315,400,-210,-440
+d10,400,500,-230,-480
+d10,500,630,-260,-540
+d10,630,800,-290,-610
+d10,800,1000,-320,-680
+d10,1000,1250,-350,-770
+d10,1250,1600,-390,-890
+d10,1600,2000,-430,-1030
+d10,2000,2500,-480,-1180
+d10,2500,3150,-520,-1380
+d11,0,3,-20,-80
+d11,3,6,-30,-105
+d11,6,10,-40,-130
+d11,10,18,-50,-160
+d11,18,30,-65,-195
+d11,30,50,-80,-240
+d11,50,80,-100,-290
+d11,80,120,-120,-340
+d11,120,180,-145,-395
+d11,180,250,-170,-460
+d11,250,315,-190,-510
+d11,315,400,-210,-570
+d11,400,500,-230,-630
+d11,500,630,-260,-700
+d11,630,800,-290,-790
+d11,800,1000,-320,-880
+d11,1000,1250,-350,-1010
+d11,1250,1600,-390,-1170
+d11,1600,2000,-430,-1350
+d11,2000,2500,-480,-1580
+d11,2500,3150,-520,-1870
+d12,0,3,-20,-120
+d12,3,6,-30,-150
+d12,6,10,-40,-190
+d12,10,18,-50,-230
+d12,18,30,-65,-275
+d12,30,50,-80,-330
+d12,50,80,-100,-400
+d12,80,120,-120,-470
+d12,120,180,-145,-545
+d12,180,250,-170,-630
+d12,250,315,-190,-710
+d12,315,400,-210,-780
+d12,400,500,-230,-860
+d12,500,630,-260,-960
+d12,630,800,-290,-1090
+d12,800,1000,-320,-1220
+d12,1000,1250,-350,-1400
+d12,1250,1600,-390,-1640
+d12,1600,2000,-430,-1930
+d12,2000,2500,-480,-2230
+d12,2500,3150,-520,-2620
+d13,0,3,-20,-160
+d13,3,6,-30,-210
+d13,6,10,-40,-260
+d13,10,18,-50,-320
+d13,18,30,-65,-395
+d13,30,50,-80,-470
+d13,50,80,-100,-560
+d13,80,120,-120,-660
+d13,120,180,-145,-775
+d13,180,250,-170,-890
+d13,250,315,-190,-1000
+d13,315,400,-210,-1100
+d13,400,500,-230,-1200
+d13,500,630,-260,-1360
+d13,630,800,-290,-1540
+d13,800,1000,-320,-1720
+d13,1000,1250,-350,-2000
+d13,1250,1600,-390,-2340
+d13,1600,2000,-430,-2730
+d13,2000,2500,-480,-3280
+d13,2500,3150,-520,-3820
+e5,0,3,-14,-18
+e5,3,6,-20,-25
+e5,6,10,-25,-31
+e5,10,18,-32,-40
+e5,18,30,-40,-49
+e5,30,50,-50,-61
+e5,50,80,-60,-73
+e5,80,120,-72,-87
+e5,120,180,-85,-103
+e5,180,250,-100,-120
+e5,250,315,-110,-133
+e5,315,400,-125,-150
+e5,400,500,-135,-162
+e5,500,630,-145,-177
+e5,630,800,-160,-196
+e5,800,1000,-170,-210
+e5,1000,1250,-195,-242
+e5,1250,1600,-220,-275
+e5,1600,2000,-240,-305
+e5,2000,2500,-260,-338
+e5,2500,3150,-290,-386
+e6,0,3,-14,-20
+e6,3,6,-20,-28
+e6,6,10,-25,-34
+e6,10,18,-32,-43
+e6,18,30,-40,-53
+e6,30,50,-50,-66
+e6,50,80,-60,-79
+e6,80,120,-72,-94
+e6,120,180,-85,-110
+e6,180,250,-100,-129
+e6,250,315,-110,-142
+e6,315,400,-125,-161
+e6,400,500,-135,-175
+e6,500,630,-145,-189
+e6,630,800,-160,-210
+e6,800,1000,-170,-226
+e6,1000,1250,-195,-261
+e6,1250,1600,-220,-298
+e6,1600,2000,-240,-332
+e6,2000,2500,-260,-370
+e6,2500,3150,-290,-425
+e7,0,3,-14,-24
+e7,3,6,-20,-32
+e7,6,10,-25,-40
+e7,10,18,-32,-50
+e7,18,30,-40,-61
+e7,30,50,-50,-75
+e7,50,80,-60,-90
+e7,80,120,-72,-107
+e7,120,180,-85,-125
+e7,180,250,-100,-146
+e7,250,315,-110,-162
+e7,315,400,-125,-182
+e7,400,500,-135,-198
+e7,500,630,-145,-215
+e7,630,800,-160,-240
+e7,800,1000,-170,-260
+e7,1000,1250,-195,-300
+e7,1250,1600,-220,-345
+e7,1600,2000,-240,-390
+e7,2000,2500,-260,-435
+e7,2500,3150,-290,-500
+e8,0,3,-14,-28
+e8,3,6,-20,-38
+e8,6,10,-25,-47
+e8,10,18,-32,-59
+e8,18,30,-40,-73
+e8,30,50,-50,-89
+e8,50,80,-60,-106
+e8,80,120,-72,-126
+e8,120,180,-85,-148
+e8,180,250,-100,-172
+e8,250,315,-110,-191
+e8,315,400,-125,-214
+e8,400,500,-135,-232
+e8,500,630,-145,-255
+e8,630,800,-160,-285
+e8,800,1000,-170,-310
+e8,1000,1250,-195,-360
+e8,1250,1600,-220,-415
+e8,1600,2000,-240,-470
+e8,2000,2500,-260,-540
+e8,2500,3150,-290,-620
+e9,0,3,-14,-39
+e9,3,6,-20,-50
+e9,6,10,-25,-61
+e9,10,18,-32,-75
+e9,18,30,-40,-92
+e9,30,50,-50,-112
+e9,50,80,-60,-134
+e9,80,120,-72,-159
+e9,120,180,-85,-185
+e9,180,250,-100,-215
+e9,250,315,-110,-240
+e9,315,400,-125,-265
+e9,400,500,-135,-290
+e9,500,630,-145,-320
+e9,630,800,-160,-360
+e9,800,1000,-170,-400
+e9,1000,1250,-195,-455
+e9,1250,1600,-220,-530
+e9,1600,2000,-240,-610
+e9,2000,2500,-260,-700
+e9,2500,3150,-290,-830
+e10,0,3,-14,-54
+e10,3,6,-20,-68
+e10,6,10,-25,-83
+e10,10,18,-32,-102
+e10,18,30,-40,-124
+e10,30,50,-50,-150
+e10,50,80,-60,-180
+e10,80,120,-72,-212
+e10,120,180,-85,-245
+e10,180,250,-100,-285
+e10,250,315,-110,-320
+e10,315,400,-125,-355
+e10,400,500,-135,-385
+e10,500,630,-145,-425
+e10,630,800,-160,-480
+e10,800,1000,-170,-530
+e10,1000,1250,-195,-615
+e10,1250,1600,-220,-720
+e10,1600,2000,-240,-840
+e10,2000,2500,-260,-960
+e10,2500,3150,-290,-1150
+ef3,0,3,-10,-12
+ef3,3,6,-14,-16.5
+ef3,6,10,-18,-20.5
+ef4,0,3,-10,-13
+ef4,3,6,-14,-18
+ef4,6,10,-18,-22
+ef5,0,3,-10,-14
+ef5,3,6,-14,-19
+ef5,6,10,-18,-24
+ef6,0,3,-10,-16
+ef6,3,6,-14,-22
+ef6,6,10,-18,-27
+ef7,0,3,-10,-20
+ef7,3,6,-14,-26
+ef7,6,10,-18,-33
+ef8,0,3,-10,-24
+ef8,3,6,-14,-32
+ef8,6,10,-18,-40
+ef9,0,3,-10,-35
+ef9,3,6,-14,-44
+ef9,6,10,-18,-54
+ef10,0,3,-10,-50
+ef10,3,6,-14,-62
+ef10,6,10,-18,-76
+f3,0,3,-6,-8
+f3,3,6,-10,-12.5
+f3,6,10,-13,-15.5
+f3,10,18,-16,-19
+f3,18,30,-20,-24
+f3,30,50,-25,-29
+f3,50,80,-30,-35
+f3,80,120,-36,-42
+f3,120,180,-43,-51
+f3,180,250,-50,-60
+f3,250,315,-56,-68
+f3,315,400,-62,-75
+f3,400,500,-68,-83
+f3,500,630,-76,-92
+f3,630,800,-80,-98
+f3,800,1000,-86,-107
+f3,1000,1250,-98,-122
+f3,1250,1600,-110,-139
+f3,1600,2000,-120,-155
+f3,2000,2500,-130,-171
+f3,2500,3150,-145,-195
+f4,0,3,-6,-9
+f4,3,6,-10,-14
+f4,6,10,-13,-17
+f4,10,18,-16,-21
+f4,18,30,-20,-26
+f4,30,50,-25,-32
+f4,50,80,-30,-38
+f4,80,120,-36,-46
+f4,120,180,-43,-55
+f4,180,250,-50,-64
+f4,250,315,-56,-72
+f4,315,400,-62,-80
+f4,400,500,-68,-88
+f4,500,630,-76,-98
+f4,630,800,-80,-105
+f4,800,1000,-86,-114
+f4,1000,1250,-98,-131
+f4,1250,1600,-110,-149
+f4,1600,2000,-120,-166
+f4,2000,2500,-130,-185
+f4,2500,3150,-145,-213
+f5,0,3,-6,-10
+f5,3,6,-10,-15
+f5,6,10,-13,-19
+f5,10,18,-16,-24
+f5,18,30,-20,-29
+f5,30,50,-25,-36
+f5,50,80,-30,-43
+f5,80,120,-36,-51
+f5,120,180,-43,-61
+f5,180,250,-50,-70
+f5,250,315,-56,-79
+f5,315,400,-62,-87
+f5,400,500,-68,-95
+f5,500,630,-76,-108
+f5,630,800,-80,-116
+f5,800,1000,-86,-126
+f5,1000,1250,-98,-145
+f5,1250,1600,-110,-165
+f5,1600,2000,-120,-185
+f5,2000,2500,-130,-208
+f5,2500,3150,-145,-241
+f6,0,3,-6,-12
+f6,3,6,-10,-18
+f6,6,10,-13,-22
+f6,10,18,-16,-27
+f6,18,30,-20,-33
+f6,30,50,-25,-41
+f6,50,80,-30,-49
+f6,80,120,-36,-58
+f6,120,180,-43,-68
+f6,180,250,-50,-79
+f6,250,315,-56,-88
+f6,315,400,-62,-98
+f6,400,500,-68,-108
+f6,500,630,-76,-120
+f6,630,800,-80,-130
+f6,800,1000,-86,-142
+f6,1000,1250,-98,-164
+f6,1250,1600,-110,-188
+f6,1600,2000,-120,-212
+f6,2000,2500,-130,-240
+f6,2500,3150,-145,-280
+f7,0,3,-6,-16
+f7,3,6,-10,-22
+f7,6,10,-13,-28
+f7,10,18,-16,-34
+f7,18,30,-20,-41
+f7,30,50,-25,-50
+f7,50,80,-30,-60
+f7,80,120,-36,-71
+f7,120,180,-43,-83
+f7,180,250,-50,-96
+f7,250,315,-56,-108
+f7,315,400,-62,-119
+f7,400,500,-68,-131
+f7,500,630,-76,-146
+f7,630,800,-80,-160
+f7,800,1000,-86,-176
+f7,1000,1250,-98,-203
+f7,1250,1600,-110,-235
+f7,1600,2000,-120,-270
+f7,2000,2500,-130,-305
+f7,2500,3150,-145,-355
+f8,0,3,-6,-20
+f8,3,6,-10,-28
+f8,6,10,-13,-35
+f8,10,18,-16,-43
+f8,18,30,-20,-53
+f8,30,50,-25,-64
+f8,50,80,-30,-76
+f8,80,120,-36,-90
+f8,120,180,-43,-106
+f8,180,250,-50,-122
+f8,250,315,-56,-137
+f8,315,400,-62,-151
+f8,400,500,-68,-165
+f8,500,630,-76,-186
+f8,630,800,-80,-205
+f8,800,1000,-86,-226
+f8,1000,1250,-98,-263
+f8,1250,1600,-110,-305
+f8,1600,2000,-120,-350
+f8,2000,2500,-130,-410
+f8,2500,3150,-145,-475
+f9,0,3,-6,-31
+f9,3,6,-10,-40
+f9,6,10,-13,-49
+f9,10,18,-16,-59
+f9,18,30,-20,-72
+f9,30,50,-25,-87
+f9,50,80,-30,-104
+f9,80,120,-36,-123
+f9,120,180,-43,-143
+f9,180,250,-50,-165
+f9,250,315,-56,-186
+f9,315,400,-62,-202
+f9,400,500,-68,-223
+f9,500,630,-76,-251
+f9,630,800,-80,-280
+f9,800,1000,-86,-316
+f9,1000,1250,-98,-358
+f9,1250,1600,-110,-420
+f9,1600,2000,-120,-490
+f9,2000,2500,-130,-570
+f9,2500,3150,-145,-685
+f10,0,3,-6,-46
+f10,3,6,-10,-58
+f10,6,10,-13,-71
+f10,10,18,-16,-86
+f10,18,30,-20,-104
+f10,30,50,-25,-125
+f10,50,80,-30,-150
+f10,80,120,-36,-176
+f10,120,180,-43,-203
+f10,180,250,-50,-235
+f10,250,315,-56,-266
+f10,315,400,-62,-292
+f10,400,500,-68,-318
+f10,500,630,-76,-356
+f10,630,800,-80,-400
+f10,800,1000,-86,-446
+f10,1000,1250,-98,-518
+f10,1250,1600,-110,-610
+f10,1600,2000,-120,-720
+f10,2000,2500,-130,-830
+f10,2500,3150,-145,-1005
+fg3,0,3,-4,-6
+fg3,3,6,-6,-8.5
+fg3,6,10,-8,-10.5
+fg4,0,3,-4,-7
+fg4,3,6,-6,-10
+fg4,6,10,-8,-12
+fg5,0,3,-4,-8
+fg5,3,6,-6,-11
+fg5,6,10,-8,-14
+fg6,0,3,-4,-10
+fg6,3,6,-6,-14
+fg6,6,10,-8,-17
+fg7,0,3,-4,-14
+fg7,3,6,-6,-18
+fg7,6,10,-8,-23
+fg8,0,3,-4,-18
+fg8,3,6,-6,-24
+fg8,6,10,-8,-30
+fg9,0,3,-4,-29
+fg9,3,6,-6,-36
+fg9,6,10,-8,-44
+fg10,0,3,-4,-44
+fg10,3,6,-6,-54
+fg10,6,10,-8,-66
+g3,0,3,-2,-4
+g3,3,6,-4,-6.5
+g3,6,10,-5,-7.5
+g3,10,18,-6,-9
+g3,18,30,-7,-11
+g3,30,50,-9,-13
+g3,50,80,-10,-15
+g3,80,120,-12,-18
+g3,120,180,-14,-22
+g3,180,250,-15,-25
+g3,250,315,-17,-29
+g3,315,400,-18,-31
+g3,400,500,-20,-35
+g3,500,630,-22,-38
+g3,630,800,-24,-42
+g3,800,1000,-26,-47
+g3,1000,1250,-28,-52
+g3,1250,1600,-30,-59
+g3,1600,2000,-32,-67
+g3,2000,2500,-34,-75
+g3,2500,3150,-38,-88
+g4,0,3,-2,-5
+g4,3,6,-4,-8
+g4,6,10,-5,-9
+g4,10,18,-6,-11
+g4,18,30,-7,-13
+g4,30,50,-9,-16
+g4,50,80,-10,-18
+g4,80,120,-12,-22
+g4,120,180,-14,-26
+g4,180,250,-15,-29
+g4,250,315,-17,-33
+g4,315,400,-18,-36
+g4,400,500,-20,-40
+g4,500,630,-22,-44
+g4,630,800,-24,-49
+g4,800,1000,-26,-54
+g4,1000,1250,-28,-61
+g4,1250,1600,-30,-69
+g4,1600,2000,-32,-78
+g4,2000,2500,-34,-89
+g4,2500,3150,-38,-106
+g5,0,3,-2,-6
+g5,3,6,-4,-9
+g5,6,10,-5,-11
+g5,10,18,-6,-14
+g5,18,30,-7,-16
+g5,30,50,-9,-20
+g5,50,80,-10,-23
+g5,80,120,-12,-27
+g5,120,180,-14,-32
+g5,180,250,-15,-35
+g5,250,315,-17,-40
+g5,315,400,-18,-43
+g5,400,500,-20,-47
+g5,500,630,-22,-54
+g5,630,800,-24,-60
+g5,800,1000,-26,-66
+g5,1000,1250,-28,-75
+g5,1250,1600,-30,-85
+g5,1600,2000,-32,-97
+g5,2000,2500,-34,-112
+g5,2500,3150,-38,-134
+g6,0,3,-2,-8
+g6,3,6,-4,-12
+g6,6,10,-5,-14
+g6,10,18,-6,-17
+g6,18,30,-7,-20
+g6,30,50,-9,-25
+g6,50,80,-10,-29
+g6,80,120,-12,-34
+g6,120,180,-14,-39
+g6,180,250,-15,-44
+g6,250,315,-17,-49
+g6,315,400,-18,-54
+g6,400,500,-20,-60
+g6,500,630,-22,-66
+g6,630,800,-24,-74
+g6,800,1000,-26,-82
+g6,1000,1250,-28,-94
+g6,1250,1600,-30,-108
+g6,1600,2000,-32,-124
+g6,2000,2500,-34,-144
+g6,2500,3150,-38,-173
+g7,0,3,-2,-12
+g7,3,6,-4,-16
+g7,6,10,-5,-20
+g7,10,18,-6,-24
+g7,18,30,-7,-28
+g7,30,50,-9,-34
+g7,50,80,-10,-40
+g7,80,120,-12,-47
+g7,120,180,-14,-54
+g7,180,250,-15,-61
+g7,250,315,-17,-69
+g7,315,400,-18,-75
+g7,400,500,-20,-83
+g7,500,630,-22,-92
+g7,630,800,-24,-104
+g7,800,1000,-26,-116
+g7,1000,1250,-28,-133
+g7,1250,1600,-30,-155
+g7,1600,2000,-32,-182
+g7,2000,2500,-34,-209
+g7,2500,3150,-38,-248
+g8,0,3,-2,-16
+g8,3,6,-4,-22
+g8,6,10,-5,-27
+g8,10,18,-6,-33
+g8,18,30,-7,-40
+g8,30,50,-9,-48
+g8,50,80,-10,-56
+g8,80,120,-12,-66
+g8,120,180,-14,-77
+g8,180,250,-15,-87
+g8,250,315,-17,-98
+g8,315,400,-18,-107
+g8,400,500,-20,-117
+g8,500,630,-22,-132
+g8,630,800,-24,-149
+g8,800,1000,-26,-166
+g8,1000,1250,-28,-193
+g8,1250,1600,-30,-225
+g8,1600,2000,-32,-262
+g8,2000,2500,-34,-314
+g8,2500,3150,-38,-368
+g9,0,3,-2,-27
+g9,3,6,-4,-34
+g9,6,10,-5,-41
+g9,10,18,-6,-49
+g9,18,30,-7,-59
+g9,30,50,-9,-71
+g9,50,80,-10,-84
+g9,80,120,-12,-99
+g9,120,180,-14,-114
+g9,180,250,-15,-130
+g9,250,315,-17,-147
+g9,315,400,-18,-158
+g9,400,500,-20,-175
+g9,500,630,-22,-197
+g9,630,800,-24,-224
+g9,800,1000,-26,-256
+g9,1000,1250,-28,-288
+g9,1250,1600,-30,-340
+g9,1600,2000,-32,-402
+g9,2000,2500,-34,-474
+g9,2500,3150,-38,-578
+g10,0,3,-2,-42
+g10,3,6,-4,-52
+g10,6,10,-5,-63
+g10,10,18,-6,-76
+g10,18,30,-7,-91
+g10,30,50,-9,-109
+g10,50,80,-10,-130
+g10,80,120,-12,-152
+g10,120,180,-14,-174
+g10,180,250,-15,-200
+g10,250,315,-17,-227
+g10,315,400,-18,-248
+g10,400,500,-20,-270
+g10,500,630,-22,-302
+g10,630,800,-24,-344
+g10,800,1000,-26,-386
+g10,1000,1250,-28,-448
+g10,1250,1600,-30,-530
+g10,1600,2000,-32,-632
+g10,2000,2500,-34,-734
+g10,2500,3150,-38,-898
+h01,0,3,0,-0.3
+h01,3,10,0,-0.4
+h01,10,18,0,-0.5
+h01,18,50,0,-0.6
+h01,50,80,0,-0.8
+h01,80,120,0,-1
+h01,120,180,0,-1.2
+h01,180,250,0,-2
+h01,250,315,0,-2.5
+h01,315,400,0,-3
+h01,400,500,0,-4
+h0,0,3,0,-0.5
+h0,3,10,0,-0.6
+h0,10,18,0,-0.8
+h0,18,50,0,-1
+h0,50,80,0,-1.2
+h0,80,120,0,-1.5
+h0,120,180,0,-2
+h0,180,250,0,-3
+h0,250,315,0,-4
+h0,315,400,0,-5
+h0,400,500,0,-6
+h1,0,3,0,-0.8
+h1,3,10,0,-1
+h1,10,18,0,-1.2
+h1,18,50,0,-1.5
+h1,50,80,0,-2
+h1,80,120,0,-2.5
+h1,120,180,0,-3.5
+h1,180,250,0,-4.5
+h1,250,315,0,-6
+h1,315,400,0,-7
+h1,400,500,0,-8
+h1,500,630,0,-9
+h1,630,800,0,-10
+h1,800,1000,0,-11
+h1,1000,1250,0,-13
+h1,1250,1600,0,-15
+h1,1600,2000,0,-18
+h1,2000,2500,0,-22
+h1,2500,3150,0,-26
+h2,0,3,0,-1.2
+h2,3,10,0,-1.5
+h2,10,18,0,-2
+h2,18,50,0,-2.5
+h2,50,80,0,-3
+h2,80,120,0,-4
+h2,120,180,0,-5
+h2,180,250,0,-7
+h2,250,315,0,-8
+h2,315,400,0,-9
+h2,400,500,0,-10
+h2,500,630,0,-11
+h2,630,800,0,-13
+h2,800,1000,0,-15
+h2,1000,1250,0,-18
+h2,1250,1600,0,-21
+h2,1600,2000,0,-25
+h2,2000,2500,0,-30
+h2,2500,3150,0,-36
+h3,0,3,0,-2
+h3,3,10,0,-2.5
+h3,10,18,0,-3
+h3,18,50,0,-4
+h3,50,80,0,-5
+h3,80,120,0,-6
+h3,120,180,0,-8
+h3,180,250,0,-10
+h3,250,315,0,-12
+h3,315,400,0,-13
+h3,400,500,0,-15
+h3,500,630,0,-16
+h3,630,800,0,-18
+h3,800,1000,0,-21
+h3,1000,1250,0,-24
+h3,1250,1600,0,-29
+h3,1600,2000,0,-35
+h3,2000,2500,0,-41
+h3,2500,3150,0,-50
+h4,0,3,0,-3
+h4,3,10,0,-4
+h4,10,18,0,-5
+h4,18,30,0,-6
+h4,30,50,0,-7
+h4,50,80,0,-8
+h4,80,120,0,-10
+h4,120,180,0,-12
+h4,180,250,0,-14
+h4,250,315,0,-16
+h4,315,400,0,-18
+h4,400,500,0,-20
+h4,500,630,0,-22
+h4,630,800,0,-25
+h4,800,1000,0,-28
+h4,1000,1250,0,-33
+h4,1250,1600,0,-39
+h4,1600,2000,0,-46
+h4,2000,2500,0,-55
+h4,2500,3150,0,-68
+h5,0,3,0,-4
+h5,3,6,0,-5
+h5,6,10,0,-6
+h5,10,18,0,-8
+h5,18,30,0,-9
+h5,30,50,0,-11
+h5,50,80,0,-13
+h5,80,120,0,-15
+h5,120,180,0,-18
+h5,180,250,0,-20
+h5,250,315,0,-23
+h5,315,400,0,-25
+h5,400,500,0,-27
+h5,500,630,0,-32
+h5,630,800,0,-36
+h5,800,1000,0,-40
+h5,1000,1250,0,-47
+h5,1250,1600,0,-55
+h5,1600,2000,0,-65
+h5,2000,2500,0,-78
+h5,2500,3150,0,-96
+h6,0,3,0,-6
+h6,3,6,0,-8
+h6,6,10,0,-9
+h6,10,18,0,-11
+h6,18,30,0,-13
+h6,30,50,0,-16
+h6,50,80,0,-19
+h6,80,120,0,-22
+h6,120,180,0,-25
+h6,180,250,0,-29
+h6,250,315,0,-32
+h6,315,400,0,-36
+h6,400,500,0,-40
+h6,500,630,0,-44
+h6,630,800,0,-50
+h6,800,1000,0,-56
+h6,1000,1250,0,-66
+h6,1250,1600,0,-78
+h6,1600,2000,0,-92
+h6,2000,2500,0,-110
+h6,2500,3150,0,-135
+h7,0,3,0,-10
+h7,3,6,0,-12
+h7,6,10,0,-15
+h7,10,18,0,-18
+h7,18,30,0,-21
+h7,30,50,0,-25
+h7,50,80,0,-30
+h7,80,120,0,-35
+h7,120,180,0,-40
+h7,180,250,0,-46
+h7,250,315,0,-52
+h7,315,400,0,-57
+h7,400,500,0,-63
+h7,500,630,0,-70
+h7,630,800,0,-80
+h7,800,1000,0,-90
+h7,1000,1250,0,-105
+h7,1250,1600,0,-125
+h7,1600,2000,0,-150
+h7,2000,2500,0,-175
+h7,2500,3150,0,-210
+h8,0,3,0,-14
+h8,3,6,0,-18
+h8,6,10,0,-22
+h8,10,18,0,-27
+h8,18,30,0,-33
+h8,30,50,0,-39
+h8,50,80,0,-46
+h8,80,120,0,-54
+h8,120,180,0,-63
+h8,180,250,0,-72
+h8,250,315,0,-81
+h8,315,400,0,-89
+h8,400,500,0,-97
+h8,500,630,0,-110
+h8,630,800,0,-125
+h8,800,1000,0,-140
+h8,1000,1250,0,-165
+h8,1250,1600,0,-195
+h8,1600,2000,0,-230
+h8,2000,2500,0,-280
+h8,2500,3150,0,-330
+h9,0,3,0,-25
+h9,3,6,0,-30
+h9,6,10,0,-36
+h9,10,18,0,-43
+h9,18,30,0,-52
+h9,30,50,0,-62
+h9,50,80,0,-74
+h9,80,120,0,-87
+h9,120,180,0,-100
+h9,180,250,0,-115
+h9,250,315,0,-130
+h9,315,400,0,-140
+h9,400,500,0,-155
+h9,500,630,0,-175
+h9,630,800,0,-200
+h9,800,1000,0,-230
+h9,1000,1250,0,-260
+h9,1250,1600,0,-310
+h9,1600,2000,0,-370
+h9,2000,2500,0,-440
+h9,2500,3150,0,-540
+h10,0,3,0,-40
+h10,3,6,0,-48
+h10,6,10,0,-58
+h10,10,18,0,-70
+h10,18,30,0,-84
+h10,30,50,0,-100
+h10,50,80,0,-120
+h10,80,120,0,-140
+h10,120,180,0,-160
+h10,180,250,0,-185
+h10,250,315,0,-210
+h10,315,400,0,-230
+h10,400,500,0,-250
+h10,500,630,0,-280
+h10,630,800,0,-320
+h10,800,1000,0,-360
+h10,1000,1250,0,-420
+h10,1250,1600,0,-500
+h10,1600,2000,0,-600
+h10,2000,2500,0,-700
+h10,2500,3150,0,-860
+h11,0,3,0,-60
+h11,3,6,0,-75
+h11,6,10,0,-90
+h11,10,18,0,-110
+h11,18,30,0,-130
+h11,30,50,0,-160
+h11,50,80,0,-190
+h11,80,120,0,-220
+h11,120,180,0,-250
+h11,180,250,0,-290
+h11,250,315,0,-320
+h11,315,400,0,-360
+h11,400,500,0,-400
+h11,500,630,0,-440
+h11,630,800,0,-500
+h11,800,1000,0,-560
+h11,1000,1250,0,-660
+h11,1250,1600,0,-780
+h11,1600,2000,0,-920
+h11,2000,2500,0,-1100
+h11,2500,3150,0,-1350
+h12,0,3,0,-100
+h12,3,6,0,-120
+h12,6,10,0,-150
+h12,10,18,0,-180
+h12,18,30,0,-210
+h12,30,50,0,-250
+h12,50,80,0,-300
+h12,80,120,0,-350
+h12,120,180,0,-400
+h12,180,250,0,-460
+h12,250,315,0,-520
+h12,315,400,0,-570
+h12,400,500,0,-630
+h12,500,630,0,-700
+h12,630,800,0,-800
+h12,800,1000,0,-900
+h12,1000,1250,0,-1050
+h12,1250,1600,0,-1250
+h12,1600,2000,0,-1500
+h12,2000,2500,0,-1750
+h12,2500,3150,0,-2100
+h13,0,3,0,-140
+h13,3,6,0,-180
+h13,6,10,0,-220
+h13,10,18,0,-270
+h13,18,30,0,-330
+h13,30,50,0,-390
+h13,50,80,0,-460
+h13,80,120,0,-540
+h13,120,180,0,-630
+h13,180,250,0,-720
+h13,250,315,0,-810
+h13,315,400,0,-890
+h13,400,500,0,-970
+h13,500,630,0,-1100
+h13,630,800,0,-1250
+h13,800,1000,0,-1400
+h13,1000,1250,0,-1650
+h13,1250,1600,0,-1950
+h13,1600,2000,0,-2300
+h13,2000,2500,0,-2800
+h13,2500,3150,0,-3300
+h14,0,3,0,-250
+h14,3,6,0,-300
+h14,6,10,0,-360
+h14,10,18,0,-430
+h14,18,30,0,-520
+h14,30,50,0,-620
+h14,50,80,0,-740
+h14,80,120,0,-870
+h14,120,180,0,-1000
+h14,180,250,0,-1150
+h14,250,315,0,-1300
+h14,315,400,0,-1400
+h14,400,500,0,-1550
+h14,500,630,0,-1750
+h14,630,800,0,-2000
+h14,800,1000,0,-2300
+h14,1000,1250,0,-2600
+h14,1250,1600,0,-3100
+h14,1600,2000,0,-3700
+h14,2000,2500,0,-4400
+h14,2500,3150,0,-5400
+h15,0,3,0,-400
+h15,3,6,0,-480
+h15,6,10,0,-580
+h15,10,18,0,-700
+h15,18,30,0,-840
+h15,30,50,0,-1000
+h15,50,80,0,-1200
+h15,80,120,0,-1400
+h15,120,180,0,-1600
+h15,180,250,0,-1850
+h15,250,315,0,-2100
+h15,315,400,0,-2300
+h15,400,500,0,-2500
+h15,500,630,0,-2800
+h15,630,800,0,-3200
+h15,800,1000,0,-3600
+h15,1000,1250,0,-4200
+h15,1250,1600,0,-5000
+h15,1600,2000,0,-6000
+h15,2000,2500,0,-7000
+h15,2500,3150,0,-8600
+h16,0,3,0,-600
+h16,3,6,0,-750
+h16,6,10,0,-900
+h16,10,18,0,-1100
+h16,18,30,0,-1300
+h16,30,50,0,-1600
+h16,50,80,0,-1900
+h16,80,120,0,-2200
+h16,120,180,0,-2500
+h16,180,250,0,-2900
+h16,250,315,0,-3200
+h16,315,400,0,-3600
+h16,400,500,0,-4000
+h16,500,630,0,-4400
+h16,630,800,0,-5000
+h16,800,1000,0,-5600
+h16,1000,1250,0,-6600
+h16,1250,1600,0,-7800
+h16,1600,2000,0,-9200
+h16,2000,2500,0,-11000
+h16,2500,3150,0,-13500
+h17,0,3,0,-1000
+h17,3,6,0,-1200
+h17,6,10,0,-1500
+h17,10,18,0,-1800
+h17,18,30,0,-2100
+h17,30,50,0,-2500
+h17,50,80,0,-3000
+h17,80,120,0,-3500
+h17,120,180,0,-4000
+h17,180,250,0,-4600
+h17,250,315,0,-5200
+h17,315,400,0,-5700
+h17,400,500,0,-6300
+h17,500,630,0,-7000
+h17,630,800,0,-8000
+h17,800,1000,0,-9000
+h17,1000,1250,0,-10500
+h17,1250,1600,0,-12500
+h17,1600,2000,0,-15000
+h17,2000,2500,0,-17500
+h17,2500,3150,0,-21000
+h18,0,3,0,-1400
+h18,3,6,0,-1800
+h18,6,10,0,-2200
+h18,10,18,0,-2700
+h18,18,30,0,-3300
+h18,30,50,0,-3900
+h18,50,80,0,-4600
+h18,80,120,0,-5400
+h18,120,180,0,-6300
+h18,180,250,0,-7200
+h18,250,315,0,-8100
+h18,315,400,0,-8900
+h18,400,500,0,-9700
+h18,500,630,0,-11000
+h18,630,800,0,-12500
+h18,800,1000,0,-14000
+h18,1000,1250,0,-16500
+h18,1250,1600,0,-19500
+h18,1600,2000,0,-23000
+h18,2000,2500,0,-28000
+h18,2500,3150,0,-33000
+js01,0,3,0.15,-0.15
+js01,3,10,0.2,-0.2
+js01,10,18,0.25,-0.25
+js01,18,50,0.3,-0.3
+js01,50,80,0.4,-0.4
+js01,80,120,0.5,-0.5
+js01,120,180,0.6,-0.6
+js01,180,250,1,-1
+js01,250,315,1.25,-1.25
+js01,315,400,1.5,-1.5
+js01,400,500,2,-2
+js0,0,3,0.25,-0.25
+js0,3,10,0.3,-0.3
+js0,10,18,0.4,-0.4
+js0,18,50,0.5,-0.5
+js0,50,80,0.6,-0.6
+js0,80,120,0.75,-0.75
+js0,120,180,1,-1
+js0,180,250,1.5,-1.5
+js0,250,315,2,-2
+js0,315,400,2.5,-2.5
+js0,400,500,3,-3
+js1,0,3,0.4,-0.4
+js1,3,10,0.5,-0.5
+js1,10,18,0.6,-0.6
+js1,18,50,0.75,-0.75
+js1,50,80,1,-1
+js1,80,120,1.25,-1.25
+js1,120,180,1.75,-1.75
+js1,180,250,2.25,-2.25
+js1,250,315,3,-3
+js1,315,400,3.5,-3.5
+js1,400,500,4,-4
+js1,500,630,4.5,-4.5
+js1,630,800,5,-5
+js1,800,1000,5.5,-5.5
+js1,1000,1250,6.5,-6.5
+js1,1250,1600,7.5,-7.5
+js1,1600,2000,9,-9
+js1,2000,2500,11,-11
+js1,2500,3150,13,-13
+js2,0,3,0.6,-0.6
+js2,3,10,0.75,-0.75
+js2,10,18,1,-1
+js2,18,50,1.25,-1.25
+js2,50,80,1.5,-1.5
+js2,80,120,2,-2
+js2,120,180,2.5,-2.5
+js2,180,250,3.5,-3.5
+js2,250,315,4,-4
+js2,315,400,4.5,-4.5
+js2,400,500,5,-5
+js2,500,630,5.5,-5.5
+js2,630,800,6.5,-6.5
+js2,800,1000,7.5,-7.5
+js2,1000,1250,9,-9
+js2,1250,1600,10.5,-10.5
+js2,1600,2000,12.5,-12.5
+js2,2000,2500,15,-15
+js2,2500,3150,18,-18
+js3,0,3,1,-1
+js3,3,10,1.25,-1.25
+js3,10,18,1.5,-1.5
+js3,18,50,2,-2
+js3,50,80,2.5,-2.5
+js3,80,120,3,-3
+js3,120,180,4,-4
+js3,180,250,5,-5
+js3,250,315,6,-6
+js3,315,400,6.5,-6.5
+js3,400,500,7.5,-7.5
+js3,500,630,8,-8
+js3,630,800,9,-9
+js3,800,1000,10.5,-10.5
+js3,1000,1250,12,-12
+js3,1250,1600,14.5,-14.5
+js3,1600,2000,17.5,-17.5
+js3,2000,2500,20.5,-20.5
+js3,2500,3150,25,-25
+js4,0,3,1.5,-1.5
+js4,3,10,2,-2
+js4,10,18,2.5,-2.5
+js4,18,30,3,-3
+js4,30,50,3.5,-3.5
+js4,50,80,4,-4
+js4,80,120,5,-5
+js4,120,180,6,-6
+js4,180,250,7,-7
+js4,250,315,8,-8
+js4,315,400,9,-9
+js4,400,500,10,-10
+js4,500,630,11,-11
+js4,630,800,12.5,-12.5
+js4,800,1000,14,-14
+js4,1000,1250,16.5,-16.5
+js4,1250,1600,19.5,-19.5
+js4,1600,2000,23,-23
+js4,2000,2500,27.5,-27.5
+js4,2500,3150,34,-34
+js5,0,3,2,-2
+js5,3,6,2.5,-2.5
+js5,6,10,3,-3
+js5,10,18,4,-4
+js5,18,30,4.5,-4.5
+js5,30,50,5.5,-5.5
+js5,50,80,6.5,-6.5
+js5,80,120,7.5,-7.5
+js5,120,180,9,-9
+js5,180,250,10,-10
+js5,250,315,11.5,-11.5
+js5,315,400,12.5,-12.5
+js5,400,500,13.5,-13.5
+js5,500,630,16,-16
+js5,630,800,18,-18
+js5,800,1000,20,-20
+js5,1000,1250,23.5,-23.5
+js5,1250,1600,27.5,-27.5
+js5,1600,2000,32.5,-32.5
+js5,2000,2500,39,-39
+js5,2500,3150,48,-48
+js6,0,3,3,-3
+js6,3,6,4,-4
+js6,6,10,4.5,-4.5
+js6,10,18,5.5,-5.5
+js6,18,30,6.5,-6.5
+js6,30,50,8,-8
+js6,50,80,9.5,-9.5
+js6,80,120,11,-11
+js6,120,180,12.5,-12.5
+js6,180,250,14.5,-14.5
+js6,250,315,16,-16
+js6,315,400,18,-18
+js6,400,500,20,-20
+js6,500,630,22,-22
+js6,630,800,25,-25
+js6,800,1000,28,-28
+js6,1000,1250,33,-33
+js6,1250,1600,39,-39
+js6,1600,2000,46,-46
+js6,2000,2500,55,-55
+js6,2500,3150,67.5,-67.5
+js7,0,3,5,-5
+js7,3,6,6,-6
+js7,6,10,7,-7
+js7,10,18,9,-9
+js7,18,30,10,-10
+js7,30,50,12,-12
+js7,50,80,15,-15
+js7,80,120,17,-17
+js7,120,180,20,-20
+js7,180,250,23,-23
+js7,250,315,26,-26
+js7,315,400,28,-28
+js7,400,500,31,-31
+js7,500,630,35,-35
+js7,630,800,40,-40
+js7,800,1000,45,-45
+js7,1000,1250,52,-52
+js7,1250,1600,62,-62
+js7,1600,2000,75,-75
+js7,2000,2500,87,-87
+js7,2500,3150,105,-105
+js8,0,3,7,-7
+js8,3,6,9,-9
+js8,6,10,11,-11
+js8,10,18,13,-13
+js8,18,30,16,-16
+js8,30,50,19,-19
+js8,50,80,23,-23
+js8,80,120,27,-27
+js8,120,180,31,-31
+js8,180,250,36,-36
+js8,250,315,40,-40
+js8,315,400,44,-44
+js8,400,500,48,-48
+js8,500,630,55,-55
+js8,630,800,62,-62
+js8,800,1000,70,-70
+js8,1000,1250,82,-82
+js8,1250,1600,97,-97
+js8,1600,2000,115,-115
+js8,2000,2500,140,-140
+js8,2500,3150,165,-165
+js9,0,3,12,-12
+js9,3,6,15,-15
+js9,6,10,18,-18
+js9,10,18,21,-21
+js9,18,30,26,-26
+js9,30,50,31,-31
+js9,50,80,37,-37
+js9,80,120,43,-43
+js9,120,180,50,-50
+js9,180,250,57,-57
+js9,250,315,65,-65
+js9,315,400,70,-70
+js9,400,500,77,-77
+js9,500,630,87,-87
+js9,630,800,100,-100
+js9,800,1000,115,-115
+js9,1000,1250,130,-130
+js9,1250,1600,155,-155
+js9,1600,2000,185,-185
+js9,2000,2500,220,-220
+js9,2500,3150,270,-270
+js10,0,3,20,-20
+js10,3,6,24,-24
+js10,6,10,29,-29
+js10,10,18,35,-35
+js10,18,30,42,-42
+js10,30,50,50,-50
+js10,50,80,60,-60
+js10,80,120,70,-70
+js10,120,180,80,-80
+js10,180,250,92,-92
+js10,250,315,105,-105
+js10,315,400,115,-115
+js10,400,500,125,-125
+js10,500,630,140,-140
+js10,630,800,160,-160
+js10,800,1000,180,-180
+js10,1000,1250,210,-210
+js10,1250,1600,250,-250
+js10,1600,2000,300,-300
+js10,2000,2500,350,-350
+js10,2500,3150,430,-430
+js11,0,3,30,-30
+js11,3,6,37,-37
+js11,6,10,45,-45
+js11,10,18,55,-55
+js11,18,30,65,-65
+js11,30,50,80,-80
+js11,50,80,95,-95
+js11,80,120,110,-110
+js11,120,180,125,-125
+js11,180,250,145,-145
+js11,250,315,160,-160
+js11,315,400,180,-180
+js11,400,500,200,-200
+js11,500,630,220,-220
+js11,630,800,250,-250
+js11,800,1000,280,-280
+js11,1000,1250,330,-330
+js11,1250,1600,390,-390
+js11,1600,2000,460,-460
+js11,2000,2500,550,-550
+js11,2500,3150,675,-675
+js12,0,3,50,-50
+js12,3,6,60,-60
+js12,6,10,75,-75
+js12,10,18,90,-90
+js12,18,30,105,-105
+js12,30,50,125,-125
+js12,50,80,150,-150
+js12,80,120,175,-175
+js12,120,180,200,-200
+js12,180,250,230,-230
+js12,250,315,260,-260
+js12,315,400,285,-285
+js12,400,500,315,-315
+js12,500,630,350,-350
+js12,630,800,400,-400
+js12,800,1000,450,-450
+js12,1000,1250,525,-525
+js12,1250,1600,625,-625
+js12,1600,2000,750,-750
+js12,2000,2500,875,-875
+js12,2500,3150,1050,-1050
+js13,0,3,70,-70
+js13,3,6,90,-90
+js13,6,10,110,-110
+js13,10,18,135,-135
+js13,18,30,165,-165
+js13,30,50,195,-195
+js13,50,80,230,-230
+js13,80,120,270,-270
+js13,120,180,315,-315
+js13,180,250,360,-360
+js13,250,315,405,-405
+js13,315,400,445,-445
+js13,400,500,485,-485
+js13,500,630,550,-550
+js13,630,800,625,-625
+js13,800,1000,700,-700
+js13,1000,1250,825,-825
+js13,1250,1600,975,-975
+js13,1600,2000,1150,-1150
+js13,2000,2500,1400,-1400
+js13,2500,3150,1650,-1650
+js14,0,3,125,-125
+js14,3,6,150,-150
+js14,6,10,180,-180
+js14,10,18,215,-215
+js14,18,30,260,-260
+js14,30,50,310,-310
+js14,50,80,370,-370
+js14,80,120,435,-435
+js14,120,180,500,-500
+js14,180,250,575,-575
+js14,250,315,650,-650
+js14,315,400,700,-700
+js14,400,500,775,-775
+js14,500,630,875,-875
+js14,630,800,1000,-1000
+js14,800,1000,1150,-1150
+js14,1000,1250,1300,-1300
+js14,1250,1600,1550,-1550
+js14,1600,2000,1850,-1850
+js14,2000,2500,2200,-2200
+js14,2500,3150,2700,-2700
+js15,0,3,200,-200
+js15,3,6,240,-240
+js15,6,10,290,-290
+js15,10,18,350,-350
+js15,18,30,420,-420
+js15,30,50,500,-500
+js15,50,80,600,-600
+js15,80,120,700,-700
+js15,120,180,800,-800
+js15,180,250,925,-925
+js15,250,315,1050,-1050
+js15,315,400,1150,-1150
+js15,400,500,1250,-1250
+js15,500,630,1400,-1400
+js15,630,800,1600,-1600
+js15,800,1000,1800,-1800
+js15,1000,1250,2100,-2100
+js15,1250,1600,2500,-2500
+js15,1600,2000,3000,-3000
+js15,2000,2500,3500,-3500
+js15,2500,3150,4300,-4300
+js16,0,3,300,-300
+js16,3,6,375,-375
+js16,6,10,450,-450
+js16,10,18,550,-550
+js16,18,30,650,-650
+js16,30,50,800,-800
+js16,50,80,950,-950
+js16,80,120,1100,-1100
+js16,120,180,1250,-1250
+js16,180,250,1450,-1450
+js16,250,315,1600,-1600
+js16,315,400,1800,-1800
+js16,400,500,2000,-2000
+js16,500,630,2200,-2200
+js16,630,800,2500,-2500
+js16,800,1000,2800,-2800
+js16,1000,1250,3300,-3300
+js16,1250,1600,3900,-3900
+js16,1600,2000,4600,-4600
+js16,2000,2500,5500,-5500
+js16,2500,3150,6750,-6750
+js17,0,3,500,-500
+js17,3,6,600,-600
+js17,6,10,750,-750
+js17,10,18,900,-900
+js17,18,30,1050,-1050
+js17,30,50,1250,-1250
+js17,50,80,1500,-1500
+js17,80,120,1750,-1750
+js17,120,180,2000,-2000
+js17,180,250,2300,-2300
+js17,250,315,2600,-2600
+js17,315,400,2850,-2850
+js17,400,500,3150,-3150
+js17,500,630,3500,-3500
+js17,630,800,4000,-4000
+js17,800,1000,4500,-4500
+js17,1000,1250,5250,-5250
+js17,1250,1600,6250,-6250
+js17,1600,2000,7500,-7500
+js17,2000,2500,8750,-8750
+js17,2500,3150,10500,-10500
+js18,0,3,700,-700
+js18,3,6,900,-900
+js18,6,10,1100,-1100
+js18,10,18,1350,-1350
+js18,18,30,1650,-1650
+js18,30,50,1950,-1950
+js18,50,80,2300,-2300
+js18,80,120,2700,-2700
+js18,120,180,3150,-3150
+js18,180,250,3600,-3600
+js18,250,315,4050,-4050
+js18,315,400,4450,-4450
+js18,400,500,4850,-4850
+js18,500,630,5500,-5500
+js18,630,800,6250,-6250
+js18,800,1000,7000,-7000
+js18,1000,1250,8250,-8250
+js18,1250,1600,9750,-9750
+js18,1600,2000,11500,-11500
+js18,2000,2500,14000,-14000
+js18,2500,3150,16500,-16500
+j5,0,3,2,-2
+j5,3,6,3,-2
+j5,6,10,4,-2
+j5,10,18,5,-3
+j5,18,30,5,-4
+j5,30,50,6,-5
+j5,50,80,6,-7
+j5,80,120,6,-9
+j5,120,180,7,-11
+j5,180,250,7,-13
+j5,250,315,7,-16
+j5,315,400,7,-18
+j5,400,500,7,-20
+j6,0,3,4,-2
+j6,3,6,6,-2
+j6,6,10,7,-2
+j6,10,18,8,-3
+j6,18,30,9,-4
+j6,30,50,11,-5
+j6,50,80,12,-7
+j6,80,120,13,-9
+j6,120,180,14,-11
+j6,180,250,16,-13
+j6,250,315,16,-16
+j6,315,400,18,-18
+j6,400,500,20,-20
+j7,0,3,6,-4
+j7,3,6,8,-4
+j7,6,10,10,-5
+j7,10,18,12,-6
+j7,18,30,13,-8
+j7,30,50,15,-10
+j7,50,80,18,-12
+j7,80,120,20,-15
+j7,120,180,22,-18
+j7,180,250,25,-21
+j7,250,315,26,-26
+j7,315,400,29,-28
+j7,400,500,31,-32
+j8,0,3,8,-6
+k3,0,3,2,0
+k3,3,10,2.5,0
+k3,10,18,3,0
+k3,18,50,4,0
+k3,50,80,5,0
+k3,80,120,6,0
+k3,120,180,8,0
+k3,180,250,10,0
+k3,250,315,12,0
+k3,315,400,13,0
+k3,400,500,15,0
+k3,500,630,16,0
+k3,630,800,18,0
+k3,800,1000,21,0
+k3,1000,1250,24,0
+k3,1250,1600,29,0
+k3,1600,2000,35,0
+k3,2000,2500,41,0
+k3,2500,3150,50,0
+k4,0,3,3,0
+k4,3,10,5,1
+k4,10,18,6,1
+k4,18,30,8,2
+k4,30,50,9,2
+k4,50,80,10,2
+k4,80,120,13,3
+k4,120,180,15,3
+k4,180,250,18,4
+k4,250,315,20,4
+k4,315,400,22,4
+k4,400,500,25,5
+k4,500,630,22,0
+k4,630,800,25,0
+k4,800,1000,28,0
+k4,1000,1250,33,0
+k4,1250,1600,39,0
+k4,1600,2000,46,0
+k4,2000,2500,55,0
+k4,2500,3150,68,0
+k5,0,3,4,0
+k5,3,6,6,1
+k5,6,10,7,1
+k5,10,18,9,1
+k5,18,30,11,2
+k5,30,50,13,2
+k5,50,80,15,2
+k5,80,120,18,3
+k5,120,180,21,3
+k5,180,250,24,4
+k5,250,315,27,4
+k5,315,400,29,4
+k5,400,500,32,5
+k5,500,630,32,0
+k5,630,800,36,0
+k5,800,1000,40,0
+k5,1000,1250,47,0
+k5,1250,1600,55,0
+k5,1600,2000,65,0
+k5,2000,2500,78,0
+k5,2500,3150,96,0
+k6,0,3,6,0
+k6,3,6,9,1
+k6,6,10,10,1
+k6,10,18,12,1
+k6,18,30,15,2
+k6,30,50,18,2
+k6,50,80,21,2
+k6,80,120,25,3
+k6,120,180,28,3
+k6,180,250,33,4
+k6,250,315,36,4
+k6,315,400,40,4
+k6,400,500,45,5
+k6,500,630,44,0
+k6,630,800,50,0
+k6,800,1000,56,0
+k6,1000,1250,66,0
+k6,1250,1600,78,0
+k6,1600,2000,92,0
+k6,2000,2500,110,0
+k6,2500,3150,135,0
+k7,0,3,10,0
+k7,3,6,13,1
+k7,6,10,16,1
+k7,10,18,19,1
+k7,18,30,23,2
+k7,30,50,27,2
+k7,50,80,32,2
+k7,80,120,38,3
+k7,120,180,43,3
+k7,180,250,50,4
+k7,250,315,56,4
+k7,315,400,61,4
+k7,400,500,68,5
+k7,500,630,70,0
+k7,630,800,80,0
+k7,800,1000,90,0
+k7,1000,1250,105,0
+k7,1250,1600,125,0
+k7,1600,2000,150,0
+k7,2000,2500,175,0
+k7,2500,3150,210,0
+k8,0,3,14,0
+k8,3,6,18,0
+k8,6,10,22,0
+k8,10,18,27,0
+k8,18,30,33,0
+k8,30,50,39,0
+k8,50,80,46,0
+k8,80,120,54,0
+k8,120,180,63,0
+k8,180,250,72,0
+k8,250,315,81,0
+k8,315,400,89,0
+k8,400,500,97,0
+k8,500,630,110,0
+k8,630,800,125,0
+k8,800,1000,140,0
+k8,1000,1250,165,0
+k8,1250,1600,195,0
+k8,1600,2000,230,0
+k8,2000,2500,280,0
+k8,2500,3150,330,0
+k9,0,3,25,0
+k9,3,6,30,0
+k9,6,10,36,0
+k9,10,18,43,0
+k9,18,30,52,0
+k9,30,50,62,0
+k9,50,80,74,0
+k9,80,120,87,0
+k9,120,180,100,0
+k9,180,250,115,0
+k9,250,315,130,0
+k9,315,400,140,0
+k9,400,500,155,0
+k9,500,630,175,0
+k9,630,800,200,0
+k9,800,1000,230,0
+k9,1000,1250,260,0
+k9,1250,1600,310,0
+k9,1600,2000,370,0
+k9,2000,2500,440,0
+k9,2500,3150,540,0
+k10,0,3,40,0
+k10,3,6,48,0
+k10,6,10,58,0
+k10,10,18,70,0
+k10,18,30,84,0
+k10,30,50,100,0
+k10,50,80,120,0
+k10,80,120,140,0
+k10,120,180,160,0
+k10,180,250,185,0
+k10,250,315,210,0
+k10,315,400,230,0
+k10,400,500,250,0
+k10,500,630,280,0
+k10,630,800,320,0
+k10,800,1000,360,0
+k10,1000,1250,420,0
+k10,1250,1600,500,0
+k10,1600,2000,600,0
+k10,2000,2500,700,0
+k10,2500,3150,860,0
+k11,0,3,60,0
+k11,3,6,75,0
+k11,6,10,90,0
+k11,10,18,110,0
+k11,18,30,130,0
+k11,30,50,160,0
+k11,50,80,190,0
+k11,80,120,220,0
+k11,120,180,250,0
+k11,180,250,290,0
+k11,250,315,320,0
+k11,315,400,360,0
+k11,400,500,400,0
+k11,500,630,440,0
+k11,630,800,500,0
+k11,800,1000,560,0
+k11,1000,1250,660,0
+k11,1250,1600,780,0
+k11,1600,2000,920,0
+k11,2000,2500,1100,0
+k11,2500,3150,1350,0
+k12,0,3,100,0
+k12,3,6,120,0
+k12,6,10,150,0
+k12,10,18,180,0
+k12,18,30,210,0
+k12,30,50,250,0
+k12,50,80,300,0
+k12,80,120,350,0
+k12,120,180,400,0
+k12,180,250,460,0
+k12,250,315,520,0
+k12,315,400,570,0
+k12,400,500,630,0
+k12,500,630,700,0
+k12,630,800,800,0
+k12,800,1000,900,0
+k12,1000,1250,1050,0
+k12,1250,1600,1250,0
+k12,1600,2000,1500,0
+k12,2000,2500,1750,0
+k12,2500,3150,2100,0
+k13,0,3,140,0
+k13,3,6,180,0
+k13,6,10,220,0
+k13,10,18,270,0
+k13,18,30,330,0
+k13,30,50,390,0
+k13,50,80,460,0
+k13,80,120,540,0
+k13,120,180,630,0
+k13,180,250,720,0
+k13,250,315,810,0
+k13,315,400,890,0
+k13,400,500,970,0
+k13,500,630,1100,0
+k13,630,800,1250,0
+k13,800,1000,1400,0
+k13,1000,1250,1650,0
+k13,1250,1600,1950,0
+k13,1600,2000,2300,0
+k13,2000,2500,2800,0
+k13,2500,3150,3300,0
+m3,0,3,4,2
+m3,3,6,6.5,4
+m3,6,10,8.5,6
+m3,10,18,10,7
+m3,18,30,12,8
+m3,30,50,13,9
+m3,50,80,16,11
+m3,80,120,19,13
+m3,120,180,23,15
+m3,180,250,27,17
+m3,250,315,32,20
+m3,315,400,34,21
+m3,400,500,38,23
+m3,500,630,42,26
+m3,630,800,48,30
+m3,800,1000,55,34
+m3,1000,1250,64,40
+m3,1250,1600,77,48
+m3,1600,2000,93,58
+m3,2000,2500,109,68
+m3,2500,3150,126,76
+m4,0,3,5,2
+m4,3,6,8,4
+m4,6,10,10,6
+m4,10,18,12,7
+m4,18,30,14,8
+m4,30,50,16,9
+m4,50,80,19,11
+m4,80,120,23,13
+m4,120,180,27,15
+m4,180,250,31,17
+m4,250,315,36,20
+m4,315,400,39,21
+m4,400,500,43,23
+m4,500,630,48,26
+m4,630,800,55,30
+m4,800,1000,62,34
+m4,1000,1250,73,40
+m4,1250,1600,87,48
+m4,1600,2000,104,58
+m4,2000,2500,123,68
+m4,2500,3150,144,76
+m5,0,3,6,2
+m5,3,6,9,4
+m5,6,10,12,6
+m5,10,18,15,7
+m5,18,30,17,8
+m5,30,50,20,9
+m5,50,80,24,11
+m5,80,120,28,13
+m5,120,180,33,15
+m5,180,250,37,17
+m5,250,315,43,20
+m5,315,400,46,21
+m5,400,500,50,23
+m5,500,630,58,26
+m5,630,800,66,30
+m5,800,1000,74,34
+m5,1000,1250,87,40
+m5,1250,1600,103,48
+m5,1600,2000,123,58
+m5,2000,2500,146,68
+m5,2500,3150,172,76
+m6,0,3,8,2
+m6,3,6,12,4
+m6,6,10,15,6
+m6,10,18,18,7
+m6,18,30,21,8
+m6,30,50,25,9
+m6,50,80,30,11
+m6,80,120,35,13
+m6,120,180,40,15
+m6,180,250,46,17
+m6,250,315,52,20
+m6,315,400,57,21
+m6,400,500,63,23
+m6,500,630,70,26
+m6,630,800,80,30
+m6,800,1000,90,34
+m6,1000,1250,106,40
+m6,1250,1600,126,48
+m6,1600,2000,150,58
+m6,2000,2500,178,68
+m6,2500,3150,211,76
+m7,0,3,12,2
+m7,3,6,16,4
+m7,6,10,21,6
+m7,10,18,25,7
+m7,18,30,29,8
+m7,30,50,34,9
+m7,50,80,41,11
+m7,80,120,48,13
+m7,120,180,55,15
+m7,180,250,63,17
+m7,250,315,72,20
+m7,315,400,78,21
+m7,400,500,86,23
+m7,500,630,96,26
+m7,630,800,110,30
+m7,800,1000,124,34
+m7,1000,1250,145,40
+m7,1250,1600,173,48
+m7,1600,2000,208,58
+m7,2000,2500,243,68
+m7,2500,3150,286,76
+m8,0,3,16,2
+m8,3,6,22,4
+m8,6,10,28,6
+m8,10,18,34,7
+m8,18,30,41,8
+m8,30,50,48,9
+m8,50,80,57,11
+m8,80,120,67,13
+m8,120,180,78,15
+m8,180,250,89,17
+m8,250,315,101,20
+m8,315,400,110,21
+m8,400,500,120,23
+m8,500,630,136,26
+m8,630,800,155,30
+m8,800,1000,174,34
+m8,1000,1250,205,40
+m8,1250,1600,243,48
+m8,1600,2000,288,58
+m8,2000,2500,348,68
+m8,2500,3150,406,76
+m9,0,3,27,2
+m9,3,6,34,4
+m9,6,10,42,6
+m9,10,18,50,7
+m9,18,30,60,8
+m9,30,50,71,9
+m9,50,80,85,11
+m9,80,120,100,13
+m9,120,180,115,15
+m9,180,250,132,17
+m9,250,315,150,20
+m9,315,400,161,21
+m9,400,500,178,23
+m9,500,630,201,26
+m9,630,800,230,30
+m9,800,1000,264,34
+m9,1000,1250,300,40
+m9,1250,1600,358,48
+m9,1600,2000,428,58
+m9,2000,2500,508,68
+m9,2500,3150,616,76
+n3,0,3,6,4
+n3,3,6,10.5,8
+n3,6,10,12.5,10
+n3,10,18,15,12
+n3,18,30,19,15
+n3,30,50,21,17
+n3,50,80,25,20
+n3,80,120,29,23
+n3,120,180,35,27
+n3,180,250,41,31
+n3,250,315,46,34
+n3,315,400,50,37
+n3,400,500,55,40
+n3,500,630,60,44
+n3,630,800,68,50
+n3,800,1000,77,56
+n3,1000,1250,90,66
+n3,1250,1600,107,78
+n3,1600,2000,127,92
+n3,2000,2500,151,110
+n3,2500,3150,185,135
+n4,0,3,7,4
+n4,3,6,12,8
+n4,6,10,14,10
+n4,10,18,17,12
+n4,18,30,21,15
+n4,30,50,24,17
+n4,50,80,28,20
+n4,80,120,33,23
+n4,120,180,39,27
+n4,180,250,45,31
+n4,250,315,50,34
+n4,315,400,55,37
+n4,400,500,60,40
+n4,500,630,66,44
+n4,630,800,75,50
+n4,800,1000,84,56
+n4,1000,1250,99,66
+n4,1250,1600,117,78
+n4,1600,2000,138,92
+n4,2000,2500,165,110
+n4,2500,3150,203,135
+n5,0,3,8,4
+n5,3,6,13,8
+n5,6,10,16,10
+n5,10,18,20,12
+n5,18,30,24,15
+n5,30,50,28,17
+n5,50,80,33,20
+n5,80,120,38,23
+n5,120,180,45,27
+n5,180,250,51,31
+n5,250,315,57,34
+n5,315,400,62,37
+n5,400,500,67,40
+n5,500,630,76,44
+n5,630,800,86,50
+n5,800,1000,96,56
+n5,1000,1250,113,66
+n5,1250,1600,133,78
+n5,1600,2000,157,92
+n5,2000,2500,188,110
+n5,2500,3150,231,135
+n6,0,3,10,4
+n6,3,6,16,8
+n6,6,10,19,10
+n6,10,18,23,12
+n6,18,30,28,15
+n6,30,50,33,17
+n6,50,80,39,20
+n6,80,120,45,23
+n6,120,180,52,27
+n6,180,250,60,31
+n6,250,315,66,34
+n6,315,400,73,37
+n6,400,500,80,40
+n6,500,630,88,44
+n6,630,800,100,50
+n6,800,1000,112,56
+n6,1000,1250,132,66
+n6,1250,1600,156,78
+n6,1600,2000,184,92
+n6,2000,2500,220,110
+n6,2500,3150,270,135
+n7,0,3,14,4
+n7,3,6,20,8
+n7,6,10,25,10
+n7,10,18,30,12
+n7,18,30,36,15
+n7,30,50,42,17
+n7,50,80,50,20
+n7,80,120,58,23
+n7,120,180,67,27
+n7,180,250,77,31
+n7,250,315,86,34
+n7,315,400,94,37
+n7,400,500,103,40
+n7,500,630,114,44
+n7,630,800,130,50
+n7,800,1000,146,56
+n7,1000,1250,171,66
+n7,1250,1600,203,78
+n7,1600,2000,242,92
+n7,2000,2500,285,110
+n7,2500,3150,345,135
+n8,0,3,18,4
+n8,3,6,26,8
+n8,6,10,32,10
+n8,10,18,39,12
+n8,18,30,48,15
+n8,30,50,56,17
+n8,50,80,66,20
+n8,80,120,77,23
+n8,120,180,90,27
+n8,180,250,103,31
+n8,250,315,115,34
+n8,315,400,126,37
+n8,400,500,137,40
+n8,500,630,154,44
+n8,630,800,175,50
+n8,800,1000,196,56
+n8,1000,1250,231,66
+n8,1250,1600,273,78
+n8,1600,2000,322,92
+n8,2000,2500,390,110
+n8,2500,3150,465,135
+n9,0,3,29,4
+n9,3,6,38,8
+n9,6,10,46,10
+n9,10,18,55,12
+n9,18,30,67,15
+n9,30,50,79,17
+n9,50,80,94,20
+n9,80,120,110,23
+n9,120,180,127,27
+n9,180,250,146,31
+n9,250,315,164,34
+n9,315,400,177,37
+n9,400,500,195,40
+n9,500,630,219,44
+n9,630,800,250,50
+n9,800,1000,286,56
+n9,1000,1250,326,66
+n9,1250,1600,388,78
+n9,1600,2000,462,92
+n9,2000,2500,550,110
+n9,2500,3150,675,135
+p3,0,3,8,6
+p3,3,6,14.5,12
+p3,6,10,17.5,15
+p3,10,18,21,18
+p3,18,30,26,22
+p3,30,50,30,26
+p3,50,80,37,32
+p3,80,120,43,37
+p3,120,180,51,43
+p3,180,250,60,50
+p3,250,315,68,56
+p3,315,400,75,62
+p3,400,500,83,68
+p3,500,630,94,78
+p3,630,800,106,88
+p3,800,1000,121,100
+p3,1000,1250,144,120
+p3,1250,1600,169,140
+p3,1600,2000,205,170
+p3,2000,2500,236,195
+p3,2500,3150,290,240
+p4,0,3,9,6
+p4,3,6,16,12
+p4,6,10,19,15
+p4,10,18,23,18
+p4,18,30,28,22
+p4,30,50,33,26
+p4,50,80,40,32
+p4,80,120,47,37
+p4,120,180,55,43
+p4,180,250,64,50
+p4,250,315,72,56
+p4,315,400,80,62
+p4,400,500,88,68
+p4,500,630,100,78
+p4,630,800,113,88
+p4,800,1000,128,100
+p4,1000,1250,153,120
+p4,1250,1600,179,140
+p4,1600,2000,216,170
+p4,2000,2500,250,195
+p4,2500,3150,308,240
+p5,0,3,10,6
+p5,3,6,17,12
+p5,6,10,21,15
+p5,10,18,26,18
+p5,18,30,31,22
+p5,30,50,37,26
+p5,50,80,45,32
+p5,80,120,52,37
+p5,120,180,61,43
+p5,180,250,70,50
+p5,250,315,79,56
+p5,315,400,87,62
+p5,400,500,95,68
+p5,500,630,110,78
+p5,630,800,124,88
+p5,800,1000,140,100
+p5,1000,1250,167,120
+p5,1250,1600,195,140
+p5,1600,2000,235,170
+p5,2000,2500,273,195
+p5,2500,3150,336,240
+p6,0,3,12,6
+p6,3,6,20,12
+p6,6,10,24,15
+p6,10,18,29,18
+p6,18,30,35,22
+p6,30,50,42,26
+p6,50,80,51,32
+p6,80,120,59,37
+p6,120,180,68,43
+p6,180,250,79,50
+p6,250,315,88,56
+p6,315,400,98,62
+p6,400,500,108,68
+p6,500,630,122,78
+p6,630,800,138,88
+p6,800,1000,156,100
+p6,1000,1250,186,120
+p6,1250,1600,218,140
+p6,1600,2000,262,170
+p6,2000,2500,305,195
+p6,2500,3150,375,240
+p7,0,3,16,6
+p7,3,6,24,12
+p7,6,10,30,15
+p7,10,18,36,18
+p7,18,30,43,22
+p7,30,50,51,26
+p7,50,80,62,32
+p7,80,120,72,37
+p7,120,180,83,43
+p7,180,250,96,50
+p7,250,315,108,56
+p7,315,400,119,62
+p7,400,500,131,68
+p7,500,630,148,78
+p7,630,800,168,88
+p7,800,1000,190,100
+p7,1000,1250,225,120
+p7,1250,1600,265,140
+p7,1600,2000,320,170
+p7,2000,2500,370,195
+p7,2500,3150,450,240
+p8,0,3,20,6
+p8,3,6,30,12
+p8,6,10,37,15
+p8,10,18,45,18
+p8,18,30,55,22
+p8,30,50,65,26
+p8,50,80,78,32
+p8,80,120,91,37
+p8,120,180,106,43
+p8,180,250,122,50
+p8,250,315,137,56
+p8,315,400,151,62
+p8,400,500,165,68
+p8,500,630,188,78
+p8,630,800,213,88
+p8,800,1000,240,100
+p8,1000,1250,285,120
+p8,1250,1600,335,140
+p8,1600,2000,400,170
+p8,2000,2500,475,195
+p8,2500,3150,570,240
+p9,0,3,31,6
+p9,3,6,42,12
+p9,6,10,51,15
+p9,10,18,61,18
+p9,18,30,74,22
+p9,30,50,88,26
+p9,50,80,106,32
+p9,80,120,124,37
+p9,120,180,143,43
+p9,180,250,165,50
+p9,250,315,186,56
+p9,315,400,202,62
+p9,400,500,223,68
+p9,500,630,253,78
+p9,630,800,288,88
+p9,800,1000,330,100
+p9,1000,1250,380,120
+p9,1250,1600,450,140
+p9,1600,2000,540,170
+p9,2000,2500,635,195
+p9,2500,3150,780,240
+p10,0,3,46,6
+p10,3,6,60,12
+p10,6,10,73,15
+p10,10,18,88,18
+p10,18,30,106,22
+p10,30,50,126,26
+p10,50,80,152,32
+p10,80,120,177,37
+p10,120,180,203,43
+p10,180,250,235,50
+p10,250,315,266,56
+p10,315,400,292,62
+p10,400,500,318,68
+p10,500,630,358,78
+p10,630,800,408,88
+p10,800,1000,460,100
+p10,1000,1250,540,120
+p10,1250,1600,640,140
+p10,1600,2000,770,170
+p10,2000,2500,895,195
+p10,2500,3150,1100,240
+r3,0,3,12,10
+r3,3,6,17.5,15
+r3,6,10,21.5,19
+r3,10,18,26,23
+r3,18,30,32,28
+r3,30,50,38,34
+r3,50,65,46,41
+r3,65,80,48,43
+r3,80,100,57,51
+r3,100,120,60,54
+r3,120,140,71,63
+r3,140,160,73,65
+r3,160,180,76,68
+r3,180,200,87,77
+r3,200,225,90,80
+r3,225,250,94,84
+r3,250,280,106,94
+r3,280,315,110,98
+r3,315,355,121,108
+r3,355,400,127,114
+r3,400,450,141,126
+r3,450,500,147,132
+r3,500,560,166,150
+r3,560,630,171,155
+r3,630,710,193,175
+r3,710,800,203,185
+r3,800,900,231,210
+r3,900,1000,241,220
+r3,1000,1120,274,250
+r3,1120,1250,284,260
+r3,1250,1400,329,300
+r3,1400,1600,359,330
+r3,1600,1800,405,370
+r3,1800,2000,435,400
+r3,2000,2240,481,440
+r3,2240,2500,501,460
+r3,2500,2800,600,550
+r3,2800,3150,630,580
+r4,0,3,13,10
+r4,3,6,19,15
+r4,6,10,23,19
+r4,10,18,28,23
+r4,18,30,34,28
+r4,30,50,41,34
+r4,50,65,49,41
+r4,65,80,51,43
+r4,80,100,61,51
+r4,100,120,64,54
+r4,120,140,75,63
+r4,140,160,77,65
+r4,160,180,80,68
+r4,180,200,91,77
+r4,200,225,94,80
+r4,225,250,98,84
+r4,250,280,110,94
+r4,280,315,114,98
+r4,315,355,126,108
+r4,355,400,132,114
+r4,400,450,146,126
+r4,450,500,152,132
+r4,500,560,172,150
+r4,560,630,177,155
+r4,630,710,200,175
+r4,710,800,210,185
+r4,800,900,238,210
+r4,900,1000,248,220
+r4,1000,1120,283,250
+r4,1120,1250,293,260
+r4,1250,1400,339,300
+r4,1400,1600,369,330
+r4,1600,1800,416,370
+r4,1800,2000,446,400
+r4,2000,2240,495,440
+r4,2240,2500,515,460
+r4,2500,2800,618,550
+r4,2800,3150,648,580
+r5,0,3,14,10
+r5,3,6,20,15
+r5,6,10,25,19
+r5,10,18,31,23
+r5,18,30,37,28
+r5,30,50,45,34
+r5,50,65,54,41
+r5,65,80,56,43
+r5,80,100,66,51
+r5,100,120,69,54
+r5,120,140,81,63
+r5,140,160,83,65
+r5,160,180,86,68
+r5,180,200,97,77
+r5,200,225,100,80
+r5,225,250,104,84
+r5,250,280,117,94
+r5,280,315,121,98
+r5,315,355,133,108
+r5,355,400,139,114
+r5,400,450,153,126
+r5,450,500,159,132
+r5,500,560,182,150
+r5,560,630,187,155
+r5,630,710,211,175
+r5,710,800,221,185
+r5,800,900,250,210
+r5,900,1000,260,220
+r5,1000,1120,297,250
+r5,1120,1250,307,260
+r5,1250,1400,355,300
+r5,1400,1600,385,330
+r5,1600,1800,435,370
+r5,1800,2000,465,400
+r5,2000,2240,518,440
+r5,2240,2500,538,460
+r5,2500,2800,646,550
+r5,2800,3150,676,580
+r6,0,3,16,10
+r6,3,6,23,15
+r6,6,10,28,19
+r6,10,18,34,23
+r6,18,30,41,28
+r6,30,50,50,34
+r6,50,65,60,41
+r6,65,80,62,43
+r6,80,100,73,51
+r6,100,120,76,54
+r6,120,140,88,63
+r6,140,160,90,65
+r6,160,180,93,68
+r6,180,200,106,77
+r6,200,225,109,80
+r6,225,250,113,84
+r6,250,280,126,94
+r6,280,315,130,98
+r6,315,355,144,108
+r6,355,400,150,114
+r6,400,450,166,126
+r6,450,500,172,132
+r6,500,560,194,150
+r6,560,630,199,155
+r6,630,710,225,175
+r6,710,800,235,185
+r6,800,900,266,210
+r6,900,1000,276,220
+r6,1000,1120,316,250
+r6,1120,1250,326,260
+r6,1250,1400,378,300
+r6,1400,1600,408,330
+r6,1600,1800,462,370
+r6,1800,2000,492,400
+r6,2000,2240,550,440
+r6,2240,2500,570,460
+r6,2500,2800,685,550
+r6,2800,3150,715,580
+r7,0,3,20,10
+r7,3,6,27,15
+r7,6,10,34,19
+r7,10,18,41,23
+r7,18,30,49,28
+r7,30,50,59,34
+r7,50,65,71,41
+r7,65,80,73,43
+r7,80,100,86,51
+r7,100,120,89,54
+r7,120,140,103,63
+r7,140,160,105,65
+r7,160,180,108,68
+r7,180,200,123,77
+r7,200,225,126,80
+r7,225,250,130,84
+r7,250,280,146,94
+r7,280,315,150,98
+r7,315,355,165,108
+r7,355,400,171,114
+r7,400,450,189,126
+r7,450,500,195,132
+r7,500,560,220,150
+r7,560,630,225,155
+r7,630,710,255,175
+r7,710,800,265,185
+r7,800,900,300,210
+r7,900,1000,310,220
+r7,1000,1120,355,250
+r7,1120,1250,365,260
+r7,1250,1400,425,300
+r7,1400,1600,455,330
+r7,1600,1800,520,370
+r7,1800,2000,550,400
+r7,2000,2240,615,440
+r7,2240,2500,635,460
+r7,2500,2800,760,550
+r7,2800,3150,790,580
+r8,0,3,24,10
+r8,3,6,33,15
+r8,6,10,41,19
+r8,10,18,50,23
+r8,18,30,61,28
+r8,30,50,73,34
+r8,50,65,87,41
+r8,65,80,89,43
+r8,80,100,105,51
+r8,100,120,108,54
+r8,120,140,126,63
+r8,140,160,128,65
+r8,160,180,131,68
+r8,180,200,149,77
+r8,200,225,152,80
+r8,225,250,156,84
+r8,250,280,175,94
+r8,280,315,179,98
+r8,315,355,197,108
+r8,355,400,203,114
+r8,400,450,223,126
+r8,450,500,229,132
+r8,500,560,260,150
+r8,560,630,265,155
+r8,630,710,300,175
+r8,710,800,310,185
+r8,800,900,350,210
+r8,900,1000,360,220
+r8,1000,1120,415,250
+r8,1120,1250,425,260
+r8,1250,1400,495,300
+r8,1400,1600,525,330
+r8,1600,1800,600,370
+r8,1800,2000,630,400
+r8,2000,2240,720,440
+r8,2240,2500,740,460
+r8,2500,2800,880,550
+r8,2800,3150,910,580
+r9,0,3,35,10
+r9,3,6,45,15
+r9,6,10,55,19
+r9,10,18,66,23
+r9,18,30,80,28
+r9,30,50,96,34
+r9,50,65,115,41
+r9,65,80,117,43
+r9,80,100,138,51
+r9,100,120,141,54
+r9,120,140,163,63
+r9,140,160,165,65
+r9,160,180,168,68
+r9,180,200,192,77
+r9,200,225,195,80
+r9,225,250,199,84
+r9,250,280,224,94
+r9,280,315,228,98
+r9,315,355,248,108
+r9,355,400,254,114
+r9,400,450,281,126
+r9,450,500,287,132
+r9,500,560,325,150
+r9,560,630,330,155
+r9,630,710,375,175
+r9,710,800,385,185
+r9,800,900,440,210
+r9,900,1000,450,220
+r9,1000,1120,510,250
+r9,1120,1250,520,260
+r9,1250,1400,610,300
+r9,1400,1600,640,330
+r9,1600,1800,740,370
+r9,1800,2000,770,400
+r9,2000,2240,880,440
+r9,2240,2500,900,460
+r9,2500,2800,1090,550
+r9,2800,3150,1120,580
+r10,0,3,50,10
+r10,3,6,63,15
+r10,6,10,77,19
+r10,10,18,93,23
+r10,18,30,112,28
+r10,30,50,134,34
+r10,50,65,161,41
+r10,65,80,163,43
+r10,80,100,191,51
+r10,100,120,194,54
+r10,120,140,223,63
+r10,140,160,225,65
+r10,160,180,228,68
+r10,180,200,262,77
+r10,200,225,265,80
+r10,225,250,269,84
+r10,250,280,304,94
+r10,280,315,308,98
+r10,315,355,338,108
+r10,355,400,344,114
+r10,400,450,376,126
+r10,450,500,382,132
+r10,500,560,430,150
+r10,560,630,435,155
+r10,630,710,495,175
+r10,710,800,505,185
+r10,800,900,570,210
+r10,900,1000,580,220
+r10,1000,1120,670,250
+r10,1120,1250,680,260
+r10,1250,1400,800,300
+r10,1400,1600,830,330
+r10,1600,1800,970,370
+r10,1800,2000,1000,400
+r10,2000,2240,1140,440
+r10,2240,2500,1160,460
+r10,2500,2800,1410,550
+r10,2800,3150,1440,580
+s3,0,3,16,14
+s3,3,6,21.5,19
+s3,6,10,25.5,23
+s3,10,18,31,28
+s3,18,30,39,35
+s3,30,50,47,43
+s3,50,65,58,53
+s3,65,80,64,59
+s3,80,100,77,71
+s3,100,120,85,79
+s3,120,140,100,92
+s3,140,160,108,100
+s3,160,180,116,108
+s3,180,200,132,122
+s3,200,225,140,130
+s3,225,250,150,140
+s3,250,280,170,158
+s3,280,315,182,170
+s3,315,355,203,190
+s3,355,400,221,208
+s3,400,450,247,232
+s3,450,500,267,252
+s3,500,560,296,280
+s3,560,630,326,310
+s3,630,710,358,340
+s3,710,800,398,380
+s3,800,900,451,430
+s3,900,1000,491,470
+s3,1000,1120,544,520
+s3,1120,1250,604,580
+s3,1250,1400,669,640
+s3,1400,1600,749,720
+s3,1600,1800,855,820
+s3,1800,2000,955,920
+s3,2000,2240,1041,1000
+s3,2240,2500,1141,1100
+s3,2500,2800,1300,1250
+s3,2800,3150,1450,1400
+s4,0,3,17,14
+s4,3,6,23,19
+s4,6,10,27,23
+s4,10,18,33,28
+s4,18,30,41,35
+s4,30,50,50,43
+s4,50,65,61,53
+s4,65,80,67,59
+s4,80,100,81,71
+s4,100,120,89,79
+s4,120,140,104,92
+s4,140,160,112,100
+s4,160,180,120,108
+s4,180,200,136,122
+s4,200,225,144,130
+s4,225,250,154,140
+s4,250,280,174,158
+s4,280,315,186,170
+s4,315,355,208,190
+s4,355,400,226,208
+s4,400,450,252,232
+s4,450,500,272,252
+s4,500,560,302,280
+s4,560,630,332,310
+s4,630,710,365,340
+s4,710,800,405,380
+s4,800,900,458,430
+s4,900,1000,498,470
+s4,1000,1120,553,520
+s4,1120,1250,613,580
+s4,1250,1400,679,640
+s4,1400,1600,759,720
+s4,1600,1800,866,820
+s4,1800,2000,966,920
+s4,2000,2240,1055,1000
+s4,2240,2500,1155,1100
+s4,2500,2800,1318,1250
+s4,2800,3150,1468,1400
+s5,0,3,18,14
+s5,3,6,24,19
+s5,6,10,29,23
+s5,10,18,36,28
+s5,18,30,44,35
+s5,30,50,54,43
+s5,50,65,66,53
+s5,65,80,72,59
+s5,80,100,86,71
+s5,100,120,94,79
+s5,120,140,110,92
+s5,140,160,118,100
+s5,160,180,126,108
+s5,180,200,142,122
+s5,200,225,150,130
+s5,225,250,160,140
+s5,250,280,181,158
+s5,280,315,193,170
+s5,315,355,215,190
+s5,355,400,233,208
+s5,400,450,259,232
+s5,450,500,279,252
+s5,500,560,312,280
+s5,560,630,342,310
+s5,630,710,376,340
+s5,710,800,416,380
+s5,800,900,470,430
+s5,900,1000,510,470
+s5,1000,1120,567,520
+s5,1120,1250,627,580
+s5,1250,1400,695,640
+s5,1400,1600,775,720
+s5,1600,1800,885,820
+s5,1800,2000,985,920
+s5,2000,2240,1078,1000
+s5,2240,2500,1178,1100
+s5,2500,2800,1346,1250
+s5,2800,3150,1496,1400
+s6,0,3,20,14
+s6,3,6,27,19
+s6,6,10,32,23
+s6,10,18,39,28
+s6,18,30,48,35
+s6,30,50,59,43
+s6,50,65,72,53
+s6,65,80,78,59
+s6,80,100,93,71
+s6,100,120,101,79
+s6,120,140,117,92
+s6,140,160,125,100
+s6,160,180,133,108
+s6,180,200,151,122
+s6,200,225,159,130
+s6,225,250,169,140
+s6,250,280,190,158
+s6,280,315,202,170
+s6,315,355,226,190
+s6,355,400,244,208
+s6,400,450,272,232
+s6,450,500,292,252
+s6,500,560,324,280
+s6,560,630,354,310
+s6,630,710,390,340
+s6,710,800,430,380
+s6,800,900,486,430
+s6,900,1000,526,470
+s6,1000,1120,586,520
+s6,1120,1250,646,580
+s6,1250,1400,718,640
+s6,1400,1600,798,720
+s6,1600,1800,912,820
+s6,1800,2000,1012,920
+s6,2000,2240,1110,1000
+s6,2240,2500,1210,1100
+s6,2500,2800,1385,1250
+s6,2800,3150,1535,1400
+s7,0,3,24,14
+s7,3,6,31,19
+s7,6,10,38,23
+s7,10,18,46,28
+s7,18,30,56,35
+s7,30,50,68,43
+s7,50,65,83,53
+s7,65,80,89,59
+s7,80,100,106,71
+s7,100,120,114,79
+s7,120,140,132,92
+s7,140,160,140,100
+s7,160,180,148,108
+s7,180,200,168,122
+s7,200,225,176,130
+s7,225,250,186,140
+s7,250,280,210,158
+s7,280,315,222,170
+s7,315,355,247,190
+s7,355,400,265,208
+s7,400,450,295,232
+s7,450,500,315,252
+s7,500,560,350,280
+s7,560,630,380,310
+s7,630,710,420,340
+s7,710,800,460,380
+s7,800,900,520,430
+s7,900,1000,560,470
+s7,1000,1120,625,520
+s7,1120,1250,685,580
+s7,1250,1400,765,640
+s7,1400,1600,845,720
+s7,1600,1800,970,820
+s7,1800,2000,1070,920
+s7,2000,2240,1175,1000
+s7,2240,2500,1275,1100
+s7,2500,2800,1460,1250
+s7,2800,3150,1610,1400
+s8,0,3,28,14
+s8,3,6,37,19
+s8,6,10,45,23
+s8,10,18,55,28
+s8,18,30,68,35
+s8,30,50,82,43
+s8,50,65,99,53
+s8,65,80,105,59
+s8,80,100,125,71
+s8,100,120,133,79
+s8,120,140,155,92
+s8,140,160,163,100
+s8,160,180,171,108
+s8,180,200,194,122
+s8,200,225,202,130
+s8,225,250,212,140
+s8,250,280,239,158
+s8,280,315,251,170
+s8,315,355,279,190
+s8,355,400,297,208
+s8,400,450,329,232
+s8,450,500,349,252
+s8,500,560,390,280
+s8,560,630,420,310
+s8,630,710,465,340
+s8,710,800,505,380
+s8,800,900,570,430
+s8,900,1000,610,470
+s8,1000,1120,685,520
+s8,1120,1250,745,580
+s8,1250,1400,835,640
+s8,1400,1600,915,720
+s8,1600,1800,1050,820
+s8,1800,2000,1150,920
+s8,2000,2240,1280,1000
+s8,2240,2500,1380,1100
+s8,2500,2800,1580,1250
+s8,2800,3150,1730,1400
+s9,0,3,39,14
+s9,3,6,49,19
+s9,6,10,59,23
+s9,10,18,71,28
+s9,18,30,87,35
+s9,30,50,105,43
+s9,50,65,127,53
+s9,65,80,133,59
+s9,80,100,158,71
+s9,100,120,166,79
+s9,120,140,192,92
+s9,140,160,200,100
+s9,160,180,208,108
+s9,180,200,237,122
+s9,200,225,245,130
+s9,225,250,255,140
+s9,250,280,288,158
+s9,280,315,300,170
+s9,315,355,330,190
+s9,355,400,348,208
+s9,400,450,387,232
+s9,450,500,407,252
+s9,500,560,455,280
+s9,560,630,485,310
+s9,630,710,540,340
+s9,710,800,580,380
+s9,800,900,660,430
+s9,900,1000,700,470
+s9,1000,1120,780,520
+s9,1120,1250,840,580
+s9,1250,1400,950,640
+s9,1400,1600,1030,720
+s9,1600,1800,1190,820
+s9,1800,2000,1290,920
+s9,2000,2240,1440,1000
+s9,2240,2500,1540,1100
+s9,2500,2800,1790,1250
+s9,2800,3150,1940,1400
+s10,0,3,54,14
+s10,3,6,67,19
+s10,6,10,81,23
+s10,10,18,98,28
+s10,18,30,119,35
+s10,30,50,143,43
+s10,50,65,173,53
+s10,65,80,179,59
+s10,80,100,211,71
+s10,100,120,219,79
+s10,120,140,252,92
+s10,140,160,260,100
+s10,160,180,268,108
+s10,180,200,307,122
+s10,200,225,315,130
+s10,225,250,325,140
+s10,250,280,368,158
+s10,280,315,380,170
+s10,315,355,420,190
+s10,355,400,438,208
+s10,400,450,482,232
+s10,450,500,502,252
+s10,500,560,560,280
+s10,560,630,590,310
+s10,630,710,660,340
+s10,710,800,700,380
+s10,800,900,790,430
+s10,900,1000,830,470
+s10,1000,1120,940,520
+s10,1120,1250,1000,580
+s10,1250,1400,1140,640
+s10,1400,1600,1220,720
+s10,1600,1800,1420,820
+s10,1800,2000,1520,920
+s10,2000,2240,1700,1000
+s10,2240,2500,1800,1100
+s10,2500,2800,2110,1250
+s10,2800,3150,2260,1400
+t5,24,30,50,41
+t5,30,40,59,48
+t5,40,50,65,54
+t5,50,65,79,66
+t5,65,80,88,75
+t5,80,100,106,91
+t5,100,120,119,104
+t5,120,140,140,122
+t5,140,160,152,134
+t5,160,180,164,146
+t5,180,200,186,166
+t5,200,225,200,180
+t5,225,250,216,196
+t5,250,280,241,218
+t5,280,315,263,240
+t5,315,355,293,268
+t5,355,400,319,294
+t5,400,450,357,330
+t5,450,500,387,360
+t5,500,560,432,400
+t5,560,630,482,450
+t5,630,710,536,500
+t5,710,800,596,560
+t5,800,900,660,620
+t5,900,1000,720,680
+t5,1000,1120,827,780
+t5,1120,1250,887,840
+t5,1250,1400,1015,960
+t5,1400,1600,1105,1050
+t5,1600,1800,1265,1200
+t5,1800,2000,1415,1350
+t5,2000,2240,1578,1500
+t5,2240,2500,1728,1650
+t5,2500,2800,1996,1900
+t5,2800,3150,2196,2100
+t6,24,30,54,41
+t6,30,40,64,48
+t6,40,50,70,54
+t6,50,65,85,66
+t6,65,80,94,75
+t6,80,100,113,91
+t6,100,120,126,104
+t6,120,140,147,122
+t6,140,160,159,134
+t6,160,180,171,146
+t6,180,200,195,166
+t6,200,225,209,180
+t6,225,250,225,196
+t6,250,280,250,218
+t6,280,315,272,240
+t6,315,355,304,268
+t6,355,400,330,294
+t6,400,450,370,330
+t6,450,500,400,360
+t6,500,560,444,400
+t6,560,630,494,450
+t6,630,710,550,500
+t6,710,800,610,560
+t6,800,900,676,620
+t6,900,1000,736,680
+t6,1000,1120,846,780
+t6,1120,1250,906,840
+t6,1250,1400,1038,960
+t6,1400,1600,1128,1050
+t6,1600,1800,1292,1200
+t6,1800,2000,1442,1350
+t6,2000,2240,1610,1500
+t6,2240,2500,1760,1650
+t6,2500,2800,2035,1900
+t6,2800,3150,2235,2100
+t7,24,30,62,41
+t7,30,40,73,48
+t7,40,50,79,54
+t7,50,65,96,66
+t7,65,80,105,75
+t7,80,100,126,91
+t7,100,120,139,104
+t7,120,140,162,122
+t7,140,160,174,134
+t7,160,180,186,146
+t7,180,200,212,166
+t7,200,225,226,180
+t7,225,250,242,196
+t7,250,280,270,218
+t7,280,315,292,240
+t7,315,355,325,268
+t7,355,400,351,294
+t7,400,450,393,330
+t7,450,500,423,360
+t7,500,560,470,400
+t7,560,630,520,450
+t7,630,710,580,500
+t7,710,800,640,560
+t7,800,900,710,620
+t7,900,1000,770,680
+t7,1000,1120,885,780
+t7,1120,1250,945,840
+t7,1250,1400,1085,960
+t7,1400,1600,1175,1050
+t7,1600,1800,1350,1200
+t7,1800,2000,1500,1350
+t7,2000,2240,1675,1500
+t7,2240,2500,1825,1650
+t7,2500,2800,2110,1900
+t7,2800,3150,2310,2100
+t8,24,30,74,41
+t8,30,40,87,48
+t8,40,50,93,54
+t8,50,65,112,66
+t8,65,80,121,75
+t8,80,100,145,91
+t8,100,120,158,104
+t8,120,140,185,122
+t8,140,160,197,134
+t8,160,180,209,146
+t8,180,200,238,166
+t8,200,225,252,180
+t8,225,250,268,196
+t8,250,280,299,218
+t8,280,315,321,240
+t8,315,355,357,268
+t8,355,400,383,294
+t8,400,450,427,330
+t8,450,500,457,360
+t8,500,560,510,400
+t8,560,630,560,450
+t8,630,710,625,500
+t8,710,800,685,560
+t8,800,900,760,620
+t8,900,1000,820,680
+t8,1000,1120,945,780
+t8,1120,1250,1005,840
+t8,1250,1400,1155,960
+t8,1400,1600,1245,1050
+t8,1600,1800,1430,1200
+t8,1800,2000,1580,1350
+t8,2000,2240,1780,1500
+t8,2240,2500,1930,1650
+t8,2500,2800,2230,1900
+t8,2800,3150,2430,2100
+u5,0,3,22,18
+u5,3,6,28,23
+u5,6,10,34,28
+u5,10,18,41,33
+u5,18,24,50,41
+u5,24,30,57,48
+u5,30,40,71,60
+u5,40,50,81,70
+u5,50,65,100,87
+u5,65,80,115,102
+u5,80,100,139,124
+u5,100,120,159,144
+u5,120,140,188,170
+u5,140,160,208,190
+u5,160,180,228,210
+u5,180,200,256,236
+u5,200,225,278,258
+u5,225,250,304,284
+u5,250,280,338,315
+u5,280,315,373,350
+u5,315,355,415,390
+u5,355,400,460,435
+u5,400,450,517,490
+u5,450,500,567,540
+u5,500,560,632,600
+u5,560,630,692,660
+u5,630,710,776,740
+u5,710,800,876,840
+u5,800,900,980,940
+u5,900,1000,1090,1050
+u5,1000,1120,1197,1150
+u5,1120,1250,1347,1300
+u5,1250,1400,1505,1450
+u5,1400,1600,1655,1600
+u5,1600,1800,1915,1850
+u5,1800,2000,2065,2000
+u5,2000,2240,2378,2300
+u5,2240,2500,2578,2500
+u5,2500,2800,2996,2900
+u5,2800,3150,3296,3200
+u6,0,3,24,18
+u6,3,6,31,23
+u6,6,10,37,28
+u6,10,18,44,33
+u6,18,24,54,41
+u6,24,30,61,48
+u6,30,40,76,60
+u6,40,50,86,70
+u6,50,65,106,87
+u6,65,80,121,102
+u6,80,100,146,124
+u6,100,120,166,144
+u6,120,140,195,170
+u6,140,160,215,190
+u6,160,180,235,210
+u6,180,200,265,236
+u6,200,225,287,258
+u6,225,250,313,284
+u6,250,280,347,315
+u6,280,315,382,350
+u6,315,355,426,390
+u6,355,400,471,435
+u6,400,450,530,490
+u6,450,500,580,540
+u6,500,560,644,600
+u6,560,630,704,660
+u6,630,710,790,740
+u6,710,800,890,840
+u6,800,900,996,940
+u6,900,1000,1106,1050
+u6,1000,1120,1216,1150
+u6,1120,1250,1366,1300
+u6,1250,1400,1528,1450
+u6,1400,1600,1678,1600
+u6,1600,1800,1942,1850
+u6,1800,2000,2092,2000
+u6,2000,2240,2410,2300
+u6,2240,2500,2610,2500
+u6,2500,2800,3035,2900
+u6,2800,3150,3335,3200
+u7,0,3,28,18
+u7,3,6,35,23
+u7,6,10,43,28
+u7,10,18,51,33
+u7,18,24,62,41
+u7,24,30,69,48
+u7,30,40,85,60
+u7,40,50,95,70
+u7,50,65,117,87
+u7,65,80,132,102
+u7,80,100,159,124
+u7,100,120,179,144
+u7,120,140,210,170
+u7,140,160,230,190
+u7,160,180,250,210
+u7,180,200,282,236
+u7,200,225,304,258
+u7,225,250,330,284
+u7,250,280,367,315
+u7,280,315,402,350
+u7,315,355,447,390
+u7,355,400,492,435
+u7,400,450,553,490
+u7,450,500,603,540
+u7,500,560,670,600
+u7,560,630,730,660
+u7,630,710,820,740
+u7,710,800,920,840
+u7,800,900,1030,940
+u7,900,1000,1140,1050
+u7,1000,1120,1255,1150
+u7,1120,1250,1405,1300
+u7,1250,1400,1575,1450
+u7,1400,1600,1725,1600
+u7,1600,1800,2000,1850
+u7,1800,2000,2150,2000
+u7,2000,2240,2475,2300
+u7,2240,2500,2675,2500
+u7,2500,2800,3110,2900
+u7,2800,3150,3410,3200
+u8,0,3,32,18
+u8,3,6,41,23
+u8,6,10,50,28
+u8,10,18,60,33
+u8,18,24,74,41
+u8,24,30,81,48
+u8,30,40,99,60
+u8,40,50,109,70
+u8,50,65,133,87
+u8,65,80,148,102
+u8,80,100,178,124
+u8,100,120,198,144
+u8,120,140,233,170
+u8,140,160,253,190
+u8,160,180,273,210
+u8,180,200,308,236
+u8,200,225,330,258
+u8,225,250,356,284
+u8,250,280,396,315
+u8,280,315,431,350
+u8,315,355,479,390
+u8,355,400,524,435
+u8,400,450,587,490
+u8,450,500,637,540
+u8,500,560,710,600
+u8,560,630,770,660
+u8,630,710,865,740
+u8,710,800,965,840
+u8,800,900,1080,940
+u8,900,1000,1190,1050
+u8,1000,1120,1315,1150
+u8,1120,1250,1465,1300
+u8,1250,1400,1645,1450
+u8,1400,1600,1795,1600
+u8,1600,1800,2080,1850
+u8,1800,2000,2230,2000
+u8,2000,2240,2580,2300
+u8,2240,2500,2780,2500
+u8,2500,2800,3230,2900
+u8,2800,3150,3530,3200
+u9,0,3,43,18
+u9,3,6,53,23
+u9,6,10,64,28
+u9,10,18,76,33
+u9,18,24,93,41
+u9,24,30,100,48
+u9,30,40,122,60
+u9,40,50,132,70
+u9,50,65,161,87
+u9,65,80,176,102
+u9,80,100,211,124
+u9,100,120,231,144
+u9,120,140,270,170
+u9,140,160,290,190
+u9,160,180,310,210
+u9,180,200,351,236
+u9,200,225,373,258
+u9,225,250,399,284
+u9,250,280,445,315
+u9,280,315,480,350
+u9,315,355,530,390
+u9,355,400,575,435
+u9,400,450,645,490
+u9,450,500,695,540
+u9,500,560,775,600
+u9,560,630,835,660
+u9,630,710,940,740
+u9,710,800,1040,840
+u9,800,900,1170,940
+u9,900,1000,1280,1050
+u9,1000,1120,1410,1150
+u9,1120,1250,1560,1300
+u9,1250,1400,1760,1450
+u9,1400,1600,1910,1600
+u9,1600,1800,2220,1850
+u9,1800,2000,2370,2000
+u9,2000,2240,2740,2300
+u9,2240,2500,2940,2500
+u9,2500,2800,3440,2900
+u9,2800,3150,3740,3200
+v5,14,18,47,39
+v5,18,24,56,47
+v5,24,30,64,55
+v5,30,40,79,68
+v5,40,50,92,81
+v5,50,65,115,102
+v5,65,80,133,120
+v5,80,100,161,146
+v5,100,120,187,172
+v5,120,140,220,202
+v5,140,160,246,228
+v5,160,180,270,252
+v5,180,200,304,284
+v5,200,225,330,310
+v5,225,250,360,340
+v5,250,280,408,385
+v5,280,315,448,425
+v5,315,355,500,475
+v5,355,400,555,530
+v5,400,450,622,595
+v5,450,500,687,660
+v6,14,18,50,39
+v6,18,24,60,47
+v6,24,30,68,55
+v6,30,40,84,68
+v6,40,50,97,81
+v6,50,65,121,102
+v6,65,80,139,120
+v6,80,100,168,146
+v6,100,120,194,172
+v6,120,140,227,202
+v6,140,160,253,228
+v6,160,180,277,252
+v6,180,200,313,284
+v6,200,225,339,310
+v6,225,250,369,340
+v6,250,280,417,385
+v6,280,315,457,425
+v6,315,355,511,475
+v6,355,400,566,530
+v6,400,450,635,595
+v6,450,500,700,660
+v7,14,18,57,39
+v7,18,24,68,47
+v7,24,30,76,55
+v7,30,40,93,68
+v7,40,50,106,81
+v7,50,65,132,102
+v7,65,80,150,120
+v7,80,100,181,146
+v7,100,120,207,172
+v7,120,140,242,202
+v7,140,160,268,228
+v7,160,180,292,252
+v7,180,200,330,284
+v7,200,225,356,310
+v7,225,250,386,340
+v7,250,280,437,385
+v7,280,315,477,425
+v7,315,355,532,475
+v7,355,400,587,530
+v7,400,450,658,595
+v7,450,500,723,660
+v8,14,18,66,39
+v8,18,24,80,47
+v8,24,30,88,55
+v8,30,40,107,68
+v8,40,50,120,81
+v8,50,65,148,102
+v8,65,80,166,120
+v8,80,100,200,146
+v8,100,120,226,172
+v8,120,140,265,202
+v8,140,160,291,228
+v8,160,180,315,252
+v8,180,200,356,284
+v8,200,225,382,310
+v8,225,250,412,340
+v8,250,280,466,385
+v8,280,315,506,425
+v8,315,355,564,475
+v8,355,400,619,530
+v8,400,450,692,595
+v8,450,500,757,660
+x5,0,3,24,20
+x5,3,6,33,28
+x5,6,10,40,34
+x5,10,14,48,40
+x5,14,18,53,45
+x5,18,24,63,54
+x5,24,30,73,64
+x5,30,40,91,80
+x5,40,50,108,97
+x5,50,65,135,122
+x5,65,80,159,146
+x5,80,100,193,178
+x5,100,120,225,210
+x5,120,140,266,248
+x5,140,160,298,280
+x5,160,180,328,310
+x5,180,200,370,350
+x5,200,225,405,385
+x5,225,250,445,425
+x5,250,280,498,475
+x5,280,315,548,525
+x5,315,355,615,590
+x5,355,400,685,660
+x5,400,450,767,740
+x5,450,500,847,820
+x6,0,3,26,20
+x6,3,6,36,28
+x6,6,10,43,34
+x6,10,14,51,40
+x6,14,18,56,45
+x6,18,24,67,54
+x6,24,30,77,64
+x6,30,40,96,80
+x6,40,50,113,97
+x6,50,65,141,122
+x6,65,80,165,146
+x6,80,100,200,178
+x6,100,120,232,210
+x6,120,140,273,248
+x6,140,160,305,280
+x6,160,180,335,310
+x6,180,200,379,350
+x6,200,225,414,385
+x6,225,250,454,425
+x6,250,280,507,475
+x6,280,315,557,525
+x6,315,355,626,590
+x6,355,400,696,660
+x6,400,450,780,740
+x6,450,500,860,820
+x7,0,3,30,20
+x7,3,6,40,28
+x7,6,10,49,34
+x7,10,14,58,40
+x7,14,18,63,45
+x7,18,24,75,54
+x7,24,30,85,64
+x7,30,40,105,80
+x7,40,50,122,97
+x7,50,65,152,122
+x7,65,80,176,146
+x7,80,100,213,178
+x7,100,120,245,210
+x7,120,140,288,248
+x7,140,160,320,280
+x7,160,180,350,310
+x7,180,200,396,350
+x7,200,225,431,385
+x7,225,250,471,425
+x7,250,280,527,475
+x7,280,315,577,525
+x7,315,355,647,590
+x7,355,400,717,660
+x7,400,450,803,740
+x7,450,500,883,820
+x8,0,3,34,20
+x8,3,6,46,28
+x8,6,10,56,34
+x8,10,14,67,40
+x8,14,18,72,45
+x8,18,24,87,54
+x8,24,30,97,64
+x8,30,40,119,80
+x8,40,50,136,97
+x8,50,65,168,122
+x8,65,80,192,146
+x8,80,100,232,178
+x8,100,120,264,210
+x8,120,140,311,248
+x8,140,160,343,280
+x8,160,180,373,310
+x8,180,200,422,350
+x8,200,225,457,385
+x8,225,250,497,425
+x8,250,280,556,475
+x8,280,315,606,525
+x8,315,355,679,590
+x8,355,400,749,660
+x8,400,450,837,740
+x8,450,500,917,820
+x9,0,3,45,20
+x9,3,6,58,28
+x9,6,10,70,34
+x9,10,14,83,40
+x9,14,18,88,45
+x9,18,24,106,54
+x9,24,30,116,64
+x9,30,40,142,80
+x9,40,50,159,97
+x9,50,65,196,122
+x9,65,80,220,146
+x9,80,100,265,178
+x9,100,120,297,210
+x9,120,140,348,248
+x9,140,160,380,280
+x9,160,180,410,310
+x9,180,200,465,350
+x9,200,225,500,385
+x9,225,250,540,425
+x9,250,280,605,475
+x9,280,315,655,525
+x9,315,355,730,590
+x9,355,400,800,660
+x9,400,450,895,740
+x9,450,500,975,820
+x10,0,3,60,20
+x10,3,6,76,28
+x10,6,10,92,34
+x10,10,14,110,40
+x10,14,18,115,45
+x10,18,24,138,54
+x10,24,30,148,64
+x10,30,40,180,80
+x10,40,50,197,97
+x10,50,65,242,122
+x10,65,80,266,146
+x10,80,100,318,178
+x10,100,120,350,210
+x10,120,140,408,248
+x10,140,160,440,280
+x10,160,180,470,310
+x10,180,200,535,350
+x10,200,225,570,385
+x10,225,250,610,425
+x10,250,280,685,475
+x10,280,315,735,525
+x10,315,355,820,590
+x10,355,400,890,660
+x10,400,450,990,740
+x10,450,500,1070,820
+y6,18,24,76,63
+y6,24,30,88,75
+y6,30,40,110,94
+y6,40,50,130,114
+y6,50,65,163,144
+y6,65,80,193,174
+y6,80,100,236,214
+y6,100,120,276,254
+y6,120,140,325,300
+y6,140,160,365,340
+y6,160,180,405,380
+y6,180,200,454,425
+y6,200,225,499,470
+y6,225,250,549,520
+y6,250,280,612,580
+y6,280,315,682,650
+y6,315,355,766,730
+y6,355,400,856,820
+y6,400,450,960,920
+y6,450,500,1040,1000
+y7,18,24,84,63
+y7,24,30,96,75
+y7,30,40,119,94
+y7,40,50,139,114
+y7,50,65,174,144
+y7,65,80,204,174
+y7,80,100,249,214
+y7,100,120,289,254
+y7,120,140,340,300
+y7,140,160,380,340
+y7,160,180,420,380
+y7,180,200,471,425
+y7,200,225,516,470
+y7,225,250,566,520
+y7,250,280,632,580
+y7,280,315,702,650
+y7,315,355,787,730
+y7,355,400,877,820
+y7,400,450,983,920
+y7,450,500,1063,1000
+y8,18,24,96,63
+y8,24,30,108,75
+y8,30,40,133,94
+y8,40,50,153,114
+y8,50,65,190,144
+y8,65,80,220,174
+y8,80,100,268,214
+y8,100,120,308,254
+y8,120,140,363,300
+y8,140,160,403,340
+y8,160,180,443,380
+y8,180,200,497,425
+y8,200,225,542,470
+y8,225,250,592,520
+y8,250,280,661,580
+y8,280,315,731,650
+y8,315,355,819,730
+y8,355,400,909,820
+y8,400,450,1017,920
+y8,450,500,1097,1000
+y9,18,24,115,63
+y9,24,30,127,75
+y9,30,40,156,94
+y9,40,50,176,114
+y9,50,65,218,144
+y9,65,80,248,174
+y9,80,100,301,214
+y9,100,120,341,254
+y9,120,140,400,300
+y9,140,160,440,340
+y9,160,180,480,380
+y9,180,200,540,425
+y9,200,225,585,470
+y9,225,250,635,520
+y9,250,280,710,580
+y9,280,315,780,650
+y9,315,355,870,730
+y9,355,400,960,820
+y9,400,450,1075,920
+y9,450,500,1155,1000
+y10,18,24,147,63
+y10,24,30,159,75
+y10,30,40,194,94
+y10,40,50,214,114
+y10,50,65,264,144
+y10,65,80,294,174
+y10,80,100,354,214
+y10,100,120,394,254
+y10,120,140,460,300
+y10,140,160,500,340
+y10,160,180,540,380
+y10,180,200,610,425
+y10,200,225,655,470
+y10,225,250,705,520
+y10,250,280,790,580
+y10,280,315,860,650
+y10,315,355,960,730
+y10,355,400,1050,820
+y10,400,450,1170,920
+y10,450,500,1250,1000
+z6,0,3,32,26
+z6,3,6,43,35
+z6,6,10,51,42
+z6,10,14,61,50
+z6,14,18,71,60
+z6,18,24,86,73
+z6,24,30,101,88
+z6,30,40,128,112
+z6,40,50,152,136
+z6,50,65,191,172
+z6,65,80,229,210
+z6,80,100,280,258
+z6,100,120,332,310
+z6,120,140,390,365
+z6,140,160,440,415
+z6,160,180,490,465
+z6,180,200,549,520
+z6,200,225,604,575
+z6,225,250,669,640
+z6,250,280,742,710
+z6,280,315,822,790
+z6,315,355,936,900
+z6,355,400,1036,1000
+z6,400,450,1140,1100
+z6,450,500,1290,1250
+z7,0,3,36,26
+z7,3,6,47,35
+z7,6,10,57,42
+z7,10,14,68,50
+z7,14,18,78,60
+z7,18,24,94,73
+z7,24,30,109,88
+z7,30,40,137,112
+z7,40,50,161,136
+z7,50,65,202,172
+z7,65,80,240,210
+z7,80,100,293,258
+z7,100,120,345,310
+z7,120,140,405,365
+z7,140,160,455,415
+z7,160,180,505,465
+z7,180,200,566,520
+z7,200,225,621,575
+z7,225,250,686,640
+z7,250,280,762,710
+z7,280,315,842,790
+z7,315,355,957,900
+z7,355,400,1057,1000
+z7,400,450,1163,1100
+z7,450,500,1313,1250
+z8,0,3,40,26
+z8,3,6,53,35
+z8,6,10,64,42
+z8,10,14,77,50
+z8,14,18,87,60
+z8,18,24,106,73
+z8,24,30,121,88
+z8,30,40,151,112
+z8,40,50,175,136
+z8,50,65,218,172
+z8,65,80,256,210
+z8,80,100,312,258
+z8,100,120,364,310
+z8,120,140,428,365
+z8,140,160,478,415
+z8,160,180,528,465
+z8,180,200,592,520
+z8,200,225,647,575
+z8,225,250,712,640
+z8,250,280,791,710
+z8,280,315,871,790
+z8,315,355,989,900
+z8,355,400,1089,1000
+z8,400,450,1197,1100
+z8,450,500,1347,1250
+z9,0,3,51,26
+z9,3,6,65,35
+z9,6,10,78,42
+z9,10,14,93,50
+z9,14,18,103,60
+z9,18,24,125,73
+z9,24,30,140,88
+z9,30,40,174,112
+z9,40,50,198,136
+z9,50,65,246,172
+z9,65,80,284,210
+z9,80,100,345,258
+z9,100,120,397,310
+z9,120,140,465,365
+z9,140,160,515,415
+z9,160,180,565,465
+z9,180,200,635,520
+z9,200,225,690,575
+z9,225,250,755,640
+z9,250,280,840,710
+z9,280,315,920,790
+z9,315,355,1040,900
+z9,355,400,1140,1000
+z9,400,450,1255,1100
+z9,450,500,1405,1250
+z10,0,3,66,26
+z10,3,6,83,35
+z10,6,10,100,42
+z10,10,14,120,50
+z10,14,18,130,60
+z10,18,24,157,73
+z10,24,30,172,88
+z10,30,40,212,112
+z10,40,50,236,136
+z10,50,65,292,172
+z10,65,80,330,210
+z10,80,100,398,258
+z10,100,120,450,310
+z10,120,140,525,365
+z10,140,160,575,415
+z10,160,180,625,465
+z10,180,200,705,520
+z10,200,225,760,575
+z10,225,250,825,640
+z10,250,280,920,710
+z10,280,315,1000,790
+z10,315,355,1130,900
+z10,355,400,1230,1000
+z10,400,450,1350,1100
+z10,450,500,1500,1250
+z11,0,3,86,26
+z11,3,6,110,35
+z11,6,10,132,42
+z11,10,14,160,50
+z11,14,18,170,60
+z11,18,24,203,73
+z11,24,30,218,88
+z11,30,40,272,112
+z11,40,50,296,136
+z11,50,65,362,172
+z11,65,80,400,210
+z11,80,100,478,258
+z11,100,120,530,310
+z11,120,140,615,365
+z11,140,160,665,415
+z11,160,180,715,465
+z11,180,200,810,520
+z11,200,225,865,575
+z11,225,250,930,640
+z11,250,280,1030,710
+z11,280,315,1110,790
+z11,315,355,1260,900
+z11,355,400,1360,1000
+z11,400,450,1500,1100
+z11,450,500,1650,1250
+za6,0,3,38,32
+za6,3,6,50,42
+za6,6,10,61,52
+za6,10,14,75,64
+za6,14,18,88,77
+za6,18,24,111,98
+za6,24,30,131,118
+za6,30,40,164,148
+za6,40,50,196,180
+za6,50,65,245,226
+za6,65,80,293,274
+za6,80,100,357,335
+za6,100,120,422,400
+za6,120,140,495,470
+za6,140,160,560,535
+za6,160,180,625,600
+za6,180,200,699,670
+za6,200,225,769,740
+za6,225,250,849,820
+za6,250,280,952,920
+za6,280,315,1032,1000
+za6,315,355,1186,1150
+za6,355,400,1336,1300
+za6,400,450,1490,1450
+za6,450,500,1640,1600
+za7,0,3,42,32
+za7,3,6,54,42
+za7,6,10,67,52
+za7,10,14,82,64
+za7,14,18,95,77
+za7,18,24,119,98
+za7,24,30,139,118
+za7,30,40,173,148
+za7,40,50,205,180
+za7,50,65,256,226
+za7,65,80,304,274
+za7,80,100,370,335
+za7,100,120,435,400
+za7,120,140,510,470
+za7,140,160,575,535
+za7,160,180,640,600
+za7,180,200,716,670
+za7,200,225,786,740
+za7,225,250,866,820
+za7,250,280,972,920
+za7,280,315,1052,1000
+za7,315,355,1207,1150
+za7,355,400,1357,1300
+za7,400,450,1513,1450
+za7,450,500,1663,1600
+za8,0,3,46,32
+za8,3,6,60,42
+za8,6,10,74,52
+za8,10,14,91,64
+za8,14,18,104,77
+za8,18,24,131,98
+za8,24,30,151,118
+za8,30,40,187,148
+za8,40,50,219,180
+za8,50,65,272,226
+za8,65,80,320,274
+za8,80,100,389,335
+za8,100,120,454,400
+za8,120,140,533,470
+za8,140,160,598,535
+za8,160,180,663,600
+za8,180,200,742,670
+za8,200,225,812,740
+za8,225,250,892,820
+za8,250,280,1001,920
+za8,280,315,1081,1000
+za8,315,355,1239,1150
+za8,355,400,1389,1300
+za8,400,450,1547,1450
+za8,450,500,1697,1600
+za9,0,3,57,32
+za9,3,6,72,42
+za9,6,10,88,52
+za9,10,14,107,64
+za9,14,18,120,77
+za9,18,24,150,98
+za9,24,30,170,118
+za9,30,40,210,148
+za9,40,50,242,180
+za9,50,65,300,226
+za9,65,80,348,274
+za9,80,100,422,335
+za9,100,120,487,400
+za9,120,140,570,470
+za9,140,160,635,535
+za9,160,180,700,600
+za9,180,200,785,670
+za9,200,225,855,740
+za9,225,250,935,820
+za9,250,280,1050,920
+za9,280,315,1130,1000
+za9,315,355,1290,1150
+za9,355,400,1440,1300
+za9,400,450,1605,1450
+za9,450,500,1755,1600
+za10,0,3,72,32
+za10,3,6,90,42
+za10,6,10,110,52
+za10,10,14,134,64
+za10,14,18,147,77
+za10,18,24,182,98
+za10,24,30,202,118
+za10,30,40,248,148
+za10,40,50,280,180
+za10,50,65,346,226
+za10,65,80,394,274
+za10,80,100,475,335
+za10,100,120,540,400
+za10,120,140,630,470
+za10,140,160,695,535
+za10,160,180,760,600
+za10,180,200,855,670
+za10,200,225,925,740
+za10,225,250,1005,820
+za10,250,280,1130,920
+za10,280,315,1210,1000
+za10,315,355,1380,1150
+za10,355,400,1530,1300
+za10,400,450,1700,1450
+za10,450,500,1850,1600
+za11,0,3,92,32
+za11,3,6,117,42
+za11,6,10,142,52
+za11,10,14,174,64
+za11,14,18,187,77
+za11,18,24,228,98
+za11,24,30,248,118
+za11,30,40,308,148
+za11,40,50,340,180
+za11,50,65,416,226
+za11,65,80,464,274
+za11,80,100,555,335
+za11,100,120,620,400
+za11,120,140,720,470
+za11,140,160,785,535
+za11,160,180,850,600
+za11,180,200,960,670
+za11,200,225,1030,740
+za11,225,250,1110,820
+za11,250,280,1240,920
+za11,280,315,1320,1000
+za11,315,355,1510,1150
+za11,355,400,1660,1300
+za11,400,450,1850,1450
+za11,450,500,2000,1600
+zb7,0,3,50,40
+zb7,3,6,62,50
+zb7,6,10,82,67
+zb7,10,14,108,90
+zb7,14,18,126,108
+zb7,18,24,157,136
+zb7,24,30,181,160
+zb7,30,40,225,200
+zb7,40,50,267,242
+zb7,50,65,330,300
+zb7,65,80,390,360
+zb7,80,100,480,445
+zb7,100,120,560,525
+zb7,120,140,660,620
+zb7,140,160,740,700
+zb7,160,180,820,780
+zb7,180,200,926,880
+zb7,200,225,1006,960
+zb7,225,250,1096,1050
+zb7,250,280,1252,1200
+zb7,280,315,1352,1300
+zb7,315,355,1557,1500
+zb7,355,400,1707,1650
+zb7,400,450,1913,1850
+zb7,450,500,2163,2100
+zb8,0,3,54,40
+zb8,3,6,68,50
+zb8,6,10,89,67
+zb8,10,14,117,90
+zb8,14,18,135,108
+zb8,18,24,169,136
+zb8,24,30,193,160
+zb8,30,40,239,200
+zb8,40,50,281,242
+zb8,50,65,346,300
+zb8,65,80,406,360
+zb8,80,100,499,445
+zb8,100,120,579,525
+zb8,120,140,683,620
+zb8,140,160,763,700
+zb8,160,180,843,780
+zb8,180,200,952,880
+zb8,200,225,1032,960
+zb8,225,250,1122,1050
+zb8,250,280,1281,1200
+zb8,280,315,1381,1300
+zb8,315,355,1589,1500
+zb8,355,400,1739,1650
+zb8,400,450,1947,1850
+zb8,450,500,2197,2100
+zb9,0,3,65,40
+zb9,3,6,80,50
+zb9,6,10,103,67
+zb9,10,14,133,90
+zb9,14,18,151,108
+zb9,18,24,188,136
+zb9,24,30,212,160
+zb9,30,40,262,200
+zb9,40,50,304,242
+zb9,50,65,374,300
+zb9,65,80,434,360
+zb9,80,100,532,445
+zb9,100,120,612,525
+zb9,120,140,720,620
+zb9,140,160,800,700
+zb9,160,180,880,780
+zb9,180,200,995,880
+zb9,200,225,1075,960
+zb9,225,250,1165,1050
+zb9,250,280,1330,1200
+zb9,280,315,1430,1300
+zb9,315,355,1640,1500
+zb9,355,400,1790,1650
+zb9,400,450,2005,1850
+zb9,450,500,2255,2100
+zb10,0,3,80,40
+zb10,3,6,98,50
+zb10,6,10,125,67
+zb10,10,14,160,90
+zb10,14,18,178,108
+zb10,18,24,220,136
+zb10,24,30,244,160
+zb10,30,40,300,200
+zb10,40,50,342,242
+zb10,50,65,420,300
+zb10,65,80,480,360
+zb10,80,100,585,445
+zb10,100,120,665,525
+zb10,120,140,780,620
+zb10,140,160,860,700
+zb10,160,180,940,780
+zb10,180,200,1065,880
+zb10,200,225,1145,960
+zb10,225,250,1235,1050
+zb10,250,280,1410,1200
+zb10,280,315,1510,1300
+zb10,315,355,1730,1500
+zb10,355,400,1880,1650
+zb10,400,450,2100,1850
+zb10,450,500,2350,2100
+zb11,0,3,100,40
+zb11,3,6,125,50
+zb11,6,10,157,67
+zb11,10,14,200,90
+zb11,14,18,218,108
+zb11,18,24,266,136
+zb11,24,30,290,160
+zb11,30,40,360,200
+zb11,40,50,402,242
+zb11,50,65,490,300
+zb11,65,80,550,360
+zb11,80,100,665,445
+zb11,100,120,745,525
+zb11,120,140,870,620
+zb11,140,160,950,700
+zb11,160,180,1030,780
+zb11,180,200,1170,880
+zb11,200,225,1250,960
+zb11,225,250,1340,1050
+zb11,250,280,1520,1200
+zb11,280,315,1620,1300
+zb11,315,355,1860,1500
+zb11,355,400,2010,1650
+zb11,400,450,2250,1850
+zb11,450,500,2500,2100
+zc7,0,3,70,60
+zc7,3,6,92,80
+zc7,6,10,112,97
+zc7,10,14,148,130
+zc7,14,18,168,150
+zc7,18,24,209,188
+zc7,24,30,239,218
+zc7,30,40,299,274
+zc7,40,50,350,325
+zc7,50,65,435,405
+zc7,65,80,510,480
+zc7,80,100,620,585
+zc7,100,120,725,690
+zc7,120,140,840,800
+zc7,140,160,940,900
+zc7,160,180,1040,1000
+zc7,180,200,1196,1150
+zc7,200,225,1296,1250
+zc7,225,250,1396,1350
+zc7,250,280,1602,1550
+zc7,280,315,1752,1700
+zc7,315,355,1957,1900
+zc7,355,400,2157,2100
+zc7,400,450,2463,2400
+zc7,450,500,2663,2600
+zc8,0,3,74,60
+zc8,3,6,98,80
+zc8,6,10,119,97
+zc8,10,14,157,130
+zc8,14,18,177,150
+zc8,18,24,221,188
+zc8,24,30,251,218
+zc8,30,40,313,274
+zc8,40,50,364,325
+zc8,50,65,451,405
+zc8,65,80,526,480
+zc8,80,100,639,585
+zc8,100,120,744,690
+zc8,120,140,863,800
+zc8,140,160,963,900
+zc8,160,180,1063,1000
+zc8,180,200,1222,1150
+zc8,200,225,1322,1250
+zc8,225,250,1422,1350
+zc8,250,280,1631,1550
+zc8,280,315,1781,1700
+zc8,315,355,1989,1900
+zc8,355,400,2189,2100
+zc8,400,450,2497,2400
+zc8,450,500,2697,2600
+zc9,0,3,85,60
+zc9,3,6,110,80
+zc9,6,10,133,97
+zc9,10,14,173,130
+zc9,14,18,193,150
+zc9,18,24,240,188
+zc9,24,30,270,218
+zc9,30,40,336,274
+zc9,40,50,387,325
+zc9,50,65,479,405
+zc9,65,80,554,480
+zc9,80,100,672,585
+zc9,100,120,777,690
+zc9,120,140,900,800
+zc9,140,160,1000,900
+zc9,160,180,1100,1000
+zc9,180,200,1265,1150
+zc9,200,225,1365,1250
+zc9,225,250,1465,1350
+zc9,250,280,1680,1550
+zc9,280,315,1830,1700
+zc9,315,355,2040,1900
+zc9,355,400,2240,2100
+zc9,400,450,2555,2400
+zc9,450,500,2755,2600
+zc10,0,3,100,60
+zc10,3,6,128,80
+zc10,6,10,155,97
+zc10,10,14,200,130
+zc10,14,18,220,150
+zc10,18,24,272,188
+zc10,24,30,302,218
+zc10,30,40,374,274
+zc10,40,50,425,325
+zc10,50,65,525,405
+zc10,65,80,600,480
+zc10,80,100,725,585
+zc10,100,120,830,690
+zc10,120,140,960,800
+zc10,140,160,1060,900
+zc10,160,180,1160,1000
+zc10,180,200,1335,1150
+zc10,200,225,1435,1250
+zc10,225,250,1535,1350
+zc10,250,280,1760,1550
+zc10,280,315,1910,1700
+zc10,315,355,2130,1900
+zc10,355,400,2330,2100
+zc10,400,450,2650,2400
+zc10,450,500,2850,2600
+zc11,0,3,120,60
+zc11,3,6,155,80
+zc11,6,10,187,97
+zc11,10,14,240,130
+zc11,14,18,260,150
+zc11,18,24,318,188
+zc11,24,30,348,218
+zc11,30,40,434,274
+zc11,40,50,485,325
+zc11,50,65,595,405
+zc11,65,80,670,480
+zc11,80,100,805,585
+zc11,100,120,910,690
+zc11,120,140,1050,800
+zc11,140,160,1150,900
+zc11,160,180,1250,1000
+zc11,180,200,1440,1150
+zc11,200,225,1540,1250
+zc11,225,250,1640,1350
+zc11,250,280,1870,1550
+zc11,280,315,2020,1700
+zc11,315,355,2260,1900
+zc11,355,400,2460,2100
+zc11,400,450,2800,2400
+zc11,450,500,3000,2600
+";
@@ -0,0 +1,224 @@
+use std::fmt;
+
+use super::{
+    lookup::DELTA,
+    tolerance::{FundamentalDeviation, IsoError, Letter, ToleranceGrade},
+};
+
+// Every special case of ISO 286-1:2010 applied on top of the lookup tables,
+// each with the part of the standard it comes from. Sizes are whole mm
+// rounded up to match the table rows, deviations are in nanometres
+
+/// A special case that changed a result, in the order it was applied.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Rule {
+    /// JS7 to JS11 with an odd IT value in µm are split from the next even value down.
+    JsEven,
+    /// Δ in nanometres added to the upper deviation of a K to ZC hole.
+    Delta(i32),
+    /// N above IT8 has an upper deviation of zero over 3 mm.
+    NAboveIt8,
+    /// k up to IT3 and above IT7 has a lower deviation of zero.
+    KOutsideIt4ToIt7,
+    /// M6 from 250 mm to 315 mm is -9 µm rather than -11 µm.
+    M6,
+}
+
+impl Rule {
+    /// Where the rule is written down in the standard.
+    pub fn clause(self) -> &'static str {
+        match self {
+            Self::JsEven => "ISO 286-1:2010 Table 2, footnote to JS",
+            Self::Delta(_) => "ISO 286-1:2010 Table 3, special rule and Δ values",
+            Self::NAboveIt8 => "ISO 286-1:2010 Table 3, N above IT8",
+            Self::KOutsideIt4ToIt7 => "ISO 286-1:2010 Table 5, k up to IT3 and above IT7",
+            Self::M6 => "ISO 286-1:2010 Table 2, special case for M6",
+        }
+    }
+}
+
+impl fmt::Display for Rule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::JsEven => write!(f, "Odd IT value rounded down to an even number of µm"),
+            Self::Delta(delta) => write!(f, "Δ = {} µm added", *delta as f64 / 1_000.0),
+            Self::NAboveIt8 => write!(f, "Upper deviation is zero for N above IT8"),
+            Self::KOutsideIt4ToIt7 => write!(f, "Lower deviation is zero for k outside IT4 to IT7"),
+            Self::M6 => write!(f, "M6 from 250 to 315 mm is -9 µm instead of -11 µm"),
+        }
+    }
+}
+
+/// Classes ISO 286 leaves undefined even where the tables have a value.
+pub fn check(
+    deviation: FundamentalDeviation,
+    grade: ToleranceGrade,
+    size: i32,
+) -> Result<(), IsoError> {
+    use FundamentalDeviation::*;
+    use ToleranceGrade::*;
+
+    match deviation {
+        // Tables 2 and 4: A and B are not used up to 1 mm
+        Hole(Letter::A | Letter::B) | Shaft(Letter::A | Letter::B) if size <= 1 => {
+            Err(IsoError::NotDefinedUpToOne(deviation))
+        }
+        // Tables 2 and 4: J is only given for IT6 to IT8 and j for IT5 to IT8
+        Hole(Letter::J) if !(IT6..=IT8).contains(&grade) => {
+            Err(IsoError::JGradeNotDefined(deviation, grade))
+        }
+        Shaft(Letter::J) if !(IT5..=IT8).contains(&grade) => {
+            Err(IsoError::JGradeNotDefined(deviation, grade))
+        }
+        // Table 2: K above IT8 is only given up to 3 mm
+        Hole(Letter::K) if grade > IT8 && size > 3 => Err(IsoError::KAboveIt8(grade)),
+        // Table 3: N above IT8 is not used up to 1 mm and isn't given above 500 mm
+        Hole(Letter::N) if grade > IT8 && (size <= 1 || size > 500) => {
+            Err(IsoError::NAboveIt8(grade))
+        }
+        _ => Ok(()),
+    }
+}
+
+/// Δ = ITn - ITn-1 from Table 3, defined for IT3 to IT8 from 3 mm to 500 mm and zero elsewhere.
+pub fn delta(size: i32, grade: ToleranceGrade) -> i32 {
+    if size > 500 || !(ToleranceGrade::IT3..=ToleranceGrade::IT8).contains(&grade) {
+        return 0;
+    }
+
+    let idx = DELTA.iter().position(|&s| s[0] >= size).unwrap();
+    100 * DELTA[idx][grade.index() - ToleranceGrade::IT3.index() + 1]
+}
+
+/// Half of the tolerance `tol` for JS and js, ±ITn/2.
+pub fn js_half(grade: ToleranceGrade, tol: i32, rules: &mut Vec<Rule>) -> i32 {
+    // Table 2 footnote: to keep JS7 to JS11 in whole micrometres an odd ITn
+    // is rounded down to the next even value before halving
+    let odd = tol % 1_000 == 0 && (tol / 1_000) % 2 == 1;
+    if odd && (ToleranceGrade::IT7..=ToleranceGrade::IT11).contains(&grade) {
+        rules.push(Rule::JsEven);
+        (tol - 1_000) / 2
+    } else {
+        tol / 2
+    }
+}
+
+/// Upper deviation ES of a K to ZC hole from the lower deviation `ei` of the same shaft letter.
+pub fn hole_upper(
+    letter: Letter,
+    grade: ToleranceGrade,
+    size: i32,
+    ei: i32,
+    rules: &mut Vec<Rule>,
+) -> i32 {
+    use Letter::*;
+    use ToleranceGrade::*;
+
+    // Tables 2 and 3 general rule: the hole mirrors the shaft
+    let mut es = -ei;
+
+    // Δ and the special cases stop at 500 mm, K, M and N above are tabulated as they are
+    if size > 500 {
+        return es;
+    }
+
+    // Table 3 special rule: Δ applies to K, M and N up to IT8 and P to ZC up to IT7
+    let special = match letter {
+        K | M | N => grade <= IT8,
+        _ => grade <= IT7,
+    };
+
+    if letter == N && !special && size > 3 {
+        rules.push(Rule::NAboveIt8);
+        return 0;
+    }
+
+    let delta = if special { delta(size, grade) } else { 0 };
+    if delta != 0 {
+        rules.push(Rule::Delta(delta));
+        es += delta;
+    }
+
+    // Table 2 footnote: M6 from 250 mm to 315 mm is -9 µm rather than -20 + 9 µm
+    if letter == M && grade == IT6 && (251..=315).contains(&size) {
+        rules.push(Rule::M6);
+        es += 2_000;
+    }
+
+    es
+}
+
+/// Lower deviation ei of a k shaft from the tabulated value `ei` for IT4 to IT7.
+pub fn shaft_k_lower(grade: ToleranceGrade, ei: i32, rules: &mut Vec<Rule>) -> i32 {
+    // Table 5: the tabulated k value only applies to IT4 to IT7, otherwise ei = 0
+    if (ToleranceGrade::IT4..=ToleranceGrade::IT7).contains(&grade) {
+        ei
+    } else {
+        if ei != 0 {
+            rules.push(Rule::KOutsideIt4ToIt7);
+        }
+        0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sections::tolerance::{Iso, Tolerance};
+
+    #[test]
+    fn test_delta() {
+        use ToleranceGrade::*;
+
+        assert_eq!(delta(165, IT5), 100 * DELTA[8][3]);
+        assert_eq!(delta(19, IT4), 100 * DELTA[4][2]);
+        assert_eq!(delta(333, IT8), 100 * DELTA[11][6]);
+        assert_eq!(delta(38, IT3), 100 * DELTA[5][1]);
+
+        // Outside IT3 to IT8 and above 500 mm there is no Δ
+        assert_eq!(delta(5, IT2), 0);
+        assert_eq!(delta(5, IT9), 0);
+        assert_eq!(delta(600, IT7), 0);
+    }
+
+    #[test]
+    fn test_rules() {
+        let convert = |iso: &str, size: f64| {
+            let derivation = iso.parse::<Iso>().unwrap().derive(size).unwrap();
+            (derivation.tolerance, derivation.rules)
+        };
+
+        let (tolerance, rules) = convert("js7", 24.0);
+        assert_eq!(tolerance, Tolerance::from_mm(0.010, -0.010));
+        assert_eq!(rules, [Rule::JsEven]);
+
+        let (tolerance, rules) = convert("JS6", 24.0);
+        assert_eq!(tolerance, Tolerance::from_mm(0.0065, -0.0065));
+        assert!(rules.is_empty());
+
+        let (tolerance, rules) = convert("M6", 300.0);
+        assert_eq!(tolerance, Tolerance::from_mm(-0.009, -0.041));
+        assert_eq!(rules, [Rule::Delta(9_000), Rule::M6]);
+
+        let (tolerance, rules) = convert("N9", 10.0);
+        assert_eq!(tolerance, Tolerance::from_mm(0.0, -0.036));
+        assert_eq!(rules, [Rule::NAboveIt8]);
+
+        let (tolerance, _) = convert("N9", 2.0);
+        assert_eq!(tolerance, Tolerance::from_mm(-0.004, -0.029));
+
+        let (tolerance, rules) = convert("k8", 10.0);
+        assert_eq!(tolerance, Tolerance::from_mm(0.022, 0.0));
+        assert_eq!(rules, [Rule::KOutsideIt4ToIt7]);
+
+        // IT2 has no Δ, it used to pick up the size column of the Δ table
+        let (tolerance, rules) = convert("K2", 5.0);
+        assert_eq!(tolerance, Tolerance::from_mm(-0.001, -0.0025));
+        assert!(rules.is_empty());
+
+        // K, M and N above 500 mm are the tabulated values
+        let (tolerance, rules) = convert("M7", 600.0);
+        assert_eq!(tolerance, Tolerance::from_mm(-0.026, -0.096));
+        assert!(rules.is_empty());
+    }
+}
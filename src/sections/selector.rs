@@ -0,0 +1,232 @@
+use egui::{ComboBox, DragValue, Grid, RichText, Ui};
+
+use super::{
+    length::Length,
+    preferred::PreferredFit,
+    tolerance::{FundamentalDeviation, Iso, Letter, Tolerance, ToleranceGrade},
+    utils::decimals,
+};
+
+// Grades outside this range are gauge or rough work and not sensible for fits
+const FIT_GRADES: std::ops::RangeInclusive<ToleranceGrade> =
+    ToleranceGrade::IT4..=ToleranceGrade::IT12;
+
+const RESULTS: usize = 10;
+
+#[derive(Clone, Copy, PartialEq, serde::Deserialize, serde::Serialize)]
+pub enum Basis {
+    Hole,
+    Shaft,
+    Any,
+}
+
+impl Basis {
+    fn label(&self) -> &'static str {
+        match self {
+            Basis::Hole => "Hole basis",
+            Basis::Shaft => "Shaft basis",
+            Basis::Any => "Any",
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct Candidate {
+    pub hole: Iso,
+    pub shaft: Iso,
+    pub mmc: Length,
+    pub lmc: Length,
+    pub preferred: bool,
+}
+
+#[derive(Clone, serde::Deserialize, serde::Serialize)]
+pub struct Selector {
+    pub size: f64,
+    pub min: f64,
+    pub max: f64,
+    pub basis: Basis,
+    #[serde(skip)]
+    pub results: Vec<Candidate>,
+}
+
+impl Default for Selector {
+    fn default() -> Self {
+        Self {
+            size: 10.0,
+            min: 0.005,
+            max: 0.030,
+            basis: Basis::Hole,
+            results: Vec::new(),
+        }
+    }
+}
+
+impl Selector {
+    /// Every hole and shaft pair whose clearance stays within `min` and `max` (mm) at `size`.
+    /// Preferred fits come first, then coarser (cheaper) grades.
+    pub fn search(size: f64, min: f64, max: f64, basis: Basis) -> Vec<Candidate> {
+        let classes = |hole: bool, basis_letter: bool| {
+            FundamentalDeviation::all(hole)
+                .filter(|d| !basis_letter || d.letter() == Letter::H)
+                .flat_map(|deviation| {
+                    ToleranceGrade::ALL
+                        .into_iter()
+                        .filter(|grade| FIT_GRADES.contains(grade))
+                        .map(move |grade| Iso::new(deviation, grade))
+                })
+                .filter_map(|iso| {
+                    let tolerance = iso.convert(size).ok()?;
+                    Some((iso, tolerance))
+                })
+                .collect::<Vec<(Iso, Tolerance)>>()
+        };
+
+        let (min, max) = (Length::from_mm(min), Length::from_mm(max));
+        let holes = classes(true, basis == Basis::Hole);
+        let shafts = classes(false, basis == Basis::Shaft);
+
+        let mut candidates = holes
+            .iter()
+            .flat_map(|(hole, hole_tol)| {
+                shafts.iter().filter_map(move |(shaft, shaft_tol)| {
+                    let mmc = hole_tol.lower - shaft_tol.upper;
+                    let lmc = hole_tol.upper - shaft_tol.lower;
+
+                    (mmc >= min && lmc <= max).then(|| Candidate {
+                        hole: hole.clone(),
+                        shaft: shaft.clone(),
+                        mmc,
+                        lmc,
+                        preferred: PreferredFit::find(hole, shaft).is_some(),
+                    })
+                })
+            })
+            .collect::<Vec<_>>();
+
+        candidates.sort_by_key(|c| {
+            (
+                !c.preferred,
+                std::cmp::Reverse(c.hole.grade.index() + c.shaft.grade.index()),
+                c.hole.grade.index().abs_diff(c.shaft.grade.index()),
+            )
+        });
+        candidates
+    }
+
+    /// Returns the hole and shaft classes when the user picks a result.
+    pub fn show(&mut self, ui: &mut Ui) -> Option<(Iso, Iso)> {
+        let mut selected = None;
+
+        ui.label(RichText::new("Fit Selector").strong().size(15.0));
+        ui.add_space(5.0);
+
+        egui::Frame::group(ui.style())
+            .inner_margin(10.0)
+            .rounding(10.0)
+            .show(ui, |ui| {
+                ui.horizontal(|ui| {
+                    ui.add_sized(
+                        [45.0, 18.0],
+                        DragValue::new(&mut self.size)
+                            .speed(0.1)
+                            .range(0.0..=3_150.0),
+                    )
+                    .on_hover_text("Size");
+
+                    ui.add_sized(
+                        [55.0, 18.0],
+                        DragValue::new(&mut self.min)
+                            .speed(0.001)
+                            .range(f64::MIN..=self.max)
+                            .min_decimals(3),
+                    )
+                    .on_hover_text("Minimum clearance, negative for interference");
+
+                    ui.add_sized(
+                        [55.0, 18.0],
+                        DragValue::new(&mut self.max)
+                            .speed(0.001)
+                            .range(self.min..=f64::MAX)
+                            .min_decimals(3),
+                    )
+                    .on_hover_text("Maximum clearance, negative for interference");
+
+                    ComboBox::from_id_salt("selector_basis")
+                        .width(80.0)
+                        .selected_text(self.basis.label())
+                        .show_ui(ui, |ui| {
+                            for basis in [Basis::Hole, Basis::Shaft, Basis::Any] {
+                                ui.selectable_value(&mut self.basis, basis, basis.label());
+                            }
+                        });
+
+                    if ui.button("Search").clicked() {
+                        self.results = Self::search(self.size, self.min, self.max, self.basis);
+                    }
+                });
+
+                if self.results.is_empty() {
+                    return;
+                }
+
+                ui.add_space(5.0);
+
+                Grid::new("selector_results")
+                    .striped(true)
+                    .min_col_width(10.0)
+                    .show(ui, |ui| {
+                        for candidate in self.results.iter().take(RESULTS) {
+                            ui.label(format!("{} / {}", candidate.hole, candidate.shaft));
+                            if candidate.preferred {
+                                ui.label("★").on_hover_text("ISO preferred fit");
+                            } else {
+                                ui.label("");
+                            }
+                            ui.label(format!(
+                                "{:.} to {:.} µm",
+                                decimals(candidate.mmc.um(), 1),
+                                decimals(candidate.lmc.um(), 1)
+                            ));
+                            if ui.button("Use").clicked() {
+                                selected = Some((candidate.hole.clone(), candidate.shaft.clone()));
+                            }
+                            ui.end_row();
+                        }
+                    });
+
+                if self.results.len() > RESULTS {
+                    ui.label(format!("{} more fits", self.results.len() - RESULTS));
+                }
+            });
+
+        selected
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_search() {
+        let results = Selector::search(25.0, 0.005, 0.045, Basis::Hole);
+        assert!(!results.is_empty());
+        assert!(results[0].preferred);
+        assert_eq!(results[0].hole.to_string(), "H7");
+        assert_eq!(results[0].shaft.to_string(), "g6");
+
+        for candidate in &results {
+            assert_eq!(
+                candidate.hole.deviation,
+                FundamentalDeviation::Hole(Letter::H)
+            );
+            assert!(candidate.mmc >= Length::from_mm(0.005));
+            assert!(candidate.lmc <= Length::from_mm(0.045));
+        }
+
+        let results = Selector::search(25.0, -0.050, -0.010, Basis::Shaft);
+        assert!(results
+            .iter()
+            .all(|c| c.shaft.deviation == FundamentalDeviation::Shaft(Letter::H)));
+    }
+}
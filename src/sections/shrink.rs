@@ -0,0 +1,453 @@
+use std::fmt;
+
+use egui::{Color32, ComboBox, DragValue, Grid, RichText, Ui};
+
+use super::{feature::Feature, length::Length, units::Units, utils::State};
+
+// Temperatures that open an interference fit up for assembly or removal. The
+// worst case is taken, the smallest hub bore over the largest shaft
+
+const HOTTEST: f64 = 1_000.0;
+const COLDEST: f64 = -273.15;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Medium {
+    pub name: &'static str,
+    /// Hottest or coldest the medium brings a part to, in ºC.
+    pub temp: f64,
+}
+
+pub const HEATING: [Medium; 3] = [
+    Medium {
+        name: "Oil bath",
+        temp: 200.0,
+    },
+    Medium {
+        name: "Oven",
+        temp: 250.0,
+    },
+    Medium {
+        name: "Induction",
+        temp: 400.0,
+    },
+];
+
+pub const COOLING: [Medium; 3] = [
+    Medium {
+        name: "Freezer",
+        temp: -30.0,
+    },
+    Medium {
+        name: "Dry ice",
+        temp: -78.5,
+    },
+    Medium {
+        name: "LN2",
+        temp: -196.0,
+    },
+];
+
+#[derive(Clone, Copy, Debug, PartialEq, serde::Deserialize, serde::Serialize)]
+pub enum Method {
+    Heat,
+    Cool,
+    /// Shaft cooled in the chosen medium, hub heated the rest of the way.
+    Both,
+}
+
+impl Method {
+    pub const ALL: [Self; 3] = [Self::Heat, Self::Cool, Self::Both];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Heat => "Heat hub",
+            Self::Cool => "Cool shaft",
+            Self::Both => "Both",
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ShrinkError {
+    TooHot,
+    TooCold,
+}
+
+impl fmt::Display for ShrinkError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::TooHot => write!(f, "The hub does not open up below {HOTTEST} ºC"),
+            Self::TooCold => write!(f, "The shaft does not shrink enough above absolute zero"),
+        }
+    }
+}
+
+impl std::error::Error for ShrinkError {}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Temperatures {
+    pub hub: f64,
+    pub shaft: f64,
+}
+
+/// Temperature between `from` and `to` where the increasing `gap` reaches
+/// `target`, `from` itself if it is already there.
+fn bisect(gap: impl Fn(f64) -> f64, from: f64, to: f64, target: f64) -> Option<f64> {
+    if gap(from) >= target {
+        return Some(from);
+    }
+    if gap(to) < target {
+        return None;
+    }
+
+    let (mut short, mut enough) = (from, to);
+    for _ in 0..60 {
+        let mid = (short + enough) / 2.0;
+        if gap(mid) >= target {
+            enough = mid;
+        } else {
+            short = mid;
+        }
+    }
+    Some(enough)
+}
+
+#[derive(Clone, serde::Deserialize, serde::Serialize)]
+pub struct ShrinkFit {
+    pub disassembly: bool,
+    pub method: Method,
+    /// Clearance wanted between the parts while they are moved.
+    pub clearance: Length,
+    pub ambient: f64,
+    /// Index into `HEATING`.
+    pub heating: usize,
+    /// Index into `COOLING`.
+    pub cooling: usize,
+    /// Fraction of the hub's temperature rise the shaft picks up while the hub is heated off it.
+    pub share: f64,
+}
+
+impl Default for ShrinkFit {
+    fn default() -> Self {
+        Self {
+            disassembly: false,
+            method: Method::Heat,
+            clearance: Length::from_mm(0.01),
+            ambient: 20.0,
+            heating: 0,
+            cooling: 2,
+            share: 0.3,
+        }
+    }
+}
+
+impl ShrinkFit {
+    fn heating(&self) -> Medium {
+        HEATING[self.heating.min(HEATING.len() - 1)]
+    }
+
+    fn cooling(&self) -> Medium {
+        COOLING[self.cooling.min(COOLING.len() - 1)]
+    }
+
+    /// Hub and shaft temperatures that give the clearance.
+    pub fn solve(&self, hole: &Feature, shaft: &Feature) -> Result<Temperatures, ShrinkError> {
+        let bore = hole.lower_limit().mm();
+        let pin = shaft.upper_limit().mm();
+        let target = self.clearance.mm();
+        let ambient = self.ambient;
+
+        let gap = |hub: f64, cold: f64| bore * hole.mat.scale(hub) - pin * shaft.mat.scale(cold);
+
+        // Hub heated with the shaft at `cold`, or following the hub when taking it apart
+        let heat = |cold: Option<f64>| {
+            let shaft_temp = |hub: f64| cold.unwrap_or(ambient + self.share * (hub - ambient));
+            let hub = bisect(|t| gap(t, shaft_temp(t)), ambient, HOTTEST, target)
+                .ok_or(ShrinkError::TooHot)?;
+            Ok(Temperatures {
+                hub,
+                shaft: shaft_temp(hub),
+            })
+        };
+
+        if self.disassembly {
+            return heat(None);
+        }
+
+        match self.method {
+            Method::Heat => heat(Some(ambient)),
+            Method::Cool => {
+                let shaft = bisect(|t| gap(ambient, t), ambient, COLDEST, target)
+                    .ok_or(ShrinkError::TooCold)?;
+                Ok(Temperatures {
+                    hub: ambient,
+                    shaft,
+                })
+            }
+            Method::Both => heat(Some(self.cooling().temp.max(COLDEST))),
+        }
+    }
+
+    fn input_ui(&mut self, ui: &mut Ui, hole: &mut Feature, units: &Units) {
+        ui.horizontal(|ui| {
+            ui.selectable_value(&mut self.disassembly, false, "Assembly");
+            ui.selectable_value(&mut self.disassembly, true, "Disassembly")
+                .on_hover_text("Heat the hub off the shaft");
+
+            if !self.disassembly {
+                ui.separator();
+                for method in Method::ALL {
+                    ui.selectable_value(&mut self.method, method, method.label());
+                }
+            }
+        });
+
+        ui.horizontal(|ui| {
+            ui.add_sized(
+                [55.0, 18.0],
+                self.clearance
+                    .drag()
+                    .custom_formatter(|c, _| units.number(c))
+                    .custom_parser(|c| units.parse_length(c))
+                    .speed(0.001)
+                    .range(0.0..=100.0),
+            )
+            .on_hover_text(format!(
+                "Clearance wanted at the worst case limits ({})",
+                units.length_unit()
+            ));
+            if ui
+                .button("0.1%")
+                .on_hover_text("A common assembly clearance, 0.001 × d")
+                .clicked()
+            {
+                self.clearance = Length::from_mm(hole.size.mm() * 0.001);
+            }
+
+            ui.add_sized(
+                [45.0, 18.0],
+                DragValue::new(&mut self.ambient)
+                    .custom_formatter(|t, _| units.temp(t))
+                    .custom_parser(|t| units.parse_temp(t))
+                    .speed(1.0)
+                    .range(COLDEST..=HOTTEST)
+                    .min_decimals(1),
+            )
+            .on_hover_text("Workshop temperature");
+
+            ui.add_sized(
+                [45.0, 18.0],
+                DragValue::new(&mut hole.mat.max_temp)
+                    .custom_formatter(|t, _| units.temp(t))
+                    .custom_parser(|t| units.parse_temp(t))
+                    .speed(1.0)
+                    .range(COLDEST..=HOTTEST)
+                    .min_decimals(1),
+            )
+            .on_hover_text("Hottest the hub material may be taken");
+        });
+
+        ui.horizontal(|ui| {
+            let heated = self.disassembly || self.method != Method::Cool;
+            let cooled = !self.disassembly && self.method != Method::Heat;
+
+            if heated {
+                medium_ui(ui, "heating", &mut self.heating, &HEATING, units);
+            }
+            if cooled {
+                medium_ui(ui, "cooling", &mut self.cooling, &COOLING, units);
+            }
+            if self.disassembly {
+                ui.add_sized(
+                    [45.0, 18.0],
+                    DragValue::new(&mut self.share)
+                        .custom_formatter(|s, _| format!("{:.0}%", s * 100.0))
+                        .custom_parser(|s| s.trim_end_matches('%').trim().parse::<f64>().ok().map(|s| s / 100.0))
+                        .speed(0.01)
+                        .range(0.0..=0.95),
+                )
+                .on_hover_text("Share of the hub's temperature rise reaching the shaft, heat quickly to keep it low");
+            }
+        });
+    }
+
+    /// Panel with the required hub and shaft temperatures and their checks.
+    pub fn show(
+        &mut self,
+        ui: &mut Ui,
+        hole: &mut Feature,
+        shaft: &mut Feature,
+        state: &mut State,
+    ) {
+        let units = state.units;
+
+        ui.label(RichText::new("Shrink Fit Temperatures").strong().size(15.0));
+        ui.add_space(5.0);
+
+        egui::Frame::group(ui.style())
+            .inner_margin(10.0)
+            .rounding(10.0)
+            .show(ui, |ui| {
+                self.input_ui(ui, hole, &units);
+                ui.add_space(5.0);
+
+                let temps = match self.solve(hole, shaft) {
+                    Ok(temps) => temps,
+                    Err(error) => {
+                        ui.colored_label(Color32::RED, error.to_string());
+                        return;
+                    }
+                };
+
+                let green = Color32::from_rgb(0, 160, 0);
+                let check = |ui: &mut Ui, ok: bool, pass: String, fail: String| {
+                    if ok {
+                        ui.colored_label(green, pass);
+                    } else {
+                        ui.colored_label(Color32::RED, fail);
+                    }
+                };
+
+                Grid::new("shrink_results")
+                    .striped(false)
+                    .min_col_width(10.0)
+                    .show(ui, |ui| {
+                        ui.label("Hub");
+                        ui.label(units.temp(temps.hub));
+                        if temps.hub > self.ambient {
+                            let medium = self.heating();
+                            check(
+                                ui,
+                                temps.hub <= medium.temp,
+                                format!("{} reaches it", medium.name),
+                                format!("Beyond {} ({})", medium.name, units.temp(medium.temp)),
+                            );
+                        }
+                        ui.end_row();
+
+                        ui.label("");
+                        ui.label("");
+                        check(
+                            ui,
+                            temps.hub <= hole.mat.max_temp,
+                            "Within the material limit".to_owned(),
+                            format!(
+                                "Over the material limit ({})",
+                                units.temp(hole.mat.max_temp)
+                            ),
+                        );
+                        ui.end_row();
+
+                        ui.label("Shaft");
+                        ui.label(units.temp(temps.shaft));
+                        if temps.shaft < self.ambient {
+                            let medium = self.cooling();
+                            check(
+                                ui,
+                                temps.shaft >= medium.temp,
+                                format!("{} reaches it", medium.name),
+                                format!("Beyond {} ({})", medium.name, units.temp(medium.temp)),
+                            );
+                        }
+                        ui.end_row();
+                    });
+
+                let warnings = [
+                    hole.mat.range_warning(temps.hub, &units),
+                    shaft.mat.range_warning(temps.shaft, &units),
+                ];
+                for warning in warnings.into_iter().flatten() {
+                    ui.colored_label(Color32::YELLOW, warning);
+                }
+
+                ui.add_space(5.0);
+
+                if ui
+                    .button("Apply")
+                    .on_hover_text("Set the part temperatures and show the fit at temperature")
+                    .clicked()
+                {
+                    hole.mat.temp = temps.hub;
+                    shaft.mat.temp = temps.shaft;
+                    state.sync_temp = false;
+                    state.thermal = true;
+                }
+            });
+    }
+}
+
+fn medium_ui(ui: &mut Ui, id: &str, selected: &mut usize, media: &[Medium], units: &Units) {
+    let current = media[(*selected).min(media.len() - 1)];
+
+    ComboBox::from_id_salt(format!("shrink_{id}"))
+        .width(110.0)
+        .selected_text(current.name)
+        .show_ui(ui, |ui| {
+            for (i, medium) in media.iter().enumerate() {
+                ui.selectable_value(
+                    selected,
+                    i,
+                    format!("{} ({})", medium.name, units.temp(medium.temp)),
+                );
+            }
+        })
+        .response
+        .on_hover_text(format!("Means of {id}"));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_shrink() {
+        // H7/s6 at 100 mm has up to 0.093 mm of interference, steel at 12 µm/m·K
+        let mut hole = Feature::from_iso(true, 100.0, "H7".parse().unwrap()).unwrap();
+        let mut shaft = Feature::from_iso(false, 100.0, "s6".parse().unwrap()).unwrap();
+        // A constant CTE keeps the expected temperatures in closed form
+        hole.mat.curve = Default::default();
+        shaft.mat.curve = Default::default();
+        let alpha = 12e-6;
+
+        let mut shrink = ShrinkFit {
+            clearance: Length::from_mm(0.1),
+            ..ShrinkFit::default()
+        };
+        let close = |a: f64, b: f64| (a - b).abs() < 1e-6;
+
+        let temps = shrink.solve(&hole, &shaft).unwrap();
+        assert!(close(temps.hub, 20.0 + 0.193 / (100.0 * alpha)));
+        assert_eq!(temps.shaft, 20.0);
+
+        shrink.method = Method::Cool;
+        let temps = shrink.solve(&hole, &shaft).unwrap();
+        assert!(close(temps.shaft, 20.0 - 0.193 / (100.093 * alpha)));
+
+        // Dry ice takes care of part of it
+        shrink.method = Method::Both;
+        shrink.cooling = 1;
+        let temps = shrink.solve(&hole, &shaft).unwrap();
+        let shrunk = 100.093 * alpha * 98.5;
+        assert!(close(temps.hub, 20.0 + (0.193 - shrunk) / (100.0 * alpha)));
+
+        // Taking it apart, the shaft follows the hub part of the way
+        shrink.disassembly = true;
+        shrink.clearance = Length::ZERO;
+        shrink.share = 0.5;
+        let temps = shrink.solve(&hole, &shaft).unwrap();
+        let rise = 0.093 / ((100.0 - 0.5 * 100.093) * alpha);
+        assert!(close(temps.hub, 20.0 + rise));
+        assert!(close(temps.shaft, 20.0 + rise / 2.0));
+
+        // A hub that does not expand more than the shaft never frees it
+        let mut invar = hole.clone();
+        invar.mat.cte = 1.0;
+        assert_eq!(shrink.solve(&invar, &shaft), Err(ShrinkError::TooHot));
+
+        // A clearance fit needs nothing
+        let clear = Feature::from_iso(false, 100.0, "g6".parse().unwrap()).unwrap();
+        shrink.disassembly = false;
+        let temps = shrink.solve(&hole, &clear).unwrap();
+        assert_eq!(temps.hub, 20.0);
+    }
+}
@@ -0,0 +1,525 @@
+use egui::{Color32, ComboBox, Grid, RichText, TextEdit, Ui};
+
+use super::{
+    general::{self, GeneralClass},
+    length::Length,
+    tolerance::{FundamentalDeviation, Iso, Letter, Tolerance, ToleranceGrade},
+    units::Units,
+};
+
+// One dimensional tolerance stack-ups, the gap is the sum of every link
+// with links that close the gap subtracted. Worst case uses exact lengths,
+// RSS is in mm as a float like the statistics module
+
+#[derive(Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+pub enum Limits {
+    Manual,
+    Iso(Iso),
+    General(GeneralClass),
+}
+
+impl Limits {
+    fn label(&self) -> &'static str {
+        match self {
+            Self::Manual => "±",
+            Self::Iso(_) => "ISO",
+            Self::General(_) => "2768",
+        }
+    }
+}
+
+/// A dimension in the stack.
+#[derive(Clone, serde::Deserialize, serde::Serialize)]
+pub struct Link {
+    pub name: String,
+    /// Adds to the gap if true, takes from it if false.
+    pub positive: bool,
+    pub size: Length,
+    pub limits: Limits,
+    /// Used for manual limits, otherwise worked out from the size.
+    pub tolerance: Tolerance,
+}
+
+impl Link {
+    pub fn manual(name: &str, positive: bool, size: f64, upper: f64, lower: f64) -> Self {
+        Self {
+            name: name.to_owned(),
+            positive,
+            size: Length::from_mm(size),
+            limits: Limits::Manual,
+            tolerance: Tolerance::from_mm(upper, lower),
+        }
+    }
+
+    pub fn resolve(&self) -> Result<Tolerance, String> {
+        match &self.limits {
+            Limits::Manual => Ok(self.tolerance.clone()),
+            Limits::Iso(iso) => iso.convert(self.size.mm()).map_err(|e| e.to_string()),
+            Limits::General(class) => {
+                general::tolerance(*class, self.size.mm()).map_err(|e| e.to_string())
+            }
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct StackResult {
+    pub nominal: Length,
+    /// Smallest and largest gap with every link at its limits.
+    pub worst: (Length, Length),
+    /// Gap in mm with the links' half tolerances root sum squared about the mid-limits.
+    pub rss: (f64, f64),
+    /// Share of each link in the worst case and RSS tolerance, as percentages.
+    pub contributions: Vec<(f64, f64)>,
+}
+
+impl StackResult {
+    pub fn worst_pass(&self, min: Length, max: Length) -> bool {
+        self.worst.0 >= min && self.worst.1 <= max
+    }
+
+    pub fn rss_pass(&self, min: Length, max: Length) -> bool {
+        self.rss.0 >= min.mm() && self.rss.1 <= max.mm()
+    }
+}
+
+#[derive(Clone, serde::Deserialize, serde::Serialize)]
+pub struct Stack {
+    pub name: String,
+    pub links: Vec<Link>,
+    /// Required gap.
+    pub min: Length,
+    pub max: Length,
+}
+
+impl Default for Stack {
+    /// Bearing and circlip on a shaft, the gap is the end float.
+    fn default() -> Self {
+        Self {
+            name: "Circlip end float".to_owned(),
+            links: vec![
+                Link {
+                    limits: Limits::Iso(Iso::new(
+                        FundamentalDeviation::Hole(Letter::H),
+                        ToleranceGrade::IT11,
+                    )),
+                    ..Link::manual("Shoulder to groove", true, 16.6, 0.0, 0.0)
+                },
+                Link::manual("Bearing width", false, 15.0, 0.0, -0.12),
+                Link {
+                    limits: Limits::Iso(Iso::new(
+                        FundamentalDeviation::Shaft(Letter::H),
+                        ToleranceGrade::IT11,
+                    )),
+                    ..Link::manual("Circlip", false, 1.5, 0.0, 0.0)
+                },
+            ],
+            min: Length::ZERO,
+            max: Length::from_mm(0.4),
+        }
+    }
+}
+
+impl Stack {
+    /// Gap of the stack, or the first link that can't be toleranced and why.
+    pub fn solve(&self) -> Result<StackResult, (usize, String)> {
+        let tolerances = self
+            .links
+            .iter()
+            .enumerate()
+            .map(|(idx, link)| link.resolve().map_err(|error| (idx, error)))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let mut nominal = Length::ZERO;
+        let (mut min, mut max) = (Length::ZERO, Length::ZERO);
+        let mut mid = 0.0;
+
+        for (link, tolerance) in self.links.iter().zip(&tolerances) {
+            if link.positive {
+                nominal += link.size;
+                min += link.size + tolerance.lower;
+                max += link.size + tolerance.upper;
+                mid += (link.size + (tolerance.upper + tolerance.lower) / 2).mm();
+            } else {
+                nominal -= link.size;
+                min -= link.size + tolerance.upper;
+                max -= link.size + tolerance.lower;
+                mid -= (link.size + (tolerance.upper + tolerance.lower) / 2).mm();
+            }
+        }
+
+        let widths = tolerances
+            .iter()
+            .map(|t| (t.upper - t.lower).mm())
+            .collect::<Vec<_>>();
+        let sum = widths.iter().sum::<f64>();
+        let sum_squares = widths.iter().map(|w| w * w).sum::<f64>();
+        let half = sum_squares.sqrt() / 2.0;
+
+        let share = |part: f64, total: f64| {
+            if total > 0.0 {
+                100.0 * part / total
+            } else {
+                0.0
+            }
+        };
+
+        Ok(StackResult {
+            nominal,
+            worst: (min, max),
+            rss: (mid - half, mid + half),
+            contributions: widths
+                .iter()
+                .map(|w| (share(*w, sum), share(w * w, sum_squares)))
+                .collect(),
+        })
+    }
+}
+
+/// Every saved stack and the one being edited.
+#[derive(Clone, serde::Deserialize, serde::Serialize)]
+pub struct Stacks {
+    pub stacks: Vec<Stack>,
+    pub selected: usize,
+}
+
+impl Default for Stacks {
+    fn default() -> Self {
+        Self {
+            stacks: vec![Stack::default()],
+            selected: 0,
+        }
+    }
+}
+
+fn limits_ui(ui: &mut Ui, id: &str, link: &mut Link, units: &Units) {
+    ComboBox::from_id_salt(format!("{id}_limits"))
+        .width(45.0)
+        .selected_text(link.limits.label())
+        .show_ui(ui, |ui| {
+            let iso = Limits::Iso(Iso::new(
+                FundamentalDeviation::Hole(Letter::H),
+                ToleranceGrade::IT11,
+            ));
+            for option in [Limits::Manual, iso, Limits::General(GeneralClass::M)] {
+                let selected =
+                    std::mem::discriminant(&link.limits) == std::mem::discriminant(&option);
+                if ui.selectable_label(selected, option.label()).clicked() && !selected {
+                    link.limits = option;
+                }
+            }
+        })
+        .response
+        .on_hover_text("Manual limits, an ISO 286 class or an ISO 2768-1 class");
+
+    match &mut link.limits {
+        Limits::Manual => {
+            let (upper, lower) = (link.tolerance.upper.mm(), link.tolerance.lower.mm());
+            ui.add_sized(
+                [45.0, 18.0],
+                link.tolerance
+                    .lower
+                    .drag()
+                    .custom_formatter(|l, _| units.number(l))
+                    .custom_parser(|l| units.parse_length(l))
+                    .speed(0.001)
+                    .range(f64::MIN..=upper),
+            )
+            .on_hover_text(format!("Lower deviation ({})", units.length_unit()));
+            ui.add_sized(
+                [45.0, 18.0],
+                link.tolerance
+                    .upper
+                    .drag()
+                    .custom_formatter(|u, _| units.number(u))
+                    .custom_parser(|u| units.parse_length(u))
+                    .speed(0.001)
+                    .range(lower..=f64::MAX),
+            )
+            .on_hover_text(format!("Upper deviation ({})", units.length_unit()));
+        }
+        Limits::Iso(iso) => {
+            ComboBox::from_id_salt(format!("{id}_deviation"))
+                .width(45.0)
+                .selected_text(iso.deviation.to_string())
+                .show_ui(ui, |ui| {
+                    for deviation in
+                        FundamentalDeviation::all(true).chain(FundamentalDeviation::all(false))
+                    {
+                        ui.selectable_value(&mut iso.deviation, deviation, deviation.to_string());
+                    }
+                })
+                .response
+                .on_hover_text("Deviation");
+            ComboBox::from_id_salt(format!("{id}_grade"))
+                .width(45.0)
+                .selected_text(iso.grade.to_string())
+                .show_ui(ui, |ui| {
+                    for grade in ToleranceGrade::ALL {
+                        ui.selectable_value(&mut iso.grade, grade, grade.to_string());
+                    }
+                })
+                .response
+                .on_hover_text("Grade");
+        }
+        Limits::General(class) => {
+            ComboBox::from_id_salt(format!("{id}_general"))
+                .width(45.0)
+                .selected_text(class.to_string())
+                .show_ui(ui, |ui| {
+                    for option in GeneralClass::ALL {
+                        ui.selectable_value(class, option, option.to_string())
+                            .on_hover_text(option.name());
+                    }
+                })
+                .response
+                .on_hover_text("ISO 2768-1 class");
+        }
+    }
+}
+
+impl Stacks {
+    pub fn show(&mut self, ui: &mut Ui, units: &Units) {
+        ui.horizontal(|ui| {
+            self.selected = self.selected.min(self.stacks.len().saturating_sub(1));
+            let names = self
+                .stacks
+                .iter()
+                .map(|s| s.name.clone())
+                .collect::<Vec<_>>();
+
+            ComboBox::from_id_salt("stack_selected")
+                .width(150.0)
+                .selected_text(names.get(self.selected).cloned().unwrap_or_default())
+                .show_ui(ui, |ui| {
+                    for (idx, name) in names.iter().enumerate() {
+                        ui.selectable_value(&mut self.selected, idx, name);
+                    }
+                })
+                .response
+                .on_hover_text("Saved stacks");
+
+            if ui.button("New").clicked() {
+                self.stacks.push(Stack {
+                    name: format!("Stack {}", self.stacks.len() + 1),
+                    links: Vec::new(),
+                    min: Length::ZERO,
+                    max: Length::from_mm(1.0),
+                });
+                self.selected = self.stacks.len() - 1;
+            }
+
+            if ui
+                .add_enabled(!self.stacks.is_empty(), egui::Button::new("Delete"))
+                .clicked()
+            {
+                self.stacks.remove(self.selected);
+                self.selected = self.selected.saturating_sub(1);
+            }
+        });
+
+        let Some(stack) = self.stacks.get_mut(self.selected) else {
+            return;
+        };
+
+        ui.add_space(5.0);
+        ui.add(TextEdit::singleline(&mut stack.name).desired_width(200.0))
+            .on_hover_text("Name");
+        ui.add_space(5.0);
+
+        let result = stack.solve();
+        let contributions = result
+            .as_ref()
+            .map(|r| r.contributions.clone())
+            .unwrap_or_default();
+        let mut remove = None;
+
+        Grid::new("stack_links")
+            .striped(true)
+            .min_col_width(10.0)
+            .show(ui, |ui| {
+                for heading in ["Link", "", "Size", "Limits", "", "", "WC", "RSS", ""] {
+                    ui.label(RichText::new(heading).strong());
+                }
+                ui.end_row();
+
+                for (idx, link) in stack.links.iter_mut().enumerate() {
+                    let id = format!("stack_link_{idx}");
+
+                    ui.add(TextEdit::singleline(&mut link.name).desired_width(120.0));
+                    let direction = if link.positive { "+" } else { "−" };
+                    ui.toggle_value(&mut link.positive, direction)
+                        .on_hover_text("Opens the gap (+) or closes it (−)");
+                    ui.add_sized(
+                        [55.0, 18.0],
+                        link.size
+                            .drag()
+                            .custom_formatter(|s, _| units.number(s))
+                            .custom_parser(|s| units.parse_length(s))
+                            .speed(0.1)
+                            .range(0.0..=10_000.0),
+                    )
+                    .on_hover_text(format!("Size ({})", units.length_unit()));
+
+                    ui.horizontal(|ui| limits_ui(ui, &id, link, units));
+
+                    match link.resolve() {
+                        Ok(tolerance) => {
+                            let range = tolerance.upper.abs().max(tolerance.lower.abs()).mm();
+                            ui.label(units.deviation(tolerance.upper.mm(), range, true));
+                            ui.label(units.deviation(tolerance.lower.mm(), range, true));
+                        }
+                        Err(error) => {
+                            ui.colored_label(Color32::RED, "⚠").on_hover_text(error);
+                            ui.label("");
+                        }
+                    }
+
+                    match contributions.get(idx) {
+                        Some((worst, rss)) => {
+                            ui.label(format!("{worst:.1} %"))
+                                .on_hover_text("Share of the worst case tolerance");
+                            ui.label(format!("{rss:.1} %"))
+                                .on_hover_text("Share of the RSS variance");
+                        }
+                        None => {
+                            ui.label("");
+                            ui.label("");
+                        }
+                    }
+
+                    if ui.button("🗑").on_hover_text("Remove link").clicked() {
+                        remove = Some(idx);
+                    }
+                    ui.end_row();
+                }
+            });
+
+        if let Some(idx) = remove {
+            stack.links.remove(idx);
+        }
+
+        if ui.button("Add link").clicked() {
+            let name = format!("Link {}", stack.links.len() + 1);
+            stack.links.push(Link::manual(&name, true, 10.0, 0.1, -0.1));
+        }
+
+        ui.add_space(5.0);
+
+        ui.horizontal(|ui| {
+            ui.label("Required gap");
+            let max = stack.max.mm();
+            ui.add_sized(
+                [55.0, 18.0],
+                stack
+                    .min
+                    .drag()
+                    .custom_formatter(|m, _| units.number(m))
+                    .custom_parser(|m| units.parse_length(m))
+                    .speed(0.001)
+                    .range(f64::MIN..=max),
+            )
+            .on_hover_text(format!("Minimum ({})", units.length_unit()));
+            let min = stack.min.mm();
+            ui.add_sized(
+                [55.0, 18.0],
+                stack
+                    .max
+                    .drag()
+                    .custom_formatter(|m, _| units.number(m))
+                    .custom_parser(|m| units.parse_length(m))
+                    .speed(0.001)
+                    .range(min..=f64::MAX),
+            )
+            .on_hover_text(format!("Maximum ({})", units.length_unit()));
+        });
+
+        ui.add_space(5.0);
+
+        let result = match result {
+            Ok(result) => result,
+            Err((idx, error)) => {
+                ui.colored_label(Color32::RED, format!("{}: {error}", stack.links[idx].name));
+                return;
+            }
+        };
+
+        let verdict = |ui: &mut Ui, pass: bool| {
+            if pass {
+                ui.colored_label(Color32::from_rgb(0, 160, 0), "Pass");
+            } else {
+                ui.colored_label(Color32::RED, "Fail");
+            }
+        };
+
+        Grid::new("stack_result")
+            .striped(false)
+            .min_col_width(10.0)
+            .show(ui, |ui| {
+                ui.label("Nominal");
+                ui.label(units.length(result.nominal.mm()));
+                ui.end_row();
+
+                ui.label("Worst case");
+                ui.label(format!(
+                    "{} to {}",
+                    units.length(result.worst.0.mm()),
+                    units.length(result.worst.1.mm())
+                ));
+                verdict(ui, result.worst_pass(stack.min, stack.max));
+                ui.end_row();
+
+                ui.label("RSS");
+                ui.label(format!(
+                    "{} to {}",
+                    units.length(result.rss.0),
+                    units.length(result.rss.1)
+                ));
+                verdict(ui, result.rss_pass(stack.min, stack.max));
+                ui.end_row();
+            });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stack() {
+        // H11 at 16.6 mm is +0.11/0 and h11 at 1.5 mm is 0/-0.06
+        let stack = Stack::default();
+        let result = stack.solve().unwrap();
+        assert_eq!(result.nominal, Length::from_mm(0.1));
+        assert_eq!(result.worst, (Length::from_mm(0.1), Length::from_mm(0.39)));
+        assert!(result.worst_pass(stack.min, stack.max));
+
+        let half = (0.11f64.powi(2) + 0.12f64.powi(2) + 0.06f64.powi(2)).sqrt() / 2.0;
+        assert!((result.rss.0 - (0.245 - half)).abs() < 1e-9);
+        assert!((result.rss.1 - (0.245 + half)).abs() < 1e-9);
+
+        let (worst, rss) = result.contributions[1];
+        assert!((worst - 100.0 * 0.12 / 0.29).abs() < 1e-9);
+        assert!((rss - 100.0 * 0.0144 / 0.0301).abs() < 1e-9);
+        assert!(!result.worst_pass(Length::from_mm(0.15), stack.max));
+        assert!(result.rss_pass(Length::from_mm(0.15), stack.max));
+
+        // A 2768-m link and one with no ISO limits
+        let mut stack = stack;
+        stack.links.push(Link {
+            limits: Limits::General(GeneralClass::M),
+            ..Link::manual("Spacer", false, 2.0, 0.0, 0.0)
+        });
+        let result = stack.solve().unwrap();
+        assert_eq!(
+            result.worst,
+            (Length::from_mm(-2.0), Length::from_mm(-1.51))
+        );
+
+        stack.links.push(Link {
+            limits: Limits::General(GeneralClass::V),
+            ..Link::manual("Washer", false, 1.0, 0.0, 0.0)
+        });
+        assert_eq!(stack.solve().unwrap_err().0, 4);
+    }
+}
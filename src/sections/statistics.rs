@@ -0,0 +1,546 @@
+use egui::{Color32, ComboBox, DragValue, Grid, RichText, Ui};
+use egui_plot::{Bar, BarChart, Legend, Plot, VLine};
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+use super::{feature::Feature, length::Length, units::Units, utils::State};
+
+// Statistical fit analysis, the hole and shaft are drawn from distributions
+// within their limits rather than taken at the extremes. Clearance is in mm
+// as a float like the thermal maths, negative for interference
+
+/// Percentiles of the clearance that are reported, and the matching z of a normal.
+pub const PERCENTILES: [(f64, f64); 5] = [
+    (0.135, -3.0),
+    (2.5, -1.959_964),
+    (50.0, 0.0),
+    (97.5, 1.959_964),
+    (99.865, 3.0),
+];
+
+const BINS: usize = 40;
+
+// Fixed so that the results don't flicker from frame to frame
+const SEED: u64 = 286;
+
+#[derive(Clone, Copy, Debug, PartialEq, serde::Deserialize, serde::Serialize)]
+pub enum Distribution {
+    Normal,
+    Uniform,
+    /// Normal with its mean a third of the way in from the MMC limit,
+    /// as parts tend to be when machinists stop short of the limit.
+    Skewed,
+}
+
+impl Distribution {
+    pub const ALL: [Self; 3] = [Self::Normal, Self::Uniform, Self::Skewed];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Normal => "Normal",
+            Self::Uniform => "Uniform",
+            Self::Skewed => "Skewed to MMC",
+        }
+    }
+}
+
+/// How far the nearer limit is from the mean.
+#[derive(Clone, Copy, Debug, PartialEq, serde::Deserialize, serde::Serialize)]
+pub enum Spread {
+    /// In standard deviations.
+    Sigma(f64),
+    /// As a process capability index, the nearer limit is 3 Cpk standard deviations away.
+    Cpk(f64),
+}
+
+impl Spread {
+    fn sigmas(self) -> f64 {
+        match self {
+            Self::Sigma(sigmas) => sigmas,
+            Self::Cpk(cpk) => 3.0 * cpk,
+        }
+    }
+}
+
+/// How the sizes of one feature are spread between its limits.
+#[derive(Clone, Copy, Debug, PartialEq, serde::Deserialize, serde::Serialize)]
+pub struct SizeModel {
+    pub distribution: Distribution,
+    pub spread: Spread,
+}
+
+impl Default for SizeModel {
+    fn default() -> Self {
+        Self {
+            distribution: Distribution::Normal,
+            spread: Spread::Sigma(3.0),
+        }
+    }
+}
+
+impl SizeModel {
+    /// Mean and standard deviation in mm of a size between `lower` and
+    /// `upper`, MMC is the upper limit when `mmc_upper` (shafts).
+    pub fn moments(&self, lower: f64, upper: f64, mmc_upper: bool) -> (f64, f64) {
+        let width = upper - lower;
+        let sigmas = self.spread.sigmas().max(f64::EPSILON);
+
+        match self.distribution {
+            Distribution::Normal => ((lower + upper) / 2.0, width / 2.0 / sigmas),
+            Distribution::Uniform => ((lower + upper) / 2.0, width / 12f64.sqrt()),
+            Distribution::Skewed => {
+                let mean = if mmc_upper {
+                    upper - width / 3.0
+                } else {
+                    lower + width / 3.0
+                };
+                (mean, width / 3.0 / sigmas)
+            }
+        }
+    }
+
+    /// One size in mm, parts outside the limits are kept as if uninspected.
+    pub fn sample(&self, rng: &mut impl Rng, lower: f64, upper: f64, mmc_upper: bool) -> f64 {
+        match self.distribution {
+            Distribution::Uniform => lower + (upper - lower) * rng.gen::<f64>(),
+            _ => {
+                let (mean, sigma) = self.moments(lower, upper, mmc_upper);
+                mean + sigma * standard_normal(rng)
+            }
+        }
+    }
+}
+
+/// Box-Muller transform of two uniform samples.
+fn standard_normal(rng: &mut impl Rng) -> f64 {
+    let u1 = 1.0 - rng.gen::<f64>();
+    let u2 = rng.gen::<f64>();
+    (-2.0 * u1.ln()).sqrt() * (std::f64::consts::TAU * u2).cos()
+}
+
+/// Complementary error function, fractional error below 1.2e-7 everywhere.
+fn erfc(x: f64) -> f64 {
+    // Numerical Recipes erfcc, Chebyshev fitting
+    let z = x.abs();
+    let t = 1.0 / (1.0 + 0.5 * z);
+    let poly = -z * z - 1.265_512_23
+        + t * (1.000_023_68
+            + t * (0.374_091_96
+                + t * (0.096_784_18
+                    + t * (-0.186_288_06
+                        + t * (0.278_868_07
+                            + t * (-1.135_203_98
+                                + t * (1.488_515_87 + t * (-0.822_152_23 + t * 0.170_872_77))))))));
+    let r = t * poly.exp();
+    if x >= 0.0 {
+        r
+    } else {
+        2.0 - r
+    }
+}
+
+/// Probability that a standard normal is below `z`.
+pub fn normal_cdf(z: f64) -> f64 {
+    0.5 * erfc(-z / std::f64::consts::SQRT_2)
+}
+
+/// Probability as a percentage, or in ppm when it is very small.
+pub fn probability(p: f64) -> String {
+    if p > 0.0 && p < 0.000_1 {
+        format!("{:.1} ppm", p * 1_000_000.0)
+    } else {
+        format!("{:.2} %", p * 100.0)
+    }
+}
+
+/// Clearance statistics from one method.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Summary {
+    pub mean: f64,
+    pub sigma: f64,
+    pub interference: f64,
+    /// Clearance at each of `PERCENTILES`.
+    pub percentiles: [f64; 5],
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Outcome {
+    pub rss: Summary,
+    pub monte_carlo: Summary,
+    /// Start of the first bin, bin width and the count in each bin.
+    pub histogram: (f64, f64, Vec<usize>),
+}
+
+/// Scale on a size in mm for the feature at its material temperature.
+fn scale(feature: &Feature, thermal: bool) -> f64 {
+    if thermal {
+        feature.thermal_scale()
+    } else {
+        1.0
+    }
+}
+
+/// RSS and Monte Carlo clearance of `hole` over `shaft`, at temperature if `thermal`.
+pub fn analyse(
+    hole: &Feature,
+    shaft: &Feature,
+    models: (&SizeModel, &SizeModel),
+    samples: usize,
+    thermal: bool,
+) -> Outcome {
+    let (hole_model, shaft_model) = models;
+    let (hole_lower, hole_upper) = (hole.lower_limit().mm(), hole.upper_limit().mm());
+    let (shaft_lower, shaft_upper) = (shaft.lower_limit().mm(), shaft.upper_limit().mm());
+    let (hole_scale, shaft_scale) = (scale(hole, thermal), scale(shaft, thermal));
+
+    // The clearance is normal if both sizes are, the root sum square of the sigmas
+    let (hole_mean, hole_sigma) = hole_model.moments(hole_lower, hole_upper, false);
+    let (shaft_mean, shaft_sigma) = shaft_model.moments(shaft_lower, shaft_upper, true);
+    let mean = hole_mean * hole_scale - shaft_mean * shaft_scale;
+    let sigma = (hole_sigma * hole_scale).hypot(shaft_sigma * shaft_scale);
+
+    let rss = Summary {
+        mean,
+        sigma,
+        interference: if sigma > 0.0 {
+            normal_cdf(-mean / sigma)
+        } else {
+            f64::from(mean < 0.0)
+        },
+        percentiles: PERCENTILES.map(|(_, z)| mean + z * sigma),
+    };
+
+    let mut rng = StdRng::seed_from_u64(SEED);
+    let mut clearances = (0..samples.max(1))
+        .map(|_| {
+            let hole = hole_model.sample(&mut rng, hole_lower, hole_upper, false);
+            let shaft = shaft_model.sample(&mut rng, shaft_lower, shaft_upper, true);
+            hole * hole_scale - shaft * shaft_scale
+        })
+        .collect::<Vec<_>>();
+    clearances.sort_by(f64::total_cmp);
+
+    let n = clearances.len() as f64;
+    let mc_mean = clearances.iter().sum::<f64>() / n;
+    let variance = clearances
+        .iter()
+        .map(|c| (c - mc_mean).powi(2))
+        .sum::<f64>()
+        / n;
+
+    let monte_carlo = Summary {
+        mean: mc_mean,
+        sigma: variance.sqrt(),
+        interference: clearances.partition_point(|c| *c < 0.0) as f64 / n,
+        percentiles: PERCENTILES
+            .map(|(p, _)| clearances[((p / 100.0) * (n - 1.0)).round() as usize]),
+    };
+
+    let (min, max) = (clearances[0], clearances[clearances.len() - 1]);
+    let width = ((max - min) / BINS as f64).max(f64::EPSILON);
+    let mut counts = vec![0; BINS];
+    for clearance in &clearances {
+        let bin = ((clearance - min) / width) as usize;
+        counts[bin.min(BINS - 1)] += 1;
+    }
+
+    Outcome {
+        rss,
+        monte_carlo,
+        histogram: (min, width, counts),
+    }
+}
+
+/// Everything an analysis depends on, to tell when it needs running again.
+#[derive(Clone, PartialEq)]
+struct Inputs {
+    limits: [Length; 4],
+    scales: [f64; 2],
+    models: (SizeModel, SizeModel),
+    samples: usize,
+    thermal: bool,
+}
+
+#[derive(Clone, serde::Deserialize, serde::Serialize)]
+pub struct Statistics {
+    pub hole: SizeModel,
+    pub shaft: SizeModel,
+    pub samples: usize,
+    #[serde(skip)]
+    cache: Option<(Inputs, Outcome, Option<Outcome>)>,
+}
+
+impl Default for Statistics {
+    fn default() -> Self {
+        Self {
+            hole: SizeModel::default(),
+            shaft: SizeModel::default(),
+            samples: 20_000,
+            cache: None,
+        }
+    }
+}
+
+impl Statistics {
+    fn model_ui(ui: &mut Ui, id: &str, model: &mut SizeModel) {
+        ComboBox::from_id_salt(format!("{id}_distribution"))
+            .width(110.0)
+            .selected_text(model.distribution.label())
+            .show_ui(ui, |ui| {
+                for distribution in Distribution::ALL {
+                    ui.selectable_value(
+                        &mut model.distribution,
+                        distribution,
+                        distribution.label(),
+                    );
+                }
+            })
+            .response
+            .on_hover_text("Distribution of sizes within the limits");
+
+        ui.add_enabled_ui(model.distribution != Distribution::Uniform, |ui| {
+            let sigmas = model.spread.sigmas();
+            if ui
+                .selectable_label(matches!(model.spread, Spread::Sigma(_)), "σ")
+                .on_hover_text("Standard deviations from the mean to the nearer limit")
+                .clicked()
+            {
+                model.spread = Spread::Sigma(sigmas);
+            }
+            if ui
+                .selectable_label(matches!(model.spread, Spread::Cpk(_)), "Cpk")
+                .on_hover_text("Process capability index")
+                .clicked()
+            {
+                model.spread = Spread::Cpk(sigmas / 3.0);
+            }
+
+            let (Spread::Sigma(value) | Spread::Cpk(value)) = &mut model.spread;
+            ui.add_sized(
+                [45.0, 18.0],
+                DragValue::new(value)
+                    .speed(0.01)
+                    .range(0.1..=10.0)
+                    .min_decimals(2),
+            );
+        });
+    }
+
+    pub fn show(&mut self, ui: &mut Ui, hole: &Feature, shaft: &Feature, state: &State) {
+        ui.label(RichText::new("Statistical Fit").strong().size(15.0));
+        ui.add_space(5.0);
+
+        egui::Frame::group(ui.style())
+            .inner_margin(10.0)
+            .rounding(10.0)
+            .show(ui, |ui| {
+                Grid::new("statistics_models")
+                    .min_col_width(10.0)
+                    .show(ui, |ui| {
+                        ui.label("Hole");
+                        ui.horizontal(|ui| Self::model_ui(ui, "statistics_hole", &mut self.hole));
+                        ui.end_row();
+
+                        ui.label("Shaft");
+                        ui.horizontal(|ui| Self::model_ui(ui, "statistics_shaft", &mut self.shaft));
+                        ui.end_row();
+
+                        ui.label("Samples");
+                        ui.add_sized(
+                            [70.0, 18.0],
+                            DragValue::new(&mut self.samples)
+                                .speed(100.0)
+                                .range(1_000..=1_000_000),
+                        );
+                        ui.end_row();
+                    });
+
+                let inputs = Inputs {
+                    limits: [
+                        hole.lower_limit(),
+                        hole.upper_limit(),
+                        shaft.lower_limit(),
+                        shaft.upper_limit(),
+                    ],
+                    scales: [hole.thermal_scale(), shaft.thermal_scale()],
+                    models: (self.hole, self.shaft),
+                    samples: self.samples,
+                    thermal: state.thermal,
+                };
+
+                if self.cache.as_ref().map(|(cached, ..)| cached) != Some(&inputs) {
+                    let models = (&self.hole, &self.shaft);
+                    let outcome = analyse(hole, shaft, models, self.samples, false);
+                    let thermal = state
+                        .thermal
+                        .then(|| analyse(hole, shaft, models, self.samples, true));
+                    self.cache = Some((inputs, outcome, thermal));
+                }
+
+                let Some((_, outcome, thermal)) = &self.cache else {
+                    return;
+                };
+
+                ui.add_space(5.0);
+                outcome_ui(ui, outcome, thermal.as_ref(), &state.units);
+                ui.add_space(5.0);
+                histogram_ui(ui, outcome, thermal.as_ref(), &state.units);
+            });
+    }
+}
+
+fn outcome_ui(ui: &mut Ui, outcome: &Outcome, thermal: Option<&Outcome>, units: &Units) {
+    let range = outcome.monte_carlo.percentiles[4]
+        .abs()
+        .max(outcome.monte_carlo.percentiles[0].abs());
+    let mut columns = vec![("Monte Carlo", &outcome.monte_carlo), ("RSS", &outcome.rss)];
+    if let Some(thermal) = thermal {
+        columns.push(("MC at temp", &thermal.monte_carlo));
+        columns.push(("RSS at temp", &thermal.rss));
+    }
+
+    Grid::new("statistics_outcome")
+        .striped(true)
+        .min_col_width(10.0)
+        .show(ui, |ui| {
+            ui.label("");
+            for (heading, _) in &columns {
+                ui.label(RichText::new(*heading).strong());
+            }
+            ui.end_row();
+
+            ui.label("Clearance");
+            for (_, summary) in &columns {
+                ui.label(probability(1.0 - summary.interference));
+            }
+            ui.end_row();
+
+            ui.label("Interference");
+            for (_, summary) in &columns {
+                ui.label(probability(summary.interference));
+            }
+            ui.end_row();
+
+            ui.label("Mean");
+            for (_, summary) in &columns {
+                ui.label(units.deviation(summary.mean, range, true));
+            }
+            ui.end_row();
+
+            ui.label("σ");
+            for (_, summary) in &columns {
+                ui.label(units.deviation(summary.sigma, range, false));
+            }
+            ui.end_row();
+
+            for (idx, (percentile, _)) in PERCENTILES.iter().enumerate() {
+                ui.label(format!("P{percentile}"))
+                    .on_hover_text("Clearance at this percentile, negative for interference");
+                for (_, summary) in &columns {
+                    ui.label(units.deviation(summary.percentiles[idx], range, true));
+                }
+                ui.end_row();
+            }
+        });
+}
+
+fn histogram_ui(ui: &mut Ui, outcome: &Outcome, thermal: Option<&Outcome>, units: &Units) {
+    // Plotted in the small deviation unit
+    let (unit, scale) = if units.imperial() {
+        ("thou", 1_000.0 / super::units::MM_PER_INCH)
+    } else {
+        ("µm", 1_000.0)
+    };
+
+    let chart = |outcome: &Outcome, name: &str, colour: Color32| {
+        let (start, width, counts) = &outcome.histogram;
+        let bars = counts
+            .iter()
+            .enumerate()
+            .map(|(bin, count)| {
+                let centre = start + (bin as f64 + 0.5) * width;
+                Bar::new(centre * scale, *count as f64).width(width * scale)
+            })
+            .collect();
+        BarChart::new(bars).name(name).color(colour)
+    };
+
+    Plot::new("statistics_histogram")
+        .height(150.0)
+        .legend(Legend::default())
+        .x_axis_label(format!("Clearance ({unit})"))
+        .show_y(false)
+        .allow_drag(false)
+        .allow_zoom(false)
+        .allow_scroll(false)
+        .show(ui, |plot_ui| {
+            plot_ui.bar_chart(chart(outcome, "At 20 ºC", Color32::from_rgb(0, 140, 200)));
+            if let Some(thermal) = thermal {
+                plot_ui.bar_chart(chart(
+                    thermal,
+                    "At temperature",
+                    Color32::from_rgb(220, 120, 0),
+                ));
+            }
+            plot_ui.vline(VLine::new(0.0).color(Color32::RED).name("Zero clearance"));
+        });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fit(hole: &str, shaft: &str) -> (Feature, Feature) {
+        (
+            Feature::from_iso(true, 10.0, hole.parse().unwrap()).unwrap(),
+            Feature::from_iso(false, 10.0, shaft.parse().unwrap()).unwrap(),
+        )
+    }
+
+    #[test]
+    fn test_normal_cdf() {
+        assert!((normal_cdf(0.0) - 0.5).abs() < 1e-7);
+        assert!((normal_cdf(-3.0) - 0.001_349_9).abs() < 1e-6);
+        assert!((normal_cdf(1.959_964) - 0.975).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_statistics() {
+        let model = SizeModel::default();
+
+        // H7/g6 always clears, the RSS mean is the mid-limits clearance
+        let (hole, shaft) = fit("H7", "g6");
+        let outcome = analyse(&hole, &shaft, (&model, &model), 20_000, false);
+        assert!((outcome.rss.mean - 0.017).abs() < 1e-12);
+        assert_eq!(outcome.monte_carlo.interference, 0.0);
+        assert!(outcome.rss.interference < 1e-6);
+
+        // H7/k6 is a transition fit, Monte Carlo and RSS should roughly agree
+        let (hole, shaft) = fit("H7", "k6");
+        let outcome = analyse(&hole, &shaft, (&model, &model), 20_000, false);
+        let (mc, rss) = (outcome.monte_carlo, outcome.rss);
+        assert!(mc.interference > 0.0 && mc.interference < 1.0);
+        assert!((mc.interference - rss.interference).abs() < 0.02);
+        assert!((mc.percentiles[2] - rss.percentiles[2]).abs() < 0.000_5);
+        assert_eq!(
+            outcome.histogram.2.iter().sum::<usize>(),
+            20_000,
+            "every sample is binned"
+        );
+
+        // Pulling both toward MMC tightens the fit
+        let skewed = SizeModel {
+            distribution: Distribution::Skewed,
+            spread: Spread::Cpk(1.0),
+        };
+        let tighter = analyse(&hole, &shaft, (&skewed, &skewed), 20_000, false);
+        assert!(tighter.rss.interference > rss.interference);
+
+        let uniform = SizeModel {
+            distribution: Distribution::Uniform,
+            ..model
+        };
+        let (mean, sigma) = uniform.moments(0.0, 1.2, false);
+        assert_eq!(mean, 0.6);
+        assert!((sigma - 1.2 / 12f64.sqrt()).abs() < 1e-12);
+    }
+}
@@ -0,0 +1,403 @@
+use std::fmt;
+
+use egui::{Color32, DragValue, Grid, RichText, Ui};
+
+use super::{
+    feature::Feature,
+    length::Length,
+    material::{self, Material},
+    units::Units,
+    utils::State,
+};
+
+// Press fit stresses from the Lamé thick walled cylinder equations, in plane
+// stress with no axial load. Diameters are in mm, stresses and moduli in MPa
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum StressError {
+    HubTooThin,
+    BoreTooLarge,
+}
+
+impl fmt::Display for StressError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::HubTooThin => write!(f, "The hub outer diameter must be larger than the fit"),
+            Self::BoreTooLarge => write!(f, "The shaft bore must be smaller than the fit"),
+        }
+    }
+}
+
+impl std::error::Error for StressError {}
+
+/// Stresses at the fitted surface of one part, compression is negative.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Surface {
+    pub hoop: f64,
+    pub radial: f64,
+    pub von_mises: f64,
+    pub yield_factor: f64,
+    pub uts_factor: f64,
+}
+
+impl Surface {
+    fn new(hoop: f64, radial: f64, mat: &Material) -> Self {
+        let von_mises = (hoop * hoop - hoop * radial + radial * radial).sqrt();
+        // Brittle parts break on the largest principal stress rather than yielding
+        let principal = hoop.abs().max(radial.abs());
+
+        Self {
+            hoop,
+            radial,
+            von_mises,
+            yield_factor: mat.yield_strength / von_mises,
+            uts_factor: mat.uts / principal,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Stresses {
+    pub pressure: f64,
+    pub hub: Surface,
+    pub shaft: Surface,
+}
+
+/// Stresses from a diametral `interference` on diameter `d`, a negative
+/// interference is a clearance and leaves the parts unloaded.
+pub fn lame(
+    interference: f64,
+    d: f64,
+    hub_outer: f64,
+    shaft_bore: f64,
+    hub: &Material,
+    shaft: &Material,
+) -> Result<Stresses, StressError> {
+    if hub_outer <= d {
+        return Err(StressError::HubTooThin);
+    }
+    if shaft_bore >= d {
+        return Err(StressError::BoreTooLarge);
+    }
+
+    let hub_k = (hub_outer.powi(2) + d.powi(2)) / (hub_outer.powi(2) - d.powi(2));
+    let shaft_k = (d.powi(2) + shaft_bore.powi(2)) / (d.powi(2) - shaft_bore.powi(2));
+
+    // Radial growth of the hub bore plus shrinkage of the shaft per unit pressure
+    let compliance =
+        d / hub.youngs * (hub_k + hub.poisson) + d / shaft.youngs * (shaft_k - shaft.poisson);
+    let pressure = (interference / compliance).max(0.0);
+
+    Ok(Stresses {
+        pressure,
+        hub: Surface::new(pressure * hub_k, -pressure, hub),
+        shaft: Surface::new(-pressure * shaft_k, -pressure, shaft),
+    })
+}
+
+/// Geometry of the parts around the fit.
+#[derive(Clone, serde::Deserialize, serde::Serialize)]
+pub struct PressFit {
+    /// Zero for twice the fit diameter, as is any size no larger than the fit.
+    pub hub_outer: Length,
+    /// Zero for a solid shaft.
+    pub shaft_bore: Length,
+}
+
+impl Default for PressFit {
+    fn default() -> Self {
+        Self {
+            hub_outer: Length::ZERO,
+            shaft_bore: Length::ZERO,
+        }
+    }
+}
+
+impl PressFit {
+    /// Hub outer diameter used for a fit of diameter `d`.
+    pub fn hub_outer(&self, d: Length) -> Length {
+        if self.hub_outer > d {
+            self.hub_outer
+        } else {
+            d * 2
+        }
+    }
+
+    /// Stresses at the largest, mid and smallest interference, at temperature if `thermal`.
+    pub fn analyse(
+        &self,
+        hole: &Feature,
+        shaft: &Feature,
+        thermal: bool,
+    ) -> Result<[Stresses; 3], StressError> {
+        let max = shaft.upper_limit_mm(thermal) - hole.lower_limit_mm(thermal);
+        let min = shaft.lower_limit_mm(thermal) - hole.upper_limit_mm(thermal);
+
+        // Moduli and yield strengths follow the parts to temperature
+        let (hub, pin) = if thermal {
+            (hole.mat.at(hole.mat.temp), shaft.mat.at(shaft.mat.temp))
+        } else {
+            (hole.mat.clone(), shaft.mat.clone())
+        };
+
+        let stresses = |interference: f64| {
+            lame(
+                interference,
+                hole.size.mm(),
+                self.hub_outer(hole.size).mm(),
+                self.shaft_bore.mm(),
+                &hub,
+                &pin,
+            )
+        };
+
+        Ok([stresses(max)?, stresses((max + min) / 2.0)?, stresses(min)?])
+    }
+
+    fn material_ui(
+        ui: &mut Ui,
+        hole: &mut Feature,
+        shaft: &mut Feature,
+        custom: &[Material],
+        units: &Units,
+    ) {
+        let stress_drag = |ui: &mut Ui, value: &mut f64, hover: &str| {
+            ui.add_sized(
+                [80.0, 18.0],
+                DragValue::new(value)
+                    .custom_formatter(|s, _| units.stress(s))
+                    .custom_parser(|s| units.parse_stress(s))
+                    .speed(1.0)
+                    .range(1.0..=f64::MAX),
+            )
+            .on_hover_text(hover);
+        };
+
+        ui.label("");
+        ui.label(RichText::new("Hub").strong());
+        ui.label(RichText::new("Shaft").strong());
+        ui.end_row();
+
+        ui.label("");
+        material::select_ui(ui, "stress_hub", &mut hole.mat, custom, units);
+        material::select_ui(ui, "stress_shaft", &mut shaft.mat, custom, units);
+        ui.end_row();
+
+        ui.label("E");
+        stress_drag(ui, &mut hole.mat.youngs, "Young's modulus");
+        stress_drag(ui, &mut shaft.mat.youngs, "Young's modulus");
+        ui.end_row();
+
+        ui.label("ν");
+        for mat in [&mut hole.mat, &mut shaft.mat] {
+            ui.add_sized(
+                [80.0, 18.0],
+                DragValue::new(&mut mat.poisson)
+                    .speed(0.01)
+                    .range(0.0..=0.5)
+                    .min_decimals(2),
+            )
+            .on_hover_text("Poisson's ratio");
+        }
+        ui.end_row();
+
+        ui.label("Yield");
+        stress_drag(ui, &mut hole.mat.yield_strength, "Yield strength");
+        stress_drag(ui, &mut shaft.mat.yield_strength, "Yield strength");
+        ui.end_row();
+
+        ui.label("UTS");
+        stress_drag(ui, &mut hole.mat.uts, "Ultimate tensile strength");
+        stress_drag(ui, &mut shaft.mat.uts, "Ultimate tensile strength");
+        ui.end_row();
+    }
+
+    /// Panel with the part geometry and materials, and the stresses at each condition.
+    pub fn show(&mut self, ui: &mut Ui, hole: &mut Feature, shaft: &mut Feature, state: &State) {
+        let units = &state.units;
+
+        ui.label(RichText::new("Press Fit Stress").strong().size(15.0));
+        ui.add_space(5.0);
+
+        egui::Frame::group(ui.style())
+            .inner_margin(10.0)
+            .rounding(10.0)
+            .show(ui, |ui| {
+                ui.horizontal(|ui| {
+                    for (length, hover) in [
+                        (
+                            &mut self.hub_outer,
+                            "Hub outer diameter, zero for twice the fit diameter",
+                        ),
+                        (&mut self.shaft_bore, "Shaft bore diameter, zero for solid"),
+                    ] {
+                        ui.add_sized(
+                            [55.0, 18.0],
+                            length
+                                .drag()
+                                .custom_formatter(|l, _| units.number(l))
+                                .custom_parser(|l| units.parse_length(l))
+                                .speed(0.1)
+                                .range(0.0..=10_000.0),
+                        )
+                        .on_hover_text(format!("{hover} ({})", units.length_unit()));
+                    }
+                });
+
+                if self.hub_outer > Length::ZERO && self.hub_outer <= hole.size {
+                    ui.colored_label(
+                        Color32::YELLOW,
+                        format!(
+                            "Hub outer diameter taken as {}",
+                            units.length(self.hub_outer(hole.size).mm())
+                        ),
+                    );
+                }
+
+                ui.add_space(5.0);
+
+                Grid::new("stress_materials")
+                    .striped(false)
+                    .min_col_width(10.0)
+                    .show(ui, |ui| {
+                        Self::material_ui(ui, hole, shaft, &state.materials, units)
+                    });
+
+                ui.add_space(5.0);
+
+                let mut columns = Vec::new();
+                for thermal in [false, true] {
+                    if thermal && !state.thermal {
+                        continue;
+                    }
+                    match self.analyse(hole, shaft, thermal) {
+                        Ok(stresses) => {
+                            let suffix = if thermal { " at temp" } else { "" };
+                            for (condition, stresses) in ["MMC", "Mid", "LMC"].iter().zip(stresses)
+                            {
+                                columns.push((format!("{condition}{suffix}"), stresses));
+                            }
+                        }
+                        Err(error) => {
+                            ui.colored_label(Color32::RED, error.to_string());
+                            return;
+                        }
+                    }
+                }
+
+                stresses_ui(ui, &columns, units);
+            });
+    }
+}
+
+/// A safety factor, red below 1 and yellow below 1.5.
+pub fn factor_ui(ui: &mut Ui, factor: f64) {
+    if factor.is_infinite() {
+        ui.label("–");
+    } else if factor < 1.0 {
+        ui.colored_label(Color32::RED, format!("{factor:.2}"));
+    } else if factor < 1.5 {
+        ui.colored_label(Color32::YELLOW, format!("{factor:.2}"));
+    } else {
+        ui.label(format!("{factor:.2}"));
+    }
+}
+
+fn stresses_ui(ui: &mut Ui, columns: &[(String, Stresses)], units: &Units) {
+    let stress_row = |ui: &mut Ui, label: &str, value: &dyn Fn(&Stresses) -> f64| {
+        ui.label(label);
+        for (_, stresses) in columns {
+            ui.label(units.stress(value(stresses)));
+        }
+        ui.end_row();
+    };
+
+    let factor_row = |ui: &mut Ui, label: &str, hover: &str, value: &dyn Fn(&Stresses) -> f64| {
+        ui.label(label).on_hover_text(hover);
+        for (_, stresses) in columns {
+            factor_ui(ui, value(stresses));
+        }
+        ui.end_row();
+    };
+
+    Grid::new("stress_results")
+        .striped(true)
+        .min_col_width(10.0)
+        .show(ui, |ui| {
+            ui.label("");
+            for (heading, _) in columns {
+                ui.label(RichText::new(heading).strong());
+            }
+            ui.end_row();
+
+            let yield_hover = "Yield strength over von Mises stress";
+            let uts_hover = "UTS over the largest principal stress";
+
+            stress_row(ui, "Pressure", &|s| s.pressure);
+            stress_row(ui, "Hub hoop", &|s| s.hub.hoop);
+            stress_row(ui, "Hub radial", &|s| s.hub.radial);
+            stress_row(ui, "Hub von Mises", &|s| s.hub.von_mises);
+            factor_row(ui, "Hub SF yield", yield_hover, &|s| s.hub.yield_factor);
+            factor_row(ui, "Hub SF UTS", uts_hover, &|s| s.hub.uts_factor);
+            stress_row(ui, "Shaft hoop", &|s| s.shaft.hoop);
+            stress_row(ui, "Shaft radial", &|s| s.shaft.radial);
+            stress_row(ui, "Shaft von Mises", &|s| s.shaft.von_mises);
+            factor_row(ui, "Shaft SF yield", yield_hover, &|s| s.shaft.yield_factor);
+            factor_row(ui, "Shaft SF UTS", uts_hover, &|s| s.shaft.uts_factor);
+        });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lame() {
+        let steel = Material::default();
+
+        // Solid steel shaft in a hub twice its size, p = Eδ/2d·(1 - (d/D)²)
+        let stresses = lame(0.05, 50.0, 100.0, 0.0, &steel, &steel).unwrap();
+        assert!((stresses.pressure - 75.0).abs() < 1e-9);
+        assert!((stresses.hub.hoop - 125.0).abs() < 1e-9);
+        assert_eq!(stresses.hub.radial, -75.0);
+        assert!((stresses.hub.von_mises - 175.0).abs() < 1e-9);
+        assert!((stresses.hub.yield_factor - 2.0).abs() < 1e-9);
+        assert!((stresses.shaft.hoop + 75.0).abs() < 1e-9);
+        assert!((stresses.shaft.von_mises - 75.0).abs() < 1e-9);
+
+        // A hollow shaft is more compliant and more highly stressed in hoop
+        let hollow = lame(0.05, 50.0, 100.0, 25.0, &steel, &steel).unwrap();
+        assert!(hollow.pressure < stresses.pressure);
+        assert!(hollow.shaft.hoop < stresses.shaft.hoop);
+
+        // A clearance carries no load
+        let clear = lame(-0.01, 50.0, 100.0, 0.0, &steel, &steel).unwrap();
+        assert_eq!(clear.pressure, 0.0);
+        assert!(clear.hub.yield_factor.is_infinite());
+
+        assert_eq!(
+            lame(0.05, 50.0, 50.0, 0.0, &steel, &steel),
+            Err(StressError::HubTooThin)
+        );
+
+        // H7/s6 at 10 mm is 0.008 to 0.032 mm of interference
+        let hole = Feature::from_iso(true, 10.0, "H7".parse().unwrap()).unwrap();
+        let shaft = Feature::from_iso(false, 10.0, "s6".parse().unwrap()).unwrap();
+        let [max, mid, min] = PressFit::default().analyse(&hole, &shaft, false).unwrap();
+        let expected = lame(0.032, 10.0, 20.0, 0.0, &steel, &steel).unwrap();
+        assert!((max.pressure - expected.pressure).abs() < 1e-9);
+        assert!(max.pressure > mid.pressure && mid.pressure > min.pressure);
+        assert!(min.pressure > 0.0);
+
+        // The hub follows the fit up in size until one is given
+        let hole = Feature::from_iso(true, 50.0, "H7".parse().unwrap()).unwrap();
+        let shaft = Feature::from_iso(false, 50.0, "s6".parse().unwrap()).unwrap();
+        let mut press = PressFit::default();
+        assert_eq!(press.hub_outer(hole.size), Length::from_mm(100.0));
+        assert!(press.analyse(&hole, &shaft, false).is_ok());
+        press.hub_outer = Length::from_mm(40.0);
+        assert_eq!(press.hub_outer(hole.size), Length::from_mm(100.0));
+        press.hub_outer = Length::from_mm(80.0);
+        assert_eq!(press.hub_outer(hole.size), Length::from_mm(80.0));
+    }
+}
@@ -0,0 +1,270 @@
+use egui::{Color32, RichText, Ui};
+use egui_plot::{HLine, Legend, Line, Plot, PlotPoints, VLine};
+
+use super::{feature::Feature, units::Units, utils::State};
+
+// Fit at each condition as the part temperatures are swept, clearance is
+// positive. Temperatures are in ºC and sizes in mm
+
+const STEPS: usize = 200;
+const CONDITIONS: [&str; 3] = ["MMC", "Mid", "LMC"];
+
+#[derive(Clone, Copy, Debug, PartialEq, serde::Deserialize, serde::Serialize)]
+pub enum Mode {
+    Together,
+    /// Hole swept, shaft held at its own temperature.
+    Hole,
+    /// Shaft swept, hole held at its own temperature.
+    Shaft,
+}
+
+impl Mode {
+    pub const ALL: [Self; 3] = [Self::Together, Self::Hole, Self::Shaft];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Together => "Both",
+            Self::Hole => "Hole only",
+            Self::Shaft => "Shaft only",
+        }
+    }
+}
+
+/// MMC, mid and LMC clearance with the parts at the given temperatures.
+pub fn clearances(hole: &Feature, shaft: &Feature, hole_temp: f64, shaft_temp: f64) -> [f64; 3] {
+    let (hole_scale, shaft_scale) = (hole.mat.scale(hole_temp), shaft.mat.scale(shaft_temp));
+
+    let mmc = hole.lower_limit().mm() * hole_scale - shaft.upper_limit().mm() * shaft_scale;
+    let lmc = hole.upper_limit().mm() * hole_scale - shaft.lower_limit().mm() * shaft_scale;
+
+    [mmc, (mmc + lmc) / 2.0, lmc]
+}
+
+#[derive(Clone, serde::Deserialize, serde::Serialize)]
+pub struct Sweep {
+    pub from: f64,
+    pub to: f64,
+    pub mode: Mode,
+}
+
+impl Default for Sweep {
+    fn default() -> Self {
+        Self {
+            from: -40.0,
+            to: 150.0,
+            mode: Mode::Together,
+        }
+    }
+}
+
+impl Sweep {
+    /// Clearances at sweep temperature `temp`.
+    pub fn at(&self, hole: &Feature, shaft: &Feature, temp: f64) -> [f64; 3] {
+        match self.mode {
+            Mode::Together => clearances(hole, shaft, temp, temp),
+            Mode::Hole => clearances(hole, shaft, temp, shaft.mat.temp),
+            Mode::Shaft => clearances(hole, shaft, hole.mat.temp, temp),
+        }
+    }
+
+    fn temps(&self) -> impl Iterator<Item = f64> + '_ {
+        (0..=STEPS).map(|i| self.from + (self.to - self.from) * i as f64 / STEPS as f64)
+    }
+
+    /// Temperatures where each condition changes between clearance and
+    /// interference, as the index into MMC, mid and LMC.
+    pub fn crossings(&self, hole: &Feature, shaft: &Feature) -> Vec<(usize, f64)> {
+        let temps: Vec<f64> = self.temps().collect();
+        let mut crossings = Vec::new();
+
+        for condition in 0..3 {
+            let clearance = |t: f64| self.at(hole, shaft, t)[condition];
+
+            for pair in temps.windows(2) {
+                let (mut low, mut high) = (pair[0], pair[1]);
+                let below = clearance(low) < 0.0;
+                if below == (clearance(high) < 0.0) {
+                    continue;
+                }
+
+                for _ in 0..50 {
+                    let mid = (low + high) / 2.0;
+                    if (clearance(mid) < 0.0) == below {
+                        low = mid;
+                    } else {
+                        high = mid;
+                    }
+                }
+                crossings.push((condition, (low + high) / 2.0));
+            }
+        }
+
+        crossings
+    }
+
+    fn input_ui(&mut self, ui: &mut Ui, units: &Units) {
+        ui.horizontal(|ui| {
+            for (temp, hover) in [(&mut self.from, "From"), (&mut self.to, "To")] {
+                ui.add_sized(
+                    [45.0, 18.0],
+                    egui::DragValue::new(temp)
+                        .custom_formatter(|t, _| units.temp(t))
+                        .custom_parser(|t| units.parse_temp(t))
+                        .speed(1.0)
+                        .range(-273.15..=10_000.0)
+                        .min_decimals(1),
+                )
+                .on_hover_text(hover);
+            }
+
+            ui.separator();
+
+            for mode in Mode::ALL {
+                ui.selectable_value(&mut self.mode, mode, mode.label());
+            }
+        });
+    }
+
+    /// Panel with the sweep range and the clearance plotted against temperature.
+    pub fn show(&mut self, ui: &mut Ui, hole: &Feature, shaft: &Feature, state: &State) {
+        let units = &state.units;
+
+        ui.label(RichText::new("Fit vs Temperature").strong().size(15.0));
+        ui.add_space(5.0);
+
+        egui::Frame::group(ui.style())
+            .inner_margin(10.0)
+            .rounding(10.0)
+            .show(ui, |ui| {
+                self.input_ui(ui, units);
+                ui.add_space(5.0);
+
+                if self.to <= self.from {
+                    ui.colored_label(Color32::RED, "The range must increase");
+                    return;
+                }
+
+                // Only the swept parts leave their own temperature
+                let swept = match self.mode {
+                    Mode::Together => vec![hole, shaft],
+                    Mode::Hole => vec![hole],
+                    Mode::Shaft => vec![shaft],
+                };
+                for feature in swept {
+                    for temp in [self.from, self.to] {
+                        if let Some(warning) = feature.mat.range_warning(temp, units) {
+                            ui.colored_label(Color32::YELLOW, warning);
+                        }
+                    }
+                }
+
+                let crossings = self.crossings(hole, shaft);
+                self.plot_ui(ui, hole, shaft, &crossings, units);
+
+                for &(condition, temp) in &crossings {
+                    ui.label(format!(
+                        "{} crosses zero clearance at {}",
+                        CONDITIONS[condition],
+                        units.temp(temp)
+                    ));
+                }
+            });
+    }
+
+    fn plot_ui(
+        &self,
+        ui: &mut Ui,
+        hole: &Feature,
+        shaft: &Feature,
+        crossings: &[(usize, f64)],
+        units: &Units,
+    ) {
+        // Plotted in the small deviation unit and the display temperature
+        let (unit, scale) = if units.imperial() {
+            ("thou", 1_000.0 / super::units::MM_PER_INCH)
+        } else {
+            ("µm", 1_000.0)
+        };
+        let temp_unit = if units.imperial() { "ºF" } else { "ºC" };
+        let temp = |t: f64| if units.imperial() { t * 1.8 + 32.0 } else { t };
+
+        let colours = [
+            Color32::from_rgb(220, 60, 60),
+            Color32::from_rgb(0, 140, 200),
+            Color32::from_rgb(0, 160, 0),
+        ];
+
+        let curves: Vec<[f64; 3]> = self.temps().map(|t| self.at(hole, shaft, t)).collect();
+
+        Plot::new("sweep_plot")
+            .height(200.0)
+            .legend(Legend::default())
+            .x_axis_label(format!("Temperature ({temp_unit})"))
+            .y_axis_label(format!("Clearance ({unit})"))
+            .allow_drag(false)
+            .allow_zoom(false)
+            .allow_scroll(false)
+            .show(ui, |plot_ui| {
+                for (condition, name) in CONDITIONS.iter().enumerate() {
+                    let points: PlotPoints = self
+                        .temps()
+                        .zip(&curves)
+                        .map(|(t, clearances)| [temp(t), clearances[condition] * scale])
+                        .collect();
+                    plot_ui.line(Line::new(points).color(colours[condition]).name(name));
+                }
+
+                plot_ui.hline(HLine::new(0.0).color(Color32::GRAY));
+
+                for &(condition, t) in crossings {
+                    plot_ui.vline(
+                        VLine::new(temp(t))
+                            .color(colours[condition])
+                            .style(egui_plot::LineStyle::dashed_loose()),
+                    );
+                }
+            });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sweep() {
+        // Aluminium H7 housing on a steel k6 seat at 10 mm
+        let mut hole = Feature::from_iso(true, 10.0, "H7".parse().unwrap()).unwrap();
+        let mut shaft = Feature::from_iso(false, 10.0, "k6".parse().unwrap()).unwrap();
+        hole.mat.cte = 23.0;
+        // A constant CTE keeps the crossings in closed form
+        hole.mat.curve = Default::default();
+        shaft.mat.curve = Default::default();
+
+        let sweep = Sweep::default();
+        let [mmc, mid, lmc] = sweep.at(&hole, &shaft, 20.0);
+        assert!((mmc + 0.010).abs() < 1e-12);
+        assert!((mid - 0.002).abs() < 1e-12);
+        assert!((lmc - 0.014).abs() < 1e-12);
+
+        // The housing loosens on the seat as it warms
+        let crossings = sweep.crossings(&hole, &shaft);
+        assert_eq!(crossings.len(), 2);
+        let (mmc_rise, mid_rise) = (
+            0.010 / (10.0 * 23e-6 - 10.010 * 12e-6),
+            -0.002 / (10.0075 * 23e-6 - 10.0055 * 12e-6),
+        );
+        assert_eq!(crossings[0].0, 0);
+        assert!((crossings[0].1 - 20.0 - mmc_rise).abs() < 1e-6);
+        assert_eq!(crossings[1].0, 1);
+        assert!((crossings[1].1 - 20.0 - mid_rise).abs() < 1e-6);
+
+        // Warming only the steel shaft tightens it
+        let shaft_only = Sweep {
+            mode: Mode::Shaft,
+            ..sweep
+        };
+        let [hot, ..] = shaft_only.at(&hole, &shaft, 100.0);
+        assert!(hot < mmc);
+    }
+}
@@ -1,238 +1,632 @@
+use std::{fmt, str::FromStr};
+
 use super::{
+    designation::ParseError,
+    length::Length,
     lookup::{
-        DELTA, DEVIATIONS_A_G, DEVIATIONS_K_ZC, DEVIATION_MAP, GRADE_MAP, LOWER_J,
+        DEVIATIONS_A_G, DEVIATIONS_K_ZC, DEVIATION_MAP, GRADE_MAP, LOWER_J,
         STANDARD_TOLERANCE_GRADES, UPPER_J,
     },
-    utils::decimals,
+    rules::{self, Rule},
 };
 
-#[derive(Clone, serde::Deserialize, serde::Serialize)]
+#[derive(Clone, Debug, PartialEq, serde::Deserialize, serde::Serialize)]
 pub struct Tolerance {
-    pub upper: f64,
-    pub lower: f64,
+    pub upper: Length,
+    pub lower: Length,
 }
 
 impl Tolerance {
-    pub fn new(upper: f64, lower: f64) -> Self {
+    pub fn new(upper: Length, lower: Length) -> Self {
         Self { upper, lower }
     }
 
-    pub fn mid(&self) -> f64 {
-        self.upper - (self.upper + self.lower) / 2.0
+    pub fn from_mm(upper: f64, lower: f64) -> Self {
+        Self::new(Length::from_mm(upper), Length::from_mm(lower))
     }
 
-    pub fn round(&mut self, n: i32) {
-        self.upper = decimals(self.upper, n);
-        self.lower = decimals(self.lower, n);
+    pub fn mid(&self) -> Length {
+        (self.upper - self.lower) / 2
     }
 }
 
-pub struct GradesDeviations {
-    pub it_numbers: Vec<String>,
-    pub hole_letters: Vec<String>,
-    pub shaft_letters: Vec<String>,
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Letter {
+    A,
+    B,
+    C,
+    CD,
+    D,
+    E,
+    EF,
+    F,
+    FG,
+    G,
+    H,
+    JS,
+    J,
+    K,
+    M,
+    N,
+    P,
+    R,
+    S,
+    T,
+    U,
+    V,
+    X,
+    Y,
+    Z,
+    ZA,
+    ZB,
+    ZC,
 }
 
-impl GradesDeviations {
-    pub fn default() -> Self {
-        let it_numbers = GRADE_MAP.iter().map(|it| it.to_string()).collect();
+impl Letter {
+    /// Every letter in the same order as the columns of `DEVIATION_MAP`.
+    pub const ALL: [Letter; 28] = [
+        Letter::A,
+        Letter::B,
+        Letter::C,
+        Letter::CD,
+        Letter::D,
+        Letter::E,
+        Letter::EF,
+        Letter::F,
+        Letter::FG,
+        Letter::G,
+        Letter::H,
+        Letter::JS,
+        Letter::J,
+        Letter::K,
+        Letter::M,
+        Letter::N,
+        Letter::P,
+        Letter::R,
+        Letter::S,
+        Letter::T,
+        Letter::U,
+        Letter::V,
+        Letter::X,
+        Letter::Y,
+        Letter::Z,
+        Letter::ZA,
+        Letter::ZB,
+        Letter::ZC,
+    ];
 
-        let hole_letters = DEVIATION_MAP
+    pub fn index(self) -> usize {
+        self as usize
+    }
+}
+
+impl fmt::Display for Letter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", DEVIATION_MAP[self.index()].to_uppercase())
+    }
+}
+
+impl FromStr for Letter {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        DEVIATION_MAP
             .iter()
-            .map(|deviation| deviation.to_string().to_uppercase())
-            .collect::<Vec<_>>();
+            .position(|d| d.eq_ignore_ascii_case(s))
+            .map(|idx| Self::ALL[idx])
+            .ok_or_else(|| ParseError::UnknownDeviation(s.to_owned()))
+    }
+}
+
+/// Upper case letters are holes, lower case letters are shafts.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, serde::Deserialize, serde::Serialize)]
+#[serde(try_from = "String", into = "String")]
+pub enum FundamentalDeviation {
+    Hole(Letter),
+    Shaft(Letter),
+}
+
+impl FundamentalDeviation {
+    pub fn new(letter: Letter, hole: bool) -> Self {
+        if hole {
+            Self::Hole(letter)
+        } else {
+            Self::Shaft(letter)
+        }
+    }
+
+    pub fn letter(self) -> Letter {
+        match self {
+            Self::Hole(letter) | Self::Shaft(letter) => letter,
+        }
+    }
+
+    pub fn is_hole(self) -> bool {
+        matches!(self, Self::Hole(_))
+    }
+
+    /// Every hole (A to ZC) or shaft (a to zc) deviation.
+    pub fn all(hole: bool) -> impl Iterator<Item = Self> {
+        Letter::ALL.into_iter().map(move |l| Self::new(l, hole))
+    }
+}
+
+impl fmt::Display for FundamentalDeviation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Hole(letter) => write!(f, "{letter}"),
+            Self::Shaft(letter) => write!(f, "{}", DEVIATION_MAP[letter.index()]),
+        }
+    }
+}
+
+impl FromStr for FundamentalDeviation {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.chars().all(char::is_uppercase) {
+            Ok(Self::Hole(s.parse()?))
+        } else if s.chars().all(char::is_lowercase) {
+            Ok(Self::Shaft(s.parse()?))
+        } else {
+            Err(ParseError::MixedCase(s.to_owned()))
+        }
+    }
+}
 
-        let shaft_letters = DEVIATION_MAP
+impl TryFrom<String> for FundamentalDeviation {
+    type Error = ParseError;
+
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
+impl From<FundamentalDeviation> for String {
+    fn from(deviation: FundamentalDeviation) -> Self {
+        deviation.to_string()
+    }
+}
+
+#[derive(
+    Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, serde::Deserialize, serde::Serialize,
+)]
+#[serde(try_from = "String", into = "String")]
+pub enum ToleranceGrade {
+    IT01,
+    IT0,
+    IT1,
+    IT2,
+    IT3,
+    IT4,
+    IT5,
+    IT6,
+    IT7,
+    IT8,
+    IT9,
+    IT10,
+    IT11,
+    IT12,
+    IT13,
+    IT14,
+    IT15,
+    IT16,
+    IT17,
+    IT18,
+}
+
+impl ToleranceGrade {
+    /// Every grade in the same order as the columns of `GRADE_MAP`.
+    pub const ALL: [ToleranceGrade; 20] = [
+        ToleranceGrade::IT01,
+        ToleranceGrade::IT0,
+        ToleranceGrade::IT1,
+        ToleranceGrade::IT2,
+        ToleranceGrade::IT3,
+        ToleranceGrade::IT4,
+        ToleranceGrade::IT5,
+        ToleranceGrade::IT6,
+        ToleranceGrade::IT7,
+        ToleranceGrade::IT8,
+        ToleranceGrade::IT9,
+        ToleranceGrade::IT10,
+        ToleranceGrade::IT11,
+        ToleranceGrade::IT12,
+        ToleranceGrade::IT13,
+        ToleranceGrade::IT14,
+        ToleranceGrade::IT15,
+        ToleranceGrade::IT16,
+        ToleranceGrade::IT17,
+        ToleranceGrade::IT18,
+    ];
+
+    pub fn index(self) -> usize {
+        self as usize
+    }
+}
+
+impl fmt::Display for ToleranceGrade {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", GRADE_MAP[self.index()])
+    }
+}
+
+impl FromStr for ToleranceGrade {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let number = s
+            .strip_prefix("IT")
+            .or_else(|| s.strip_prefix("it"))
+            .unwrap_or(s);
+
+        GRADE_MAP
             .iter()
-            .map(|deviation| deviation.to_string())
-            .collect::<Vec<_>>();
+            .position(|&g| g == number)
+            .map(|idx| Self::ALL[idx])
+            .ok_or_else(|| ParseError::UnknownGrade(s.to_owned()))
+    }
+}
+
+impl TryFrom<String> for ToleranceGrade {
+    type Error = ParseError;
+
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
+impl From<ToleranceGrade> for String {
+    fn from(grade: ToleranceGrade) -> Self {
+        grade.to_string()
+    }
+}
+
+/// A tolerance class found by `Iso::find` and its total distance from the target limits.
+#[derive(Clone, Debug)]
+pub struct IsoMatch {
+    pub iso: Iso,
+    pub tolerance: Tolerance,
+    pub error: Length,
+}
 
-        Self {
-            it_numbers,
-            hole_letters,
-            shaft_letters,
+impl IsoMatch {
+    pub fn is_exact(&self) -> bool {
+        self.error == Length::ZERO
+    }
+}
+
+/// The fundamental deviation table cell a class was read from.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Source {
+    pub table: &'static str,
+    pub over: i32,
+    pub up_to: i32,
+    pub column: String,
+    /// As printed, without the sign for holes or shafts, None where the cell is empty.
+    pub value: Option<Length>,
+}
+
+/// Everything `Iso::convert` used to reach a tolerance, for checking it against the standard.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Derivation {
+    /// Size range of the standard tolerance row in mm.
+    pub over: i32,
+    pub up_to: i32,
+    pub it: Length,
+    /// None for H, h, JS and js which don't need a deviation table.
+    pub source: Option<Source>,
+    pub rules: Vec<Rule>,
+    pub tolerance: Tolerance,
+}
+
+/// Reasons a tolerance class doesn't exist within ISO 286.
+#[derive(Clone, Debug, PartialEq)]
+pub enum IsoError {
+    SizeOutOfRange(f64),
+    GradeNotDefined(ToleranceGrade),
+    DeviationNotDefined(FundamentalDeviation, f64),
+    NotDefinedUpToOne(FundamentalDeviation),
+    JGradeNotDefined(FundamentalDeviation, ToleranceGrade),
+    KAboveIt8(ToleranceGrade),
+    NAboveIt8(ToleranceGrade),
+}
+
+impl fmt::Display for IsoError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::SizeOutOfRange(size) => {
+                write!(
+                    f,
+                    "Size {size} mm is outside the ISO 286 range of 0 to 3150 mm"
+                )
+            }
+            Self::GradeNotDefined(grade) => {
+                write!(f, "IT{grade} is not defined for sizes above 500 mm")
+            }
+            Self::DeviationNotDefined(deviation, size) => {
+                write!(
+                    f,
+                    "Deviation {deviation} is not defined for a size of {size} mm"
+                )
+            }
+            Self::NotDefinedUpToOne(deviation) => {
+                write!(
+                    f,
+                    "Deviation {deviation} is not defined for sizes up to 1 mm"
+                )
+            }
+            Self::JGradeNotDefined(deviation, grade) => {
+                let grades = if deviation.is_hole() {
+                    "IT6 to IT8"
+                } else {
+                    "IT5 to IT8"
+                };
+                write!(
+                    f,
+                    "{deviation}{grade} is not defined, {deviation} only exists for {grades}"
+                )
+            }
+            Self::KAboveIt8(grade) => {
+                write!(
+                    f,
+                    "K{grade} is not defined, K above IT8 only exists up to 3 mm"
+                )
+            }
+            Self::NAboveIt8(grade) => write!(
+                f,
+                "N{grade} is not defined, N above IT8 only exists from 1 mm to 500 mm"
+            ),
         }
     }
 }
 
-#[derive(Clone, serde::Deserialize, serde::Serialize)]
+impl std::error::Error for IsoError {}
+
+#[derive(Clone, Debug, PartialEq, serde::Deserialize, serde::Serialize)]
 pub struct Iso {
-    pub deviation: String,
-    pub grade: String,
+    pub deviation: FundamentalDeviation,
+    pub grade: ToleranceGrade,
 }
 
 impl Iso {
-    pub fn new(d: &str, g: &str) -> Self {
-        Self {
-            deviation: d.to_owned(),
-            grade: g.to_owned(),
-        }
+    pub fn new(deviation: FundamentalDeviation, grade: ToleranceGrade) -> Self {
+        Self { deviation, grade }
+    }
+
+    /// Searches every deviation and grade for classes with limits closest to `target`, best first.
+    pub fn find(hole: bool, size: f64, target: &Tolerance) -> Vec<IsoMatch> {
+        let mut matches = FundamentalDeviation::all(hole)
+            .flat_map(|deviation| {
+                ToleranceGrade::ALL
+                    .into_iter()
+                    .map(move |grade| Iso::new(deviation, grade))
+            })
+            .filter_map(|iso| {
+                let tolerance = iso.convert(size).ok()?;
+                let error =
+                    (tolerance.upper - target.upper).abs() + (tolerance.lower - target.lower).abs();
+
+                Some(IsoMatch {
+                    iso,
+                    tolerance,
+                    error,
+                })
+            })
+            .collect::<Vec<_>>();
+
+        matches.sort_by_key(|m| m.error);
+        matches
     }
 
-    pub fn convert(&self, size: f64) -> Option<Tolerance> {
-        let hole = self.deviation.chars().next().unwrap().is_uppercase();
+    pub fn convert(&self, size: f64) -> Result<Tolerance, IsoError> {
+        self.derive(size).map(|derivation| derivation.tolerance)
+    }
+
+    /// As `convert`, keeping every table value and special case that went into the result.
+    pub fn derive(&self, size: f64) -> Result<Derivation, IsoError> {
+        if !(size > 0.0 && size <= 3_150.0) {
+            return Err(IsoError::SizeOutOfRange(size));
+        }
 
         // Calculate integer size for lookup
         let int_size = size.ceil() as i32;
 
         // Lookup table indices
-        let idx_grade = GRADE_MAP.iter().position(|&g| g == &self.grade)? + 1; // +1 to ignore column
-        let idx_dev = DEVIATION_MAP
-            .iter()
-            .position(|&d| d.eq_ignore_ascii_case(&self.deviation))?
-            + 1;
+        let idx_grade = self.grade.index() + 1; // +1 to ignore column
         let idx_tol = STANDARD_TOLERANCE_GRADES
             .iter()
-            .position(|&s| s[0] >= int_size)?;
+            .position(|&s| s[0] >= int_size)
+            .ok_or(IsoError::SizeOutOfRange(size))?;
 
         // International tolerance grade value converted to nanometres
-        let tolerance = *STANDARD_TOLERANCE_GRADES[idx_tol].get(idx_grade)? * 100;
+        let tolerance = STANDARD_TOLERANCE_GRADES[idx_tol][idx_grade] * 100;
         if tolerance == -100 {
-            return None;
+            return Err(IsoError::GradeNotDefined(self.grade));
         }
 
-        if hole {
-            Self::lookup_hole(int_size, tolerance, idx_dev, idx_grade)
+        rules::check(self.deviation, self.grade, int_size)?;
+
+        let (mut source, mut rules) = (None, Vec::new());
+        let letter = self.deviation.letter();
+
+        // Deviation table cells marked -1 don't exist at this size
+        if self.deviation.is_hole() {
+            Self::lookup_hole(
+                int_size,
+                tolerance,
+                letter,
+                self.grade,
+                &mut source,
+                &mut rules,
+            )
         } else {
-            Self::lookup_shaft(int_size, tolerance, idx_dev, idx_grade)
+            Self::lookup_shaft(
+                int_size,
+                tolerance,
+                letter,
+                self.grade,
+                &mut source,
+                &mut rules,
+            )
         }
+        .map(|result| Derivation {
+            over: idx_tol
+                .checked_sub(1)
+                .map_or(0, |idx| STANDARD_TOLERANCE_GRADES[idx][0]),
+            up_to: STANDARD_TOLERANCE_GRADES[idx_tol][0],
+            it: Length::from_nm(tolerance as i64),
+            source,
+            rules,
+            tolerance: result,
+        })
+        .ok_or(IsoError::DeviationNotDefined(self.deviation, size))
     }
 
-    fn lookup_hole(size: i32, tol: i32, idx_dev: usize, idx_grade: usize) -> Option<Tolerance> {
-        // Helper function to convert nanometre integers to metre floats
-        let flt = |d: i32| d as f64 / 1_000_000.0;
+    // None where the table has no value, the special cases are all in rules.rs
+    fn lookup_hole(
+        size: i32,
+        tol: i32,
+        letter: Letter,
+        grade: ToleranceGrade,
+        source: &mut Option<Source>,
+        rules: &mut Vec<Rule>,
+    ) -> Option<Tolerance> {
+        // Helper function to convert nanometre integers to lengths
+        let flt = |d: i32| Length::from_nm(d as i64);
 
-        // Helper function to retrieve lookup value, filtering -1, micrometre -> nanometre
-        let rtv = |d: i32| if d != -1 { Some(d * 1000) } else { None };
+        let idx_dev = letter.index() + 1;
+        let heading = DEVIATION_MAP[letter.index()].to_owned();
 
-        if (0..11).contains(&idx_dev) {
-            // A to G
-            let idx_size = DEVIATIONS_A_G.iter().position(|&s| s[0] >= size)?;
-            let dev = rtv(*DEVIATIONS_A_G[idx_size].get(idx_dev)?)?;
-            // let dev = Self::get_dev(size, idx_dev, DEVIATIONS_A_G)?
-            if (idx_dev == 1 || idx_dev == 2) && size == 1 {
-                // This covers deviations A and B for sizes <= 1
-                None
-            } else {
-                Some(Tolerance::new(flt(dev + tol), flt(dev)))
+        match letter {
+            Letter::H => Some(Tolerance::new(flt(tol), Length::ZERO)),
+            Letter::JS => {
+                let half = rules::js_half(grade, tol, rules);
+                Some(Tolerance::new(flt(half), -flt(half)))
             }
-        } else if idx_dev == 11 {
-            // H
-            Some(Tolerance::new(flt(tol), 0.0))
-        } else if idx_dev == 12 {
-            // JS
-            Some(Tolerance::new(flt(tol / 2), -flt(tol / 2)))
-        } else if idx_dev == 13 && (8..11).contains(&idx_grade) {
-            // J
-            let idx_size = UPPER_J.iter().position(|&s| s[0] >= size)?;
-            let dev = rtv(*UPPER_J[idx_size].get(idx_grade - 7)?)?;
-            Some(Tolerance::new(flt(dev), flt(dev - tol)))
-        } else if idx_dev == 14 {
-            // K
-            let idx_size = DEVIATIONS_K_ZC.iter().position(|&s| s[0] >= size)?;
-            let dev =
-                -rtv(*DEVIATIONS_K_ZC[idx_size].get(idx_dev - 13)?)? + Self::delta(size, idx_grade);
-            if idx_grade > 10 && size > 3 {
-                None
-            } else {
+            Letter::J => {
+                let column = grade.index() - 6;
+                let dev = lookup(
+                    UPPER_J,
+                    "Upper J",
+                    format!("IT{grade}"),
+                    size,
+                    column,
+                    source,
+                )?;
                 Some(Tolerance::new(flt(dev), flt(dev - tol)))
             }
-        } else if idx_dev == 15 {
-            // M
-            let idx_size = DEVIATIONS_K_ZC.iter().position(|&s| s[0] >= size)?;
-            let mut dev =
-                -rtv(*DEVIATIONS_K_ZC[idx_size].get(idx_dev - 13)?)? + Self::delta(size, idx_grade);
-            if idx_grade == 8 && size > 250 && size <= 315 {
-                dev += 2_000; // M6 special case
+            _ if letter.index() < Letter::H.index() => {
+                // A to G
+                let dev = lookup(DEVIATIONS_A_G, "A to G", heading, size, idx_dev, source)?;
+                Some(Tolerance::new(flt(dev + tol), flt(dev)))
             }
-            Some(Tolerance::new(flt(dev), flt(dev - tol)))
-        } else if idx_dev == 16 {
-            // N
-            let idx_size = DEVIATIONS_K_ZC.iter().position(|&s| s[0] >= size)?;
-            let dev =
-                -rtv(*DEVIATIONS_K_ZC[idx_size].get(idx_dev - 13)?)? + Self::delta(size, idx_grade);
-            if (idx_grade > 10 && size > 500) || (idx_grade > 10 && size <= 1) {
-                None
-            } else {
+            _ => {
+                // K to ZC mirror the shaft column
+                let column = idx_dev - 13;
+                let ei = lookup(DEVIATIONS_K_ZC, "K to ZC", heading, size, column, source)?;
+                let dev = rules::hole_upper(letter, grade, size, ei, rules);
                 Some(Tolerance::new(flt(dev), flt(dev - tol)))
             }
-        } else if (17..30).contains(&idx_dev) {
-            // P to ZC
-            let idx_size = DEVIATIONS_K_ZC.iter().position(|&s| s[0] >= size)?;
-            let dev = -rtv(*DEVIATIONS_K_ZC[idx_size].get(idx_dev - 13)?)?
-                + if idx_grade < 10 {
-                    Self::delta(size, idx_grade) // Below IT8 delta is added
-                } else {
-                    0
-                };
-            Some(Tolerance::new(flt(dev), flt(dev - tol)))
-        } else {
-            None
         }
     }
 
-    fn lookup_shaft(size: i32, tol: i32, idx_dev: usize, idx_grade: usize) -> Option<Tolerance> {
-        // Helper function to convert nanometre integers to metre floats
-        let flt = |d: i32| d as f64 / 1_000_000.0;
+    fn lookup_shaft(
+        size: i32,
+        tol: i32,
+        letter: Letter,
+        grade: ToleranceGrade,
+        source: &mut Option<Source>,
+        rules: &mut Vec<Rule>,
+    ) -> Option<Tolerance> {
+        // Helper function to convert nanometre integers to lengths
+        let flt = |d: i32| Length::from_nm(d as i64);
 
-        // Helper function to retrieve lookup value, filtering -1, micrometre -> nanometre
-        let rtv = |d: i32| if d != -1 { Some(d * 1000) } else { None };
+        let idx_dev = letter.index() + 1;
+        let heading = DEVIATION_MAP[letter.index()].to_owned();
 
-        if (0..11).contains(&idx_dev) {
-            // a to g
-            let idx_size = DEVIATIONS_A_G.iter().position(|&s| s[0] >= size)?;
-            let dev = -rtv(*DEVIATIONS_A_G[idx_size].get(idx_dev)?)?;
-            if dev == -1 || ((idx_dev == 1 || idx_dev == 2) && size == 1) {
-                // This covers deviations a and b for sizes <= 1
-                None
-            } else {
+        match letter {
+            Letter::H => Some(Tolerance::new(Length::ZERO, -flt(tol))),
+            Letter::JS => {
+                let half = rules::js_half(grade, tol, rules);
+                Some(Tolerance::new(flt(half), -flt(half)))
+            }
+            Letter::J => {
+                // j5 and j6 share a column
+                let shared = grade.max(ToleranceGrade::IT6);
+                let column = shared.index() - 6;
+                let dev = -lookup(
+                    LOWER_J,
+                    "Lower j",
+                    format!("IT{shared}"),
+                    size,
+                    column,
+                    source,
+                )?;
+                Some(Tolerance::new(flt(dev + tol), flt(dev)))
+            }
+            _ if letter.index() < Letter::H.index() => {
+                // a to g
+                let dev = -lookup(DEVIATIONS_A_G, "a to g", heading, size, idx_dev, source)?;
                 Some(Tolerance::new(flt(dev), flt(dev - tol)))
             }
-        } else if idx_dev == 11 {
-            // h
-            Some(Tolerance::new(0.0, -flt(tol)))
-        } else if idx_dev == 12 {
-            // js
-            Some(Tolerance::new(flt(tol / 2), -flt(tol / 2)))
-        } else if idx_dev == 13 && idx_grade > 6 && idx_grade < 11 {
-            // j
-            let idx_size = LOWER_J.iter().position(|&s| s[0] >= size)?;
-            let dev = -rtv(*LOWER_J[idx_size].get(idx_grade.max(8) - 7)?)?;
-            Some(Tolerance::new(flt(dev + tol), flt(dev)))
-        } else if idx_dev == 14 {
-            // k
-            let idx_size = DEVIATIONS_K_ZC.iter().position(|&s| s[0] >= size)?;
-            let dev = if idx_grade > 5 && idx_grade < 10 {
-                rtv(*DEVIATIONS_K_ZC[idx_size].get(idx_dev - 13)?)?
-            } else {
-                0
-            };
-            Some(Tolerance::new(flt(dev + tol), flt(dev)))
-        } else if (15..28).contains(&idx_dev) {
-            // m to zc
-            let idx_size = DEVIATIONS_K_ZC.iter().position(|&s| s[0] >= size)?;
-            let dev = rtv(*DEVIATIONS_K_ZC[idx_size].get(idx_dev - 13)?)?;
-            Some(Tolerance::new(flt(dev + tol), flt(dev)))
-        } else {
-            None
+            Letter::K => {
+                let column = idx_dev - 13;
+                let ei = lookup(DEVIATIONS_K_ZC, "k to zc", heading, size, column, source)?;
+                let dev = rules::shaft_k_lower(grade, ei, rules);
+                Some(Tolerance::new(flt(dev + tol), flt(dev)))
+            }
+            _ => {
+                // m to zc
+                let column = idx_dev - 13;
+                let dev = lookup(DEVIATIONS_K_ZC, "k to zc", heading, size, column, source)?;
+                Some(Tolerance::new(flt(dev + tol), flt(dev)))
+            }
         }
     }
+}
 
-    fn delta(size: i32, grade: usize) -> i32 {
-        if size > 500 || grade < 4 || grade > 9 {
-            0
-        } else {
-            let idx = DELTA.iter().position(|&s| s[0] >= size).unwrap();
-            100 * DELTA[idx][grade - 4]
-        }
+/// Deviation in nanometres from the row covering `size`, None for the -1 cells.
+/// The cell is noted in `source` so that the result can be traced back to it.
+fn lookup<const N: usize>(
+    table: &[[i32; N]],
+    name: &'static str,
+    heading: String,
+    size: i32,
+    column: usize,
+    source: &mut Option<Source>,
+) -> Option<i32> {
+    let idx = table.iter().position(|row| row[0] >= size)?;
+    let dev = table[idx][column];
+
+    *source = Some(Source {
+        table: name,
+        over: idx.checked_sub(1).map_or(0, |idx| table[idx][0]),
+        up_to: table[idx][0],
+        column: heading,
+        value: (dev != -1).then(|| Length::from_um(dev as f64)),
+    });
+
+    (dev != -1).then_some(dev * 1000)
+}
+
+impl fmt::Display for Iso {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}{}", self.deviation, self.grade)
     }
+}
+
+impl FromStr for Iso {
+    type Err = ParseError;
 
-    // fn get_dev(size: i32, idx_dev: usize, dev_map: &[[i32]]) -> Option<i32> {
-    //     dev_map.iter().position(|&s| s[0] >= size).and_then(|idx_size| {
-    //         let dev = *dev_map[idx_size].get(idx_dev)?;
-    //         if dev != -1 { Some(1000 * dev) } else { None }
-    //     })
-    // }
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let split = s.find(|c: char| !c.is_alphabetic()).unwrap_or(s.len());
+
+        Ok(Self {
+            deviation: s[..split].parse()?,
+            grade: s[split..].parse()?,
+        })
+    }
 }
 
 #[cfg(test)]
@@ -241,50 +635,114 @@ mod tests {
 
     #[test]
     fn test_grade_tolerance() {
-        let test_vec = vec![
+        let test_vec = [
+            (
+                "H7".parse::<Iso>().unwrap().convert(10.0),
+                Ok(Tolerance::from_mm(0.015, 0.000)),
+            ),
+            (
+                "js4".parse::<Iso>().unwrap().convert(5.4),
+                Ok(Tolerance::from_mm(0.002, -0.002)),
+            ),
             (
-                Iso::new("H", "7").convert(10.0),
-                Some(Tolerance::new(0.015, 0.000)),
+                "H7".parse::<Iso>().unwrap().convert(52.8),
+                Ok(Tolerance::from_mm(0.030, 0.000)),
             ),
             (
-                Iso::new("js", "4").convert(5.4),
-                Some(Tolerance::new(0.002, -0.002)),
+                "g6".parse::<Iso>().unwrap().convert(52.8),
+                Ok(Tolerance::from_mm(-0.010, -0.029)),
             ),
             (
-                Iso::new("H", "7").convert(52.8),
-                Some(Tolerance::new(0.030, 0.000)),
+                "K6".parse::<Iso>().unwrap().convert(10.0),
+                Ok(Tolerance::from_mm(0.002, -0.007)),
             ),
             (
-                Iso::new("g", "6").convert(52.8),
-                Some(Tolerance::new(-0.010, -0.029)),
+                "K3".parse::<Iso>().unwrap().convert(50.0),
+                Ok(Tolerance::from_mm(-0.0005, -0.0045)),
+            ),
+            // ISO 286-2 only lists T5 to T8, from the 286-1 rule ES = -t + Δ = -66 + 2 µm
+            (
+                "T3".parse::<Iso>().unwrap().convert(53.0),
+                Ok(Tolerance::from_mm(-0.064, -0.069)),
+            ),
+            (
+                "T6".parse::<Iso>().unwrap().convert(53.0),
+                Ok(Tolerance::from_mm(-0.060, -0.079)),
             ),
-            // (
-            //     Iso::new("K", "6").convert(10.0),
-            //     Some(Tolerance::new(0.002, -0.007)),
-            // ),
-            // (
-            //     Iso::new("K", "3").convert(50.0),
-            //     Some(Tolerance::new(-0.0005, -0.0045)),
-            // ),
-            // (
-            //     Iso::new("T", "3").convert(53.0),
-            //     Some(Tolerance::new(-0.066, -0.071)),
-            // ),
         ];
 
-        for test in test_vec.iter() {
-            if let (Some(iso), Some(bilateral)) = test {
-                assert_eq!(decimals(iso.upper, 4), decimals(bilateral.upper, 4));
-                assert_eq!(decimals(iso.lower, 4), decimals(bilateral.lower, 4));
-            }
+        for (convert, expected) in test_vec {
+            assert_eq!(convert, expected);
         }
     }
 
     #[test]
-    fn test_delta_fn() {
-        assert_eq!(Iso::delta(165, 7), 100 * DELTA[8][3]);
-        assert_eq!(Iso::delta(19, 5), 100 * DELTA[4][1]);
-        assert_eq!(Iso::delta(333, 8), 100 * DELTA[11][4]);
-        assert_eq!(Iso::delta(38, 5), 100 * DELTA[5][1]);
+    fn test_iso_errors() {
+        let error = |iso: &str, size: f64| iso.parse::<Iso>().unwrap().convert(size).err();
+        let hole = |letter| FundamentalDeviation::Hole(letter);
+
+        assert_eq!(error("H7", 0.0), Some(IsoError::SizeOutOfRange(0.0)));
+        assert_eq!(
+            error("H7", 3_200.0),
+            Some(IsoError::SizeOutOfRange(3_200.0))
+        );
+        assert_eq!(
+            error("H01", 600.0),
+            Some(IsoError::GradeNotDefined(ToleranceGrade::IT01))
+        );
+        assert_eq!(
+            error("CD7", 100.0),
+            Some(IsoError::DeviationNotDefined(hole(Letter::CD), 100.0))
+        );
+        assert_eq!(
+            error("B11", 0.5),
+            Some(IsoError::NotDefinedUpToOne(hole(Letter::B)))
+        );
+        assert_eq!(
+            error("J9", 10.0),
+            Some(IsoError::JGradeNotDefined(
+                hole(Letter::J),
+                ToleranceGrade::IT9
+            ))
+        );
+        assert_eq!(
+            error("K9", 10.0),
+            Some(IsoError::KAboveIt8(ToleranceGrade::IT9))
+        );
+        assert_eq!(
+            error("N9", 600.0),
+            Some(IsoError::NAboveIt8(ToleranceGrade::IT9))
+        );
+    }
+
+    #[test]
+    fn test_iso_find() {
+        let found = Iso::find(true, 25.0, &Tolerance::from_mm(0.021, 0.0));
+        assert!(found[0].is_exact());
+        assert_eq!(found[0].iso.to_string(), "H7");
+
+        let found = Iso::find(false, 40.0, &Tolerance::from_mm(-0.025, -0.050));
+        assert!(found[0].is_exact());
+        assert_eq!(found[0].iso.to_string(), "f7");
+
+        let found = Iso::find(false, 40.0, &Tolerance::from_mm(-0.026, -0.050));
+        assert!(!found[0].is_exact());
+        assert_eq!(found[0].iso.to_string(), "f7");
+    }
+
+    #[test]
+    fn test_iso_round_trip() {
+        for hole in [true, false] {
+            for deviation in FundamentalDeviation::all(hole) {
+                for grade in ToleranceGrade::ALL {
+                    let iso = Iso::new(deviation, grade);
+                    assert_eq!(iso.to_string().parse::<Iso>(), Ok(iso));
+                }
+            }
+        }
+
+        assert_eq!("IT7".parse::<ToleranceGrade>(), Ok(ToleranceGrade::IT7));
+        assert!("".parse::<FundamentalDeviation>().is_err());
+        assert!("Js".parse::<FundamentalDeviation>().is_err());
     }
 }
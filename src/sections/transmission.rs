@@ -0,0 +1,360 @@
+use std::{f64::consts::PI, fmt};
+
+use egui::{Color32, ComboBox, DragValue, Grid, RichText, Ui};
+
+use super::{
+    feature::Feature,
+    length::Length,
+    stress::{factor_ui, PressFit, StressError},
+    units::Units,
+    utils::State,
+};
+
+// Load an interference fit carries before it slips, from the contact pressure
+// over the engaged surface. Forces are in N, torques in N·m, lengths in mm
+
+#[derive(Clone, Copy, Debug, PartialEq, serde::Deserialize, serde::Serialize)]
+pub enum Assembly {
+    /// Pressed in cold, the surfaces are partly smoothed as they slide.
+    Press,
+    /// Assembled hot or cold with clearance, the surfaces stay intact.
+    Shrink,
+}
+
+impl fmt::Display for Assembly {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Press => write!(f, "press"),
+            Self::Shrink => write!(f, "shrink"),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Friction {
+    pub pair: &'static str,
+    pub assembly: Assembly,
+    pub oiled: bool,
+    pub mu: f64,
+}
+
+impl Friction {
+    pub fn label(&self) -> String {
+        let condition = if self.oiled { "oiled" } else { "dry" };
+        format!("{}, {}, {condition}", self.pair, self.assembly)
+    }
+}
+
+const fn friction(pair: &'static str, assembly: Assembly, oiled: bool, mu: f64) -> Friction {
+    Friction {
+        pair,
+        assembly,
+        oiled,
+        mu,
+    }
+}
+
+/// Typical static coefficients for the hub and shaft materials, in the style
+/// of DIN 7190. These scatter widely, test data is better where it matters.
+pub const FRICTION: [Friction; 16] = {
+    use Assembly::*;
+
+    [
+        friction("Steel / steel", Press, false, 0.11),
+        friction("Steel / steel", Press, true, 0.08),
+        friction("Steel / steel", Shrink, false, 0.15),
+        friction("Steel / steel", Shrink, true, 0.12),
+        friction("Cast iron / steel", Press, false, 0.10),
+        friction("Cast iron / steel", Press, true, 0.06),
+        friction("Cast iron / steel", Shrink, false, 0.14),
+        friction("Cast iron / steel", Shrink, true, 0.10),
+        friction("Aluminium / steel", Press, false, 0.06),
+        friction("Aluminium / steel", Press, true, 0.05),
+        friction("Aluminium / steel", Shrink, false, 0.10),
+        friction("Aluminium / steel", Shrink, true, 0.07),
+        friction("Bronze / steel", Press, false, 0.07),
+        friction("Bronze / steel", Press, true, 0.05),
+        friction("Bronze / steel", Shrink, false, 0.10),
+        friction("Bronze / steel", Shrink, true, 0.07),
+    ]
+};
+
+/// Load carried at one interference.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Capacity {
+    pub pressure: f64,
+    pub torque: f64,
+    pub axial: f64,
+    /// Friction force available over that needed for the combined required loads.
+    pub safety: f64,
+}
+
+#[derive(Clone, serde::Deserialize, serde::Serialize)]
+pub struct Transmission {
+    /// Engaged length of the hub on the shaft.
+    pub length: Length,
+    pub mu: f64,
+    /// Index into `FRICTION`, none once the coefficient is edited.
+    pub preset: Option<usize>,
+    /// Required torque in N·m.
+    pub torque: f64,
+    /// Required axial load in N.
+    pub axial: f64,
+}
+
+impl Default for Transmission {
+    fn default() -> Self {
+        Self {
+            length: Length::from_mm(10.0),
+            mu: FRICTION[0].mu,
+            preset: Some(0),
+            torque: 10.0,
+            axial: 0.0,
+        }
+    }
+}
+
+impl Transmission {
+    /// Load carried by contact `pressure` in MPa on diameter `d`.
+    pub fn capacity(&self, pressure: f64, d: f64) -> Capacity {
+        let axial = self.mu * pressure * PI * d * self.length.mm();
+        let torque = axial * d / 2_000.0;
+
+        // Torque and axial load share the friction force at the surface
+        let required = self.axial.hypot(self.torque * 2_000.0 / d);
+
+        Capacity {
+            pressure,
+            torque,
+            axial,
+            safety: if required > 0.0 {
+                axial / required
+            } else {
+                f64::INFINITY
+            },
+        }
+    }
+
+    /// Capacity at the largest and smallest interference, at temperature if `thermal`.
+    pub fn analyse(
+        &self,
+        press: &PressFit,
+        hole: &Feature,
+        shaft: &Feature,
+        thermal: bool,
+    ) -> Result<[Capacity; 2], StressError> {
+        let [max, _, min] = press.analyse(hole, shaft, thermal)?;
+        let d = hole.size.mm();
+
+        Ok([
+            self.capacity(max.pressure, d),
+            self.capacity(min.pressure, d),
+        ])
+    }
+
+    fn input_ui(&mut self, ui: &mut Ui, units: &Units) {
+        ui.horizontal(|ui| {
+            ui.add_sized(
+                [55.0, 18.0],
+                self.length
+                    .drag()
+                    .custom_formatter(|l, _| units.number(l))
+                    .custom_parser(|l| units.parse_length(l))
+                    .speed(0.1)
+                    .range(0.0..=10_000.0),
+            )
+            .on_hover_text(format!("Engagement length ({})", units.length_unit()));
+
+            let selected = self
+                .preset
+                .and_then(|i| FRICTION.get(i))
+                .map_or("Custom".to_owned(), Friction::label);
+            ComboBox::from_id_salt("friction")
+                .width(200.0)
+                .selected_text(selected)
+                .show_ui(ui, |ui| {
+                    for (i, friction) in FRICTION.iter().enumerate() {
+                        let label = format!("{} (μ {:.2})", friction.label(), friction.mu);
+                        if ui.selectable_label(self.preset == Some(i), label).clicked() {
+                            self.preset = Some(i);
+                            self.mu = friction.mu;
+                        }
+                    }
+                })
+                .response
+                .on_hover_text("Hub / shaft materials, assembly and lubrication");
+
+            if ui
+                .add_sized(
+                    [45.0, 18.0],
+                    DragValue::new(&mut self.mu)
+                        .speed(0.005)
+                        .range(0.01..=1.0)
+                        .min_decimals(2),
+                )
+                .on_hover_text("Static friction coefficient μ")
+                .changed()
+            {
+                self.preset = None;
+            }
+        });
+
+        ui.horizontal(|ui| {
+            ui.add_sized(
+                [80.0, 18.0],
+                DragValue::new(&mut self.torque)
+                    .custom_formatter(|t, _| units.torque(t))
+                    .custom_parser(|t| units.parse_torque(t))
+                    .speed(0.1)
+                    .range(0.0..=f64::MAX),
+            )
+            .on_hover_text("Required torque");
+            ui.add_sized(
+                [80.0, 18.0],
+                DragValue::new(&mut self.axial)
+                    .custom_formatter(|a, _| units.force(a))
+                    .custom_parser(|a| units.parse_force(a))
+                    .speed(1.0)
+                    .range(0.0..=f64::MAX),
+            )
+            .on_hover_text("Required axial load");
+        });
+    }
+
+    /// Panel with the friction inputs and the load carried at each interference.
+    pub fn show(
+        &mut self,
+        ui: &mut Ui,
+        press: &PressFit,
+        hole: &Feature,
+        shaft: &Feature,
+        state: &State,
+    ) {
+        let units = &state.units;
+
+        ui.label(RichText::new("Transmission Capacity").strong().size(15.0));
+        ui.add_space(5.0);
+
+        egui::Frame::group(ui.style())
+            .inner_margin(10.0)
+            .rounding(10.0)
+            .show(ui, |ui| {
+                self.input_ui(ui, units);
+                ui.add_space(5.0);
+
+                let mut columns = Vec::new();
+                for thermal in [false, true] {
+                    if thermal && !state.thermal {
+                        continue;
+                    }
+                    match self.analyse(press, hole, shaft, thermal) {
+                        Ok(capacities) => {
+                            let suffix = if thermal { " at temp" } else { "" };
+                            for (condition, capacity) in ["Max δ", "Min δ"].iter().zip(capacities)
+                            {
+                                columns.push((format!("{condition}{suffix}"), capacity));
+                            }
+                        }
+                        Err(error) => {
+                            ui.colored_label(Color32::RED, error.to_string());
+                            return;
+                        }
+                    }
+                }
+
+                capacities_ui(ui, &columns, units);
+            });
+    }
+}
+
+fn capacities_ui(ui: &mut Ui, columns: &[(String, Capacity)], units: &Units) {
+    Grid::new("transmission_results")
+        .striped(true)
+        .min_col_width(10.0)
+        .show(ui, |ui| {
+            ui.label("");
+            for (heading, _) in columns {
+                ui.label(RichText::new(heading).strong())
+                    .on_hover_text("Interference δ, the smallest decides whether it slips");
+            }
+            ui.end_row();
+
+            ui.label("Pressure");
+            for (_, capacity) in columns {
+                ui.label(units.stress(capacity.pressure));
+            }
+            ui.end_row();
+
+            ui.label("Torque");
+            for (_, capacity) in columns {
+                ui.label(units.torque(capacity.torque));
+            }
+            ui.end_row();
+
+            ui.label("Axial");
+            for (_, capacity) in columns {
+                ui.label(units.force(capacity.axial));
+            }
+            ui.end_row();
+
+            ui.label("SF slip").on_hover_text(
+                "Friction force over that needed for the required torque and axial load together",
+            );
+            for (_, capacity) in columns {
+                factor_ui(ui, capacity.safety);
+            }
+            ui.end_row();
+
+            ui.label("");
+            for (_, capacity) in columns {
+                if capacity.safety >= 1.0 {
+                    ui.colored_label(Color32::from_rgb(0, 160, 0), "Pass");
+                } else {
+                    ui.colored_label(Color32::RED, "Slips");
+                }
+            }
+            ui.end_row();
+        });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_transmission() {
+        let transmission = Transmission {
+            length: Length::from_mm(20.0),
+            mu: 0.1,
+            preset: None,
+            torque: 100.0,
+            axial: 0.0,
+        };
+
+        // 50 MPa on a 40 mm × 20 mm surface, F = μpπdL
+        let capacity = transmission.capacity(50.0, 40.0);
+        assert!((capacity.axial - 4_000.0 * PI).abs() < 1e-9);
+        assert!((capacity.torque - 80.0 * PI).abs() < 1e-9);
+        assert!((capacity.safety - 0.8 * PI).abs() < 1e-9);
+
+        // An axial load at right angles to the torque's tangential force
+        let combined = Transmission {
+            axial: 5_000.0 * 0.75,
+            ..transmission.clone()
+        };
+        let capacity = combined.capacity(50.0, 40.0);
+        assert!((capacity.safety - 4_000.0 * PI / 6_250.0).abs() < 1e-9);
+
+        // H7/s6 at 10 mm holds its smallest interference, H7/g6 carries nothing
+        let hole = Feature::from_iso(true, 10.0, "H7".parse().unwrap()).unwrap();
+        let shaft = Feature::from_iso(false, 10.0, "s6".parse().unwrap()).unwrap();
+        let press = PressFit::default();
+        let [max, min] = transmission.analyse(&press, &hole, &shaft, false).unwrap();
+        assert!(max.torque > min.torque && min.torque > 0.0);
+
+        let clear = Feature::from_iso(false, 10.0, "g6".parse().unwrap()).unwrap();
+        let [_, min] = transmission.analyse(&press, &hole, &clear, false).unwrap();
+        assert_eq!(min.torque, 0.0);
+        assert_eq!(min.safety, 0.0);
+    }
+}
@@ -0,0 +1,326 @@
+use egui::{DragValue, Ui};
+
+use super::utils::decimals;
+
+// Everything is stored in mm, ºC and µm/m·K, these only change what is shown
+// and how typed values are read back
+
+pub const MM_PER_INCH: f64 = 25.4;
+pub const KSI_PER_MPA: f64 = 0.145_037_7;
+pub const N_PER_LBF: f64 = 4.448_222;
+pub const NM_PER_LBF_FT: f64 = 1.355_818;
+pub const KG_M3_PER_LB_IN3: f64 = 27_679.9;
+
+#[derive(Clone, Copy, Debug, PartialEq, serde::Deserialize, serde::Serialize)]
+pub enum UnitSystem {
+    Metric,
+    Imperial,
+}
+
+impl UnitSystem {
+    fn other(self) -> Self {
+        match self {
+            UnitSystem::Metric => UnitSystem::Imperial,
+            UnitSystem::Imperial => UnitSystem::Metric,
+        }
+    }
+
+    /// Length unit and scale from mm.
+    fn length(self) -> (&'static str, f64) {
+        match self {
+            UnitSystem::Metric => ("mm", 1.0),
+            UnitSystem::Imperial => ("in", 1.0 / MM_PER_INCH),
+        }
+    }
+
+    /// Deviation unit and scale from mm, the small unit is used below 1 mm or 0.1 in.
+    fn deviation(self, range: f64) -> (&'static str, f64) {
+        match self {
+            UnitSystem::Metric if range.abs() < 1.0 => ("µm", 1_000.0),
+            UnitSystem::Imperial if range.abs() < 0.1 * MM_PER_INCH => {
+                ("thou", 1_000.0 / MM_PER_INCH)
+            }
+            _ => self.length(),
+        }
+    }
+}
+
+#[derive(Clone, Copy, serde::Deserialize, serde::Serialize)]
+pub struct Units {
+    pub system: UnitSystem,
+    pub dual: bool,
+    pub decimals: usize,
+}
+
+impl Default for Units {
+    fn default() -> Self {
+        Units {
+            system: UnitSystem::Metric,
+            dual: false,
+            decimals: 4,
+        }
+    }
+}
+
+impl Units {
+    pub fn imperial(&self) -> bool {
+        self.system == UnitSystem::Imperial
+    }
+
+    pub fn length_unit(&self) -> &'static str {
+        self.system.length().0
+    }
+
+    /// Length in the display unit without a suffix, for drag values.
+    pub fn number(&self, mm: f64) -> String {
+        let (_, scale) = self.system.length();
+        format!("{:.}", decimals(scale * mm, self.decimals as i32))
+    }
+
+    /// Length with its unit, plus the other system in brackets when dual dimensioning.
+    pub fn length(&self, mm: f64) -> String {
+        let single = |system: UnitSystem| {
+            let (unit, scale) = system.length();
+            format!("{:.} {unit}", decimals(scale * mm, self.decimals as i32))
+        };
+
+        if self.dual {
+            format!("{} [{}]", single(self.system), single(self.system.other()))
+        } else {
+            single(self.system)
+        }
+    }
+
+    /// Deviation or clearance with its unit, which is picked from `range` so
+    /// that values shown together share a unit.
+    pub fn deviation(&self, mm: f64, range: f64, signed: bool) -> String {
+        let single = |system: UnitSystem| {
+            let (unit, scale) = system.deviation(range);
+            // The small units are three orders down on the length units
+            let places = if matches!(unit, "µm" | "thou") {
+                self.decimals.saturating_sub(3).max(1)
+            } else {
+                self.decimals
+            };
+            let value = decimals(scale * mm, places as i32);
+            if signed && value.is_sign_positive() {
+                format!("+{value:.} {unit}")
+            } else {
+                format!("{value:.} {unit}")
+            }
+        };
+
+        if self.dual {
+            format!("{} [{}]", single(self.system), single(self.system.other()))
+        } else {
+            single(self.system)
+        }
+    }
+
+    pub fn temp(&self, celsius: f64) -> String {
+        match self.system {
+            UnitSystem::Metric => format!("{:.} ºC", decimals(celsius, 1)),
+            UnitSystem::Imperial => format!("{:.} ºF", decimals(celsius * 1.8 + 32.0, 1)),
+        }
+    }
+
+    pub fn cte(&self, per_kelvin: f64) -> String {
+        match self.system {
+            UnitSystem::Metric => format!("{per_kelvin:.1} µm/m·K"),
+            UnitSystem::Imperial => format!("{:.1} µin/in·ºF", per_kelvin / 1.8),
+        }
+    }
+
+    pub fn stress(&self, mpa: f64) -> String {
+        match self.system {
+            UnitSystem::Metric => format!("{:.} MPa", decimals(mpa, 1)),
+            UnitSystem::Imperial => format!("{:.} ksi", decimals(mpa * KSI_PER_MPA, 2)),
+        }
+    }
+
+    pub fn force(&self, newtons: f64) -> String {
+        match self.system {
+            UnitSystem::Metric => format!("{:.} N", decimals(newtons, 0)),
+            UnitSystem::Imperial => format!("{:.} lbf", decimals(newtons / N_PER_LBF, 0)),
+        }
+    }
+
+    pub fn torque(&self, newton_metres: f64) -> String {
+        match self.system {
+            UnitSystem::Metric => format!("{:.} N·m", decimals(newton_metres, 1)),
+            UnitSystem::Imperial => {
+                format!("{:.} lbf·ft", decimals(newton_metres / NM_PER_LBF_FT, 1))
+            }
+        }
+    }
+
+    pub fn density(&self, kg_m3: f64) -> String {
+        match self.system {
+            UnitSystem::Metric => format!("{:.} kg/m³", decimals(kg_m3, 0)),
+            UnitSystem::Imperial => {
+                format!("{:.} lb/in³", decimals(kg_m3 / KG_M3_PER_LB_IN3, 4))
+            }
+        }
+    }
+
+    /// Reads a length in mm, a missing suffix means the display unit.
+    pub fn parse_length(&self, s: &str) -> Option<f64> {
+        let (value, unit) = split_unit(s)?;
+        let scale = match unit.as_str() {
+            "" => 1.0 / self.system.length().1,
+            "mm" => 1.0,
+            "cm" => 10.0,
+            "m" => 1_000.0,
+            "µm" | "μm" | "um" => 0.001,
+            "in" | "inch" | "inches" | "\"" => MM_PER_INCH,
+            "thou" | "mil" | "mils" => MM_PER_INCH / 1_000.0,
+            _ => return None,
+        };
+        Some(value * scale)
+    }
+
+    /// Reads a temperature in ºC, a missing suffix means the display unit.
+    pub fn parse_temp(&self, s: &str) -> Option<f64> {
+        let (value, unit) = split_unit(s)?;
+        let unit = unit.trim_start_matches(['º', '°']);
+        match unit {
+            "" if self.imperial() => Some((value - 32.0) / 1.8),
+            "" | "c" => Some(value),
+            "f" => Some((value - 32.0) / 1.8),
+            "k" => Some(value - 273.15),
+            _ => None,
+        }
+    }
+
+    /// Reads a thermal expansion coefficient in µm/m·K.
+    pub fn parse_cte(&self, s: &str) -> Option<f64> {
+        let (value, unit) = split_unit(s)?;
+        match unit.replace('°', "º").as_str() {
+            "" if self.imperial() => Some(value * 1.8),
+            "" | "µm/m·k" | "um/m·k" | "/k" => Some(value),
+            "µin/in·ºf" | "uin/in·ºf" | "/ºf" => Some(value * 1.8),
+            _ => None,
+        }
+    }
+
+    /// Reads a stress or modulus in MPa.
+    pub fn parse_stress(&self, s: &str) -> Option<f64> {
+        let (value, unit) = split_unit(s)?;
+        match unit.as_str() {
+            "" if self.imperial() => Some(value / KSI_PER_MPA),
+            "" | "mpa" => Some(value),
+            "gpa" => Some(value * 1_000.0),
+            "ksi" => Some(value / KSI_PER_MPA),
+            "psi" => Some(value / KSI_PER_MPA / 1_000.0),
+            _ => None,
+        }
+    }
+
+    /// Reads a force in N.
+    pub fn parse_force(&self, s: &str) -> Option<f64> {
+        let (value, unit) = split_unit(s)?;
+        match unit.as_str() {
+            "" if self.imperial() => Some(value * N_PER_LBF),
+            "" | "n" => Some(value),
+            "kn" => Some(value * 1_000.0),
+            "lbf" => Some(value * N_PER_LBF),
+            _ => None,
+        }
+    }
+
+    /// Reads a torque in N·m.
+    pub fn parse_torque(&self, s: &str) -> Option<f64> {
+        let (value, unit) = split_unit(s)?;
+        match unit.as_str() {
+            "" if self.imperial() => Some(value * NM_PER_LBF_FT),
+            "" | "nm" | "n·m" => Some(value),
+            "lbf·ft" | "lbfft" | "lbf ft" => Some(value * NM_PER_LBF_FT),
+            _ => None,
+        }
+    }
+
+    /// Reads a density in kg/m³.
+    pub fn parse_density(&self, s: &str) -> Option<f64> {
+        let (value, unit) = split_unit(s)?;
+        match unit.as_str() {
+            "" if self.imperial() => Some(value * KG_M3_PER_LB_IN3),
+            "" | "kg/m³" | "kg/m3" => Some(value),
+            "g/cm³" | "g/cm3" => Some(value * 1_000.0),
+            "lb/in³" | "lb/in3" => Some(value * KG_M3_PER_LB_IN3),
+            _ => None,
+        }
+    }
+
+    pub fn show(&mut self, ui: &mut Ui) {
+        ui.menu_button(self.length_unit(), |ui| {
+            ui.radio_value(&mut self.system, UnitSystem::Metric, "Metric");
+            ui.radio_value(&mut self.system, UnitSystem::Imperial, "Imperial");
+            ui.checkbox(&mut self.dual, "Dual dimensions");
+            ui.horizontal(|ui| {
+                ui.add(DragValue::new(&mut self.decimals).range(0..=6));
+                ui.label("Decimal places");
+            });
+        })
+        .response
+        .on_hover_text("Units");
+    }
+}
+
+/// Splits a typed value like "1.25 in" into its number and lower case unit.
+fn split_unit(s: &str) -> Option<(f64, String)> {
+    let s = s.trim();
+    let end = s
+        .find(|c: char| !(c.is_ascii_digit() || matches!(c, '.' | ',' | '-' | '+')))
+        .unwrap_or(s.len());
+    let value = s[..end].replace(',', ".").parse().ok()?;
+    Some((value, s[end..].trim().to_lowercase()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_units() {
+        let metric = Units::default();
+        let imperial = Units {
+            system: UnitSystem::Imperial,
+            ..Units::default()
+        };
+
+        assert_eq!(metric.parse_length("31.75mm"), Some(31.75));
+        assert_eq!(metric.parse_length("1.25 in"), Some(31.75));
+        assert_eq!(metric.parse_length("12,5"), Some(12.5));
+        assert_eq!(imperial.parse_length("1.25"), Some(31.75));
+        assert_eq!(imperial.parse_length("15 µm"), Some(0.015));
+        assert_eq!(metric.parse_length("1 ft"), None);
+
+        assert_eq!(imperial.parse_temp("68"), Some(20.0));
+        assert_eq!(metric.parse_temp("68 ºF"), Some(20.0));
+        assert_eq!(metric.parse_temp("-196 C"), Some(-196.0));
+        assert_eq!(imperial.parse_cte("10 µin/in·ºF"), Some(18.0));
+        assert_eq!(imperial.parse_cte("12 µm/m·K"), Some(12.0));
+        assert_eq!(metric.parse_cte("10 µin/in·ºF"), Some(18.0));
+        assert_eq!(metric.parse_cte("10 /°F"), Some(18.0));
+        assert_eq!(metric.parse_cte("12 µm/mm"), None);
+        assert_eq!(metric.parse_stress("200 GPa"), Some(200_000.0));
+        assert_eq!(imperial.stress(1_000.0), "145.04 ksi");
+        assert_eq!(metric.parse_force("2 kN"), Some(2_000.0));
+        assert_eq!(imperial.parse_torque("1 lbf·ft"), Some(NM_PER_LBF_FT));
+        assert_eq!(metric.torque(12.34), "12.3 N·m");
+        assert_eq!(metric.parse_density("7.85 g/cm³"), Some(7_850.0));
+        assert_eq!(imperial.density(7_850.0), "0.2836 lb/in³");
+
+        assert_eq!(metric.length(25.4), "25.4 mm");
+        assert_eq!(imperial.length(25.4), "1 in");
+        assert_eq!(imperial.temp(100.0), "212 ºF");
+        assert_eq!(metric.deviation(0.015, 0.015, true), "+15 µm");
+        assert_eq!(imperial.deviation(-0.0254, 0.0254, true), "-1 thou");
+
+        let dual = Units {
+            dual: true,
+            ..Units::default()
+        };
+        assert_eq!(dual.length(31.75), "31.75 mm [1.25 in]");
+    }
+}
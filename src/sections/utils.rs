@@ -1,28 +1,94 @@
-use egui::{Button, Context, DragValue, Label, Pos2, Sense, Ui, Vec2};
+use egui::{Button, Context, Ui};
+
+use super::{length::Length, material::Material, units::Units};
 
 #[derive(Clone, serde::Deserialize, serde::Serialize)]
 pub struct State {
     pub debug: bool,
     pub force_valid: bool,
+    #[serde(default)]
+    pub cross_check: bool,
+    #[serde(default)]
+    pub conformance: bool,
     pub sync_size: bool,
-    pub synced_size: f64,
+    pub synced_size: Length,
     pub sync_temp: bool,
     pub synced_temp: f64,
     pub thermal: bool,
+    #[serde(default)]
+    pub explain: bool,
+    #[serde(default)]
+    pub selector: bool,
+    #[serde(default)]
+    pub preferred: bool,
+    #[serde(default)]
+    pub ansi: bool,
+    #[serde(default)]
+    pub browser: bool,
+    #[serde(default)]
+    pub general: bool,
+    #[serde(default)]
+    pub geometric: bool,
+    #[serde(default)]
+    pub statistics: bool,
+    #[serde(default)]
+    pub capability: bool,
+    #[serde(default)]
+    pub stack: bool,
+    #[serde(default)]
+    pub stress: bool,
+    #[serde(default)]
+    pub slip: bool,
+    #[serde(default)]
+    pub shrink: bool,
+    #[serde(default)]
+    pub sweep: bool,
+    #[serde(default)]
+    pub library: bool,
+    /// The user's own materials, kept on reset like the stacks.
+    #[serde(default)]
+    pub materials: Vec<Material>,
     pub zoom: Zoom,
+    #[serde(default)]
+    pub units: Units,
+    #[serde(default)]
+    pub designation: String,
+    #[serde(skip)]
+    pub designation_error: Option<String>,
 }
 
-impl State {
-    pub fn default() -> Self {
+impl Default for State {
+    fn default() -> Self {
         State {
             debug: false,
             force_valid: false,
+            cross_check: false,
+            conformance: false,
             sync_size: true,
-            synced_size: 10.0,
+            synced_size: Length::from_mm(10.0),
             sync_temp: true,
             synced_temp: 20.0,
             thermal: false,
+            explain: false,
+            selector: false,
+            preferred: false,
+            ansi: false,
+            browser: false,
+            general: false,
+            geometric: false,
+            statistics: false,
+            capability: false,
+            stack: false,
+            stress: false,
+            slip: false,
+            shrink: false,
+            sweep: false,
+            library: false,
+            materials: Vec::new(),
             zoom: Zoom::default(),
+            units: Units::default(),
+            designation: String::new(),
+            designation_error: None,
         }
     }
 }
@@ -33,14 +99,16 @@ pub struct Zoom {
     pub scale: f32,
 }
 
-impl Zoom {
-    pub fn default() -> Self {
+impl Default for Zoom {
+    fn default() -> Self {
         Zoom {
             expand: false,
             scale: 1.0,
         }
     }
+}
 
+impl Zoom {
     pub fn show(&mut self, ui: &mut Ui, ctx: &Context) {
         ctx.set_zoom_factor(self.scale);
 